@@ -0,0 +1,315 @@
+//! Complex numbers.
+//!
+//! [`Complex<T>`] has arithmetic for any [`Scalar`] component type, so
+//! `Complex<Rational>` is exact. Polar form and the transcendental functions
+//! are provided for the default `Complex<f32>`.
+
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    linear_algebra::format::Counter,
+    math::{atan2, cos, exp, ln, sin, sqrt_f64},
+    scalar::{Scalar, write_signed},
+};
+
+/// A complex number `re + im·i`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex<T = f32> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Scalar> Complex<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ZERO);
+    pub const I: Self = Self::new(T::ZERO, T::ONE);
+
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// The squared absolute value `re² + im²`, which is exact when `T` is.
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// The reciprocal `1 / self`.
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    /// Raises the number to an integer power by repeated squaring. Negative
+    /// powers take the reciprocal of the result.
+    pub fn powi(self, exponent: i32) -> Self {
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut n = exponent.unsigned_abs();
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base *= base;
+            }
+        }
+        if exponent < 0 { result.recip() } else { result }
+    }
+
+    fn write_parts(&self, out: &mut dyn Write, precision: Option<usize>) -> fmt::Result {
+        match precision {
+            Some(precision) => write!(out, "{:.*}", precision, self.re)?,
+            None => write!(out, "{}", self.re)?,
+        }
+        write_signed(out, &self.im, precision, "+", "-")?;
+        out.write_char('i')
+    }
+}
+
+impl Complex {
+    /// The number with absolute value `r` and argument `theta`.
+    pub fn from_polar(r: f32, theta: f32) -> Self {
+        Self::new(r * cos(theta), r * sin(theta))
+    }
+
+    /// The absolute value and argument.
+    pub fn to_polar(self) -> (f32, f32) {
+        (self.abs(), self.arg())
+    }
+
+    /// The absolute value, computed without intermediate overflow.
+    pub fn abs(self) -> f32 {
+        let (re, im) = (self.re as f64, self.im as f64);
+        sqrt_f64(re * re + im * im) as f32
+    }
+
+    /// The argument in `(-π, π]`.
+    pub fn arg(self) -> f32 {
+        atan2(self.im, self.re)
+    }
+
+    pub fn exp(self) -> Self {
+        Self::from_polar(exp(self.re), self.im)
+    }
+
+    /// The principal natural logarithm, with imaginary part in `(-π, π]`.
+    pub fn ln(self) -> Self {
+        Self::new(ln(self.abs()), self.arg())
+    }
+
+    /// The principal square root, with a non-negative real part.
+    pub fn sqrt(self) -> Self {
+        if self == Self::ZERO {
+            return Self::ZERO;
+        }
+        // Avoids the cancellation in `sqrt((|z| - re) / 2)` for negative `re`.
+        let t = sqrt_f64((self.re.abs() as f64 + self.abs() as f64) / 2.0) as f32;
+        if self.re >= 0.0 {
+            Self::new(t, self.im / (2.0 * t))
+        } else {
+            Self::new(self.im.abs() / (2.0 * t), t.copysign(self.im))
+        }
+    }
+
+    /// Raises the number to a real power on the principal branch.
+    pub fn powf(self, exponent: f32) -> Self {
+        if exponent == 0.0 {
+            return Self::ONE;
+        }
+        if self == Self::ZERO && exponent > 0.0 {
+            return Self::ZERO;
+        }
+        Self::from_polar(exp(exponent * ln(self.abs())), exponent * self.arg())
+    }
+
+    /// Raises the number to a complex power, `e^(w ln z)`, on the principal
+    /// branch.
+    pub fn powc(self, exponent: Self) -> Self {
+        if exponent == Self::ZERO {
+            return Self::ONE;
+        }
+        if self == Self::ZERO && exponent.re > 0.0 {
+            return Self::ZERO;
+        }
+        (exponent * self.ln()).exp()
+    }
+}
+
+impl<T: Scalar> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Self::new(re, T::ZERO)
+    }
+}
+
+impl<T: Scalar> Neg for Complex<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Scalar> Add for Complex<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Scalar> Sub for Complex<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Scalar> Mul for Complex<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// Smith's algorithm, which divides by the larger component of `rhs` first
+/// so that `f32` intermediates do not overflow.
+impl<T: Scalar> Div for Complex<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let Self { re: a, im: b } = self;
+        let Self { re: c, im: d } = rhs;
+        if c.magnitude() >= d.magnitude() {
+            let ratio = d / c;
+            let denominator = c + d * ratio;
+            Self::new((a + b * ratio) / denominator, (b - a * ratio) / denominator)
+        } else {
+            let ratio = c / d;
+            let denominator = c * ratio + d;
+            Self::new((a * ratio + b) / denominator, (b * ratio - a) / denominator)
+        }
+    }
+}
+
+impl<T: Scalar> Add<T> for Complex<T> {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self {
+        Self::new(self.re + rhs, self.im)
+    }
+}
+
+impl<T: Scalar> Sub<T> for Complex<T> {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self {
+        Self::new(self.re - rhs, self.im)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Complex<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl<T: Scalar> Div<T> for Complex<T> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+impl<T: Scalar> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar> AddAssign<T> for Complex<T> {
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar> SubAssign<T> for Complex<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Complex<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Complex<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+/// Prints `re+imi` or `re-imi`, passing the precision on to both parts and
+/// right-aligning the whole to the formatter's width.
+impl<T: Scalar> Display for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        if let Some(width) = f.width() {
+            let mut counter = Counter(0);
+            self.write_parts(&mut counter, precision)?;
+            for _ in counter.0..width {
+                f.write_char(' ')?;
+            }
+        }
+        self.write_parts(f, precision)
+    }
+}
+
+impl<T: Scalar> Scalar for Complex<T> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const EPSILON: f64 = T::EPSILON;
+
+    /// The absolute value.
+    fn magnitude(&self) -> f64 {
+        let (re, im) = (self.re.magnitude(), self.im.magnitude());
+        sqrt_f64(re * re + im * im)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    fn write_rust(&self, out: &mut dyn Write, precision: Option<usize>) -> fmt::Result {
+        out.write_str("Complex::new(")?;
+        self.re.write_rust(out, precision)?;
+        out.write_str(", ")?;
+        self.im.write_rust(out, precision)?;
+        out.write_char(')')
+    }
+}
@@ -0,0 +1,462 @@
+//! Forward-mode automatic differentiation.
+//!
+//! A [`Dual<N>`] carries a value together with its gradient with respect to
+//! `N` inputs, and every operation applies the chain rule, so evaluating a
+//! function on dual inputs yields its exact first derivatives alongside its
+//! value. A [`HyperDual<N>`] also carries the Hessian. Both implement
+//! [`Real`], so they can be stored in a [`Matrix`] or vector, multiplied with
+//! [`RealVector`](crate::linear_algebra::vector::RealVector) and passed to the
+//! rotation constructors:
+//!
+//! ```
+//! # use minmath::{dual::{jacobian, Dual}, linear_algebra::matrix::Matrix};
+//! // The tip of a planar arm with two unit links and joint angles `q`.
+//! let j = jacobian(
+//!     |q: Matrix<2, 1, Dual<2>>| {
+//!         let link = Matrix::new([[Dual::constant(1.0)], [Dual::constant(0.0)]]);
+//!         let first = Matrix::<2, 2, Dual<2>>::rotation_matrix2x2(q[0][0]);
+//!         let second = Matrix::<2, 2, Dual<2>>::rotation_matrix2x2(q[0][0] + q[1][0]);
+//!         first * link + second * link
+//!     },
+//!     Matrix::new([[0.0], [0.0]]),
+//! );
+//! assert_eq!(j, Matrix::new([[0.0, 0.0], [2.0, 1.0]]));
+//! ```
+
+use core::{
+    array,
+    fmt::{self, Display, Formatter, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    linear_algebra::matrix::Matrix,
+    scalar::{Real, Scalar, write_signed},
+};
+
+/// A value and its gradient with respect to `N` independent variables.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dual<const N: usize = 1> {
+    pub value: f32,
+    pub gradient: [f32; N],
+}
+
+impl<const N: usize> Dual<N> {
+    /// A value that does not depend on any variable.
+    pub const fn constant(value: f32) -> Self {
+        Self {
+            value,
+            gradient: [0.0; N],
+        }
+    }
+
+    /// Variable number `index`, whose gradient is the `index`th unit vector.
+    pub const fn variable(value: f32, index: usize) -> Self {
+        let mut gradient = [0.0; N];
+        gradient[index] = 1.0;
+        Self { value, gradient }
+    }
+
+    // Applies a function with value `f` and derivative `df` at `self.value`.
+    fn chain(self, f: f32, df: f32) -> Self {
+        Self {
+            value: f,
+            gradient: self.gradient.map(|g| df * g),
+        }
+    }
+}
+
+/// A value with its gradient and Hessian with respect to `N` independent
+/// variables.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HyperDual<const N: usize = 1> {
+    pub value: f32,
+    pub gradient: [f32; N],
+    pub hessian: [[f32; N]; N],
+}
+
+impl<const N: usize> HyperDual<N> {
+    /// A value that does not depend on any variable.
+    pub const fn constant(value: f32) -> Self {
+        Self {
+            value,
+            gradient: [0.0; N],
+            hessian: [[0.0; N]; N],
+        }
+    }
+
+    /// Variable number `index`, whose gradient is the `index`th unit vector.
+    pub const fn variable(value: f32, index: usize) -> Self {
+        let mut gradient = [0.0; N];
+        gradient[index] = 1.0;
+        Self {
+            value,
+            gradient,
+            hessian: [[0.0; N]; N],
+        }
+    }
+
+    // Applies a function with value `f` and first and second derivatives `df`
+    // and `d2f` at `self.value`.
+    fn chain(self, f: f32, df: f32, d2f: f32) -> Self {
+        let g = self.gradient;
+        Self {
+            value: f,
+            gradient: g.map(|g| df * g),
+            hessian: array::from_fn(|i| {
+                array::from_fn(|j| df * self.hessian[i][j] + d2f * g[i] * g[j])
+            }),
+        }
+    }
+}
+
+/// The gradient of `f` at `x`, from a single evaluation on dual numbers.
+pub fn gradient<const N: usize>(
+    f: impl FnOnce(Matrix<N, 1, Dual<N>>) -> Dual<N>,
+    x: Matrix<N, 1>,
+) -> Matrix<N, 1> {
+    let y = f(Matrix::from_fn(|i, _| Dual::variable(x[i][0], i)));
+    Matrix::from_fn(|i, _| y.gradient[i])
+}
+
+/// The `M`x`N` Jacobian of `f` at `x`, from a single evaluation on dual
+/// numbers. Row `i` is the gradient of output `i`.
+pub fn jacobian<const M: usize, const N: usize>(
+    f: impl FnOnce(Matrix<N, 1, Dual<N>>) -> Matrix<M, 1, Dual<N>>,
+    x: Matrix<N, 1>,
+) -> Matrix<M, N> {
+    let y = f(Matrix::from_fn(|i, _| Dual::variable(x[i][0], i)));
+    Matrix::from_fn(|i, j| y[i][0].gradient[j])
+}
+
+/// The Hessian of `f` at `x`, from a single evaluation on hyper-dual numbers.
+pub fn hessian<const N: usize>(
+    f: impl FnOnce(Matrix<N, 1, HyperDual<N>>) -> HyperDual<N>,
+    x: Matrix<N, 1>,
+) -> Matrix<N, N> {
+    let y = f(Matrix::from_fn(|i, _| HyperDual::variable(x[i][0], i)));
+    Matrix::new(y.hessian)
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.chain(-self.value, -1.0)
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value + rhs.value,
+            gradient: array::from_fn(|i| self.gradient[i] + rhs.gradient[i]),
+        }
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+    // The product and quotient rules mix operators by design.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value * rhs.value,
+            gradient: array::from_fn(|i| {
+                self.value * rhs.gradient[i] + rhs.value * self.gradient[i]
+            }),
+        }
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Self;
+    // The product and quotient rules mix operators by design.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        Self {
+            value,
+            gradient: array::from_fn(|i| (self.gradient[i] - value * rhs.gradient[i]) / rhs.value),
+        }
+    }
+}
+
+impl<const N: usize> AddAssign for Dual<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign for Dual<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> MulAssign for Dual<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize> DivAssign for Dual<N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: usize> Neg for HyperDual<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.chain(-self.value, -1.0, 0.0)
+    }
+}
+
+impl<const N: usize> Add for HyperDual<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value + rhs.value,
+            gradient: array::from_fn(|i| self.gradient[i] + rhs.gradient[i]),
+            hessian: array::from_fn(|i| array::from_fn(|j| self.hessian[i][j] + rhs.hessian[i][j])),
+        }
+    }
+}
+
+impl<const N: usize> Sub for HyperDual<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const N: usize> Mul for HyperDual<N> {
+    type Output = Self;
+    // The product and quotient rules mix operators by design.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self {
+            value: a.value * b.value,
+            gradient: array::from_fn(|i| a.value * b.gradient[i] + b.value * a.gradient[i]),
+            hessian: array::from_fn(|i| {
+                array::from_fn(|j| {
+                    a.value * b.hessian[i][j]
+                        + b.value * a.hessian[i][j]
+                        + a.gradient[i] * b.gradient[j]
+                        + b.gradient[i] * a.gradient[j]
+                })
+            }),
+        }
+    }
+}
+
+impl<const N: usize> Div for HyperDual<N> {
+    type Output = Self;
+    // The product and quotient rules mix operators by design.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        let x = rhs.value;
+        self * rhs.chain(1.0 / x, -1.0 / (x * x), 2.0 / (x * x * x))
+    }
+}
+
+impl<const N: usize> AddAssign for HyperDual<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign for HyperDual<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> MulAssign for HyperDual<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize> DivAssign for HyperDual<N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: usize> Real for Dual<N> {
+    fn from_f32(value: f32) -> Self {
+        Self::constant(value)
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.value
+    }
+
+    fn abs(self) -> Self {
+        self.chain(self.value.abs(), self.value.signum())
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.chain(root, 0.5 / root)
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.value.tan();
+        self.chain(tan, 1.0 + tan * tan)
+    }
+}
+
+impl<const N: usize> Real for HyperDual<N> {
+    fn from_f32(value: f32) -> Self {
+        Self::constant(value)
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.value
+    }
+
+    fn abs(self) -> Self {
+        self.chain(self.value.abs(), self.value.signum(), 0.0)
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.chain(root, 0.5 / root, -0.25 / (root * self.value))
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp, exp)
+    }
+
+    fn ln(self) -> Self {
+        let x = self.value;
+        self.chain(x.ln(), 1.0 / x, -1.0 / (x * x))
+    }
+
+    fn sin(self) -> Self {
+        let (sin, cos) = (self.value.sin(), self.value.cos());
+        self.chain(sin, cos, -sin)
+    }
+
+    fn cos(self) -> Self {
+        let (sin, cos) = (self.value.sin(), self.value.cos());
+        self.chain(cos, -sin, -cos)
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.value.tan();
+        let derivative = 1.0 + tan * tan;
+        self.chain(tan, derivative, 2.0 * tan * derivative)
+    }
+}
+
+impl<const N: usize> Scalar for Dual<N> {
+    const ZERO: Self = Self::constant(0.0);
+    const ONE: Self = Self::constant(1.0);
+    const EPSILON: f64 = f32::EPSILON as f64;
+
+    /// The absolute value of the value part.
+    fn magnitude(&self) -> f64 {
+        self.value.abs() as f64
+    }
+
+    fn is_finite(&self) -> bool {
+        self.value.is_finite() && self.gradient.iter().all(|g| g.is_finite())
+    }
+}
+
+impl<const N: usize> Scalar for HyperDual<N> {
+    const ZERO: Self = Self::constant(0.0);
+    const ONE: Self = Self::constant(1.0);
+    const EPSILON: f64 = f32::EPSILON as f64;
+
+    /// The absolute value of the value part.
+    fn magnitude(&self) -> f64 {
+        self.value.abs() as f64
+    }
+
+    fn is_finite(&self) -> bool {
+        self.value.is_finite()
+            && self.gradient.iter().all(|g| g.is_finite())
+            && self.hessian.iter().flatten().all(|h| h.is_finite())
+    }
+}
+
+// Writes `value` followed by `+ coefficient·label` terms, with the labels
+// `ε` or `ε0`, `ε1`, … for the variables.
+fn write_terms(
+    f: &mut Formatter<'_>,
+    value: f32,
+    terms: impl Iterator<Item = (f32, usize, Option<usize>)>,
+    single: bool,
+) -> fmt::Result {
+    let precision = f.precision();
+    match precision {
+        Some(precision) => write!(f, "{:.*}", precision, value)?,
+        None => write!(f, "{}", value)?,
+    }
+    let epsilon = |f: &mut Formatter<'_>, i: usize| {
+        if single {
+            f.write_char('ε')
+        } else {
+            write!(f, "ε{}", i)
+        }
+    };
+    for (coefficient, i, j) in terms {
+        write_signed(f, &coefficient, precision, "+", "-")?;
+        epsilon(f, i)?;
+        if let Some(j) = j {
+            epsilon(f, j)?;
+        }
+    }
+    Ok(())
+}
+
+/// Prints the value followed by one `ε` term per variable, such as
+/// `1+2ε0-3ε1`, or `1+2ε` when there is a single variable.
+impl<const N: usize> Display for Dual<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let terms = self.gradient.iter().enumerate().map(|(i, &g)| (g, i, None));
+        write_terms(f, self.value, terms, N == 1)
+    }
+}
+
+/// Prints like [`Dual`], followed by an `εiεj` term with coefficient `H[i][j]`
+/// for each Hessian entry on or above the diagonal.
+impl<const N: usize> Display for HyperDual<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let first = self.gradient.iter().enumerate().map(|(i, &g)| (g, i, None));
+        let second = (0..N).flat_map(|i| (i..N).map(move |j| (i, j)));
+        let second = second.map(|(i, j)| (self.hessian[i][j], i, Some(j)));
+        write_terms(f, self.value, first.chain(second), N == 1)
+    }
+}
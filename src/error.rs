@@ -0,0 +1,40 @@
+use core::fmt::Display;
+
+/// The error returned by the `checked_*` operations across the crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathError {
+    /// The operands' shapes are incompatible. `expected` is the shape the
+    /// operation needed, `found` the shape it was given.
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// The matrix has no inverse.
+    Singular,
+    /// An iterative algorithm did not reach its tolerance.
+    NotConverged { iterations: usize },
+    /// The result contains an infinity or NaN.
+    NonFinite,
+    /// An argument lies outside the operation's domain, such as a zero divisor.
+    Domain(&'static str),
+}
+
+impl Display for MathError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ShapeMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} operand, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Self::Singular => write!(f, "matrix is singular"),
+            Self::NotConverged { iterations } => {
+                write!(f, "did not converge after {} iterations", iterations)
+            }
+            Self::NonFinite => write!(f, "result is not finite"),
+            Self::Domain(reason) => write!(f, "domain error: {}", reason),
+        }
+    }
+}
+
+impl core::error::Error for MathError {}
@@ -0,0 +1,113 @@
+//! Finite-value checks.
+//!
+//! With the `debug-finite` feature enabled, every arithmetic operator on
+//! matrices and vectors checks its result in builds with debug assertions
+//! and panics at the caller's location, naming the operation, as soon as an
+//! infinity or NaN appears.
+
+use core::{
+    fmt::{Debug, Display},
+    ops::Deref,
+};
+
+#[cfg(feature = "std")]
+use crate::linear_algebra::dmatrix::DMatrix;
+use crate::{
+    error::MathError,
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+    },
+    scalar::Scalar,
+};
+
+/// Values made of scalars that can be checked for infinities and NaN.
+pub trait CheckFinite {
+    /// Whether every component is neither infinite nor NaN.
+    fn is_finite(&self) -> bool;
+}
+
+impl CheckFinite for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> CheckFinite for Matrix<ROWS, COLUMNS, T> {
+    fn is_finite(&self) -> bool {
+        Matrix::is_finite(self)
+    }
+}
+
+impl<T: Scalar> CheckFinite for Vector2<T> {
+    fn is_finite(&self) -> bool {
+        Vector2::is_finite(self)
+    }
+}
+
+impl<T: Scalar> CheckFinite for Vector3<T> {
+    fn is_finite(&self) -> bool {
+        Vector3::is_finite(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl CheckFinite for DMatrix {
+    fn is_finite(&self) -> bool {
+        DMatrix::is_finite(self)
+    }
+}
+
+/// A value that is known to contain no infinities or NaN.
+///
+/// The check happens once, in [`Finite::new`]; the wrapped value can be read
+/// through `Deref` but not modified.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Finite<T>(T);
+
+impl<T: CheckFinite> Finite<T> {
+    pub fn new(value: T) -> Result<Self, MathError> {
+        if value.is_finite() {
+            Ok(Self(value))
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Finite<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Finite<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+// Panics if `debug-finite` checks are active and `value` is not finite.
+#[cfg_attr(feature = "debug-finite", track_caller)]
+#[inline(always)]
+pub(crate) fn audit<T: CheckFinite + Debug>(value: &T, operation: impl Display) {
+    if cfg!(all(feature = "debug-finite", debug_assertions)) && !value.is_finite() {
+        panic!("`{}` produced a non-finite result: {:?}", operation, value);
+    }
+}
+
+#[cfg_attr(feature = "debug-finite", track_caller)]
+#[inline(always)]
+pub(crate) fn audited<T: CheckFinite + Debug>(value: T, operation: impl Display) -> T {
+    audit(&value, operation);
+    value
+}
@@ -0,0 +1,459 @@
+//! Reading and writing matrices as delimiter-separated text.
+//!
+//! Fields may be quoted as described in [RFC 4180], so quoted header names
+//! can contain delimiters, line breaks and doubled quotes. Whitespace
+//! around unquoted fields is ignored and blank lines are skipped. Errors
+//! refer to the 1-based line on which the offending record starts and the
+//! 1-based field within it.
+//!
+//! [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    io::{self, Read, Write},
+};
+
+use crate::linear_algebra::{dmatrix::DMatrix, matrix::Matrix};
+
+/// How cells with no content are treated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmptyCells {
+    /// Reading an empty cell fails with [`CsvError::EmptyCell`].
+    Reject,
+    /// Empty cells are read as NaN, and NaN is written as an empty cell.
+    Nan,
+    /// Empty cells are read as zero.
+    Zero,
+}
+
+/// Whether NaN values are accepted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NanValues {
+    Allow,
+    /// Reading or writing a NaN fails with [`CsvError::NanValue`]. Empty
+    /// cells read as NaN under [`EmptyCells::Nan`] are still accepted.
+    Reject,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    /// Whether the first record holds column names rather than values.
+    pub header: bool,
+    /// The quote character, or `None` to disable quoting.
+    pub quote: Option<char>,
+    pub empty: EmptyCells,
+    pub nan: NanValues,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            header: false,
+            quote: Some('"'),
+            empty: EmptyCells::Reject,
+            nan: NanValues::Allow,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    /// The delimiter is a line break or the quote character.
+    InvalidDelimiter(char),
+    UnterminatedQuote {
+        row: usize,
+        column: usize,
+    },
+    /// A closing quote is followed by something other than a delimiter or
+    /// the end of the record.
+    InvalidQuote {
+        row: usize,
+        column: usize,
+    },
+    InvalidNumber {
+        row: usize,
+        column: usize,
+        text: String,
+    },
+    EmptyCell {
+        row: usize,
+        column: usize,
+    },
+    NanValue {
+        row: usize,
+        column: usize,
+    },
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The number of header names given to [`write`] differs from the
+    /// number of columns.
+    HeaderLength {
+        expected: usize,
+        found: usize,
+    },
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {}", error),
+            Self::InvalidDelimiter(delimiter) => {
+                write!(f, "{:?} cannot be used as a delimiter", delimiter)
+            }
+            Self::UnterminatedQuote { row, column } => {
+                write!(f, "row {}, column {}: unterminated quote", row, column)
+            }
+            Self::InvalidQuote { row, column } => write!(
+                f,
+                "row {}, column {}: unexpected character after closing quote",
+                row, column
+            ),
+            Self::InvalidNumber { row, column, text } => write!(
+                f,
+                "row {}, column {}: invalid number '{}'",
+                row, column, text
+            ),
+            Self::EmptyCell { row, column } => {
+                write!(f, "row {}, column {}: empty cell", row, column)
+            }
+            Self::NanValue { row, column } => {
+                write!(f, "row {}, column {}: NaN is not allowed", row, column)
+            }
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {}: expected {} columns, found {}",
+                row, expected, found
+            ),
+            Self::HeaderLength { expected, found } => {
+                write!(f, "expected {} header names, found {}", expected, found)
+            }
+            Self::ShapeMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+fn check_options(options: CsvOptions) -> Result<(), CsvError> {
+    let delimiter = options.delimiter;
+    if delimiter == '\n' || delimiter == '\r' || Some(delimiter) == options.quote {
+        return Err(CsvError::InvalidDelimiter(delimiter));
+    }
+    Ok(())
+}
+
+struct Cell<'a> {
+    text: Cow<'a, str>,
+    quoted: bool,
+}
+
+// Splits the input into records of cells, tracking line numbers across
+// quoted line breaks.
+struct Records<'a> {
+    text: &'a str,
+    position: usize,
+    line: usize,
+    options: CsvOptions,
+}
+
+impl<'a> Records<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn is_blank(&self, c: char) -> bool {
+        c != self.options.delimiter && c != '\n' && c.is_whitespace()
+    }
+
+    fn skip_blanks(&mut self) {
+        while self.peek().is_some_and(|c| self.is_blank(c)) {
+            self.bump();
+        }
+    }
+
+    fn quoted(&mut self, quote: char, row: usize, column: usize) -> Result<Cell<'a>, CsvError> {
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(CsvError::UnterminatedQuote { row, column }),
+                Some(c) if c == quote => {
+                    if self.peek() == Some(quote) {
+                        self.bump();
+                        text.push(quote);
+                    } else {
+                        break;
+                    }
+                }
+                Some(c) => text.push(c),
+            }
+        }
+        self.skip_blanks();
+        match self.peek() {
+            None | Some('\n') => {}
+            Some(c) if c == self.options.delimiter => {}
+            Some(_) => return Err(CsvError::InvalidQuote { row, column }),
+        }
+        Ok(Cell {
+            text: Cow::Owned(text),
+            quoted: true,
+        })
+    }
+
+    fn unquoted(&mut self) -> Cell<'a> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c != self.options.delimiter && c != '\n')
+        {
+            self.bump();
+        }
+        let text = self.text[start..self.position].trim_matches(|c| self.is_blank(c));
+        Cell {
+            text: Cow::Borrowed(text),
+            quoted: false,
+        }
+    }
+
+    fn next_record(&mut self) -> Result<Option<(usize, Vec<Cell<'a>>)>, CsvError> {
+        loop {
+            if self.position == self.text.len() {
+                return Ok(None);
+            }
+            let row = self.line;
+            let mut cells = Vec::new();
+            loop {
+                self.skip_blanks();
+                let cell = match self.options.quote {
+                    Some(quote) if self.peek() == Some(quote) => {
+                        self.bump();
+                        self.quoted(quote, row, cells.len() + 1)?
+                    }
+                    _ => self.unquoted(),
+                };
+                cells.push(cell);
+                if self.peek() == Some(self.options.delimiter) {
+                    self.bump();
+                } else {
+                    self.bump();
+                    break;
+                }
+            }
+            let blank = matches!(&cells[..], [cell] if !cell.quoted && cell.text.is_empty());
+            if !blank {
+                return Ok(Some((row, cells)));
+            }
+        }
+    }
+}
+
+fn parse_cell(
+    cell: &Cell,
+    row: usize,
+    column: usize,
+    options: CsvOptions,
+) -> Result<f32, CsvError> {
+    if cell.text.is_empty() {
+        return match options.empty {
+            EmptyCells::Reject => Err(CsvError::EmptyCell { row, column }),
+            EmptyCells::Nan => Ok(f32::NAN),
+            EmptyCells::Zero => Ok(0.0),
+        };
+    }
+    let value: f32 = cell.text.parse().map_err(|_| CsvError::InvalidNumber {
+        row,
+        column,
+        text: cell.text.to_string(),
+    })?;
+    if value.is_nan() && options.nan == NanValues::Reject {
+        return Err(CsvError::NanValue { row, column });
+    }
+    Ok(value)
+}
+
+/// Reads delimited text into a [`DMatrix`], returning it together with the
+/// column names when [`CsvOptions::header`] is set.
+pub fn read<R: Read>(
+    mut reader: R,
+    options: CsvOptions,
+) -> Result<(Option<Vec<String>>, DMatrix), CsvError> {
+    check_options(options)?;
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    let mut records = Records {
+        text: &text,
+        position: 0,
+        line: 1,
+        options,
+    };
+
+    let mut header = None;
+    let mut columns = None;
+    if options.header
+        && let Some((_, cells)) = records.next_record()?
+    {
+        columns = Some(cells.len());
+        header = Some(
+            cells
+                .into_iter()
+                .map(|cell| cell.text.into_owned())
+                .collect(),
+        );
+    }
+
+    let mut rows = 0;
+    let mut data = Vec::new();
+    while let Some((row, cells)) = records.next_record()? {
+        let expected = *columns.get_or_insert(cells.len());
+        if cells.len() != expected {
+            return Err(CsvError::RaggedRow {
+                row,
+                expected,
+                found: cells.len(),
+            });
+        }
+        for (c, cell) in cells.iter().enumerate() {
+            data.push(parse_cell(cell, row, c + 1, options)?);
+        }
+        rows += 1;
+    }
+    let columns = if rows == 0 { 0 } else { columns.unwrap_or(0) };
+    Ok((header, DMatrix::new(rows, columns, data)))
+}
+
+/// Reads delimited text into a fixed-size [`Matrix`], failing if the data has
+/// a different shape. Any header row is discarded.
+pub fn read_matrix<const ROWS: usize, const COLUMNS: usize, R: Read>(
+    reader: R,
+    options: CsvOptions,
+) -> Result<Matrix<ROWS, COLUMNS>, CsvError> {
+    let (_, matrix) = read(reader, options)?;
+    if matrix.size() != (ROWS, COLUMNS) {
+        return Err(CsvError::ShapeMismatch {
+            expected: (ROWS, COLUMNS),
+            found: matrix.size(),
+        });
+    }
+    Ok(Matrix::from_fn(|r, c| matrix[r][c]))
+}
+
+fn write_name<W: Write>(writer: &mut W, name: &str, options: CsvOptions) -> Result<(), CsvError> {
+    let needs_quotes = name.starts_with(|c: char| c.is_whitespace())
+        || name.ends_with(|c: char| c.is_whitespace())
+        || name.contains(|c| c == options.delimiter || c == '\n' || Some(c) == options.quote);
+    match options.quote {
+        Some(quote) if needs_quotes => {
+            let escaped = name.replace(quote, &format!("{}{}", quote, quote));
+            write!(writer, "{}{}{}", quote, escaped, quote)?;
+        }
+        _ => write!(writer, "{}", name)?,
+    }
+    Ok(())
+}
+
+/// Writes `matrix` one row per line, preceded by `header` if given.
+///
+/// [`CsvOptions::header`] only affects reading. Values are written in their
+/// shortest round-trip form; NaN is written as `NaN`, or as an empty cell
+/// under [`EmptyCells::Nan`].
+pub fn write<W: Write>(
+    mut writer: W,
+    matrix: &DMatrix,
+    header: Option<&[&str]>,
+    options: CsvOptions,
+) -> Result<(), CsvError> {
+    check_options(options)?;
+    let (rows, columns) = matrix.size();
+    let mut line = 1;
+    if let Some(names) = header {
+        if names.len() != columns {
+            return Err(CsvError::HeaderLength {
+                expected: columns,
+                found: names.len(),
+            });
+        }
+        for (c, name) in names.iter().enumerate() {
+            if c > 0 {
+                write!(writer, "{}", options.delimiter)?;
+            }
+            write_name(&mut writer, name, options)?;
+        }
+        writeln!(writer)?;
+        line += 1;
+    }
+    for r in 0..rows {
+        for (c, &value) in matrix[r].iter().enumerate() {
+            if c > 0 {
+                write!(writer, "{}", options.delimiter)?;
+            }
+            if !value.is_nan() {
+                write!(writer, "{:?}", value)?;
+            } else if options.nan == NanValues::Reject {
+                return Err(CsvError::NanValue {
+                    row: line,
+                    column: c + 1,
+                });
+            } else if options.empty != EmptyCells::Nan {
+                write!(writer, "NaN")?;
+            } else if let (1, Some(quote)) = (columns, options.quote) {
+                // A bare empty cell would be an (ignored) blank line.
+                write!(writer, "{}{}", quote, quote)?;
+            } else if columns == 1 {
+                write!(writer, "NaN")?;
+            }
+        }
+        writeln!(writer)?;
+        line += 1;
+    }
+    Ok(())
+}
+
+/// Writes a fixed-size [`Matrix`]; see [`write`].
+pub fn write_matrix<const ROWS: usize, const COLUMNS: usize, W: Write>(
+    writer: W,
+    matrix: &Matrix<ROWS, COLUMNS>,
+    header: Option<&[&str]>,
+    options: CsvOptions,
+) -> Result<(), CsvError> {
+    write(writer, &DMatrix::from(*matrix), header, options)
+}
@@ -0,0 +1,576 @@
+//! Reading and writing the NIST [Matrix Market] exchange format.
+//!
+//! Both the `coordinate` and `array` formats are supported with `real`,
+//! `integer` and `pattern` fields and `general`, `symmetric` and
+//! `skew-symmetric` symmetry. Files are always read into a dense matrix;
+//! pattern entries become `1.0`.
+//!
+//! [Matrix Market]: https://math.nist.gov/MatrixMarket/formats.html
+
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Write},
+};
+
+use crate::linear_algebra::{dmatrix::DMatrix, matrix::Matrix};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Coordinate,
+    Array,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field {
+    Real,
+    Integer,
+    Pattern,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+/// The qualifiers from a `%%MatrixMarket matrix ...` banner line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub format: Format,
+    pub field: Field,
+    pub symmetry: Symmetry,
+}
+
+impl Header {
+    pub fn new(format: Format, field: Field, symmetry: Symmetry) -> Self {
+        Self {
+            format,
+            field,
+            symmetry,
+        }
+    }
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self::new(Format::Array, Field::Real, Symmetry::General)
+    }
+}
+
+#[derive(Debug)]
+pub enum MatrixMarketError {
+    Io(io::Error),
+    /// The first line is not a valid `%%MatrixMarket matrix ...` banner.
+    InvalidBanner {
+        line: usize,
+    },
+    /// A valid but unsupported banner qualifier, such as `complex` or `hermitian`.
+    Unsupported {
+        line: usize,
+        qualifier: String,
+    },
+    InvalidSize {
+        line: usize,
+    },
+    /// The size line declares a matrix that does not fit in available memory.
+    TooLarge {
+        line: usize,
+    },
+    InvalidEntry {
+        line: usize,
+    },
+    IndexOutOfBounds {
+        line: usize,
+        row: usize,
+        column: usize,
+    },
+    /// A symmetric or skew-symmetric file stores an entry above the diagonal
+    /// (or on it, for skew-symmetric matrices).
+    NotLowerTriangular {
+        line: usize,
+        row: usize,
+        column: usize,
+    },
+    DuplicateEntry {
+        line: usize,
+        row: usize,
+        column: usize,
+    },
+    EntryCount {
+        expected: usize,
+        found: usize,
+    },
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// The matrix cannot be written with the requested header, for example a
+    /// non-symmetric matrix as `symmetric` or a fractional value as `integer`.
+    NotRepresentable {
+        row: usize,
+        column: usize,
+    },
+}
+
+impl Display for MatrixMarketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {}", error),
+            Self::InvalidBanner { line } => {
+                write!(f, "line {}: invalid Matrix Market banner", line)
+            }
+            Self::Unsupported { line, qualifier } => {
+                write!(f, "line {}: unsupported qualifier '{}'", line, qualifier)
+            }
+            Self::InvalidSize { line } => write!(f, "line {}: invalid size line", line),
+            Self::TooLarge { line } => {
+                write!(f, "line {}: matrix is too large to allocate", line)
+            }
+            Self::InvalidEntry { line } => write!(f, "line {}: invalid entry", line),
+            Self::IndexOutOfBounds { line, row, column } => {
+                write!(
+                    f,
+                    "line {}: entry ({}, {}) is out of bounds",
+                    line, row, column
+                )
+            }
+            Self::NotLowerTriangular { line, row, column } => write!(
+                f,
+                "line {}: entry ({}, {}) is not in the stored lower triangle",
+                line, row, column
+            ),
+            Self::DuplicateEntry { line, row, column } => {
+                write!(f, "line {}: duplicate entry ({}, {})", line, row, column)
+            }
+            Self::EntryCount { expected, found } => {
+                write!(f, "expected {} entries, found {}", expected, found)
+            }
+            Self::ShapeMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Self::NotRepresentable { row, column } => write!(
+                f,
+                "entry ({}, {}) cannot be represented with the requested header",
+                row, column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MatrixMarketError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MatrixMarketError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+// Yields (line number, trimmed line) for every non-comment, non-blank line.
+struct Lines<R> {
+    reader: R,
+    line: usize,
+    buffer: String,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn next_raw(&mut self) -> Result<Option<&str>, MatrixMarketError> {
+        self.buffer.clear();
+        if self.reader.read_line(&mut self.buffer)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        Ok(Some(self.buffer.trim()))
+    }
+
+    fn next_data(&mut self) -> Result<Option<(usize, &str)>, MatrixMarketError> {
+        loop {
+            match self.next_raw()? {
+                None => return Ok(None),
+                Some(line) if line.is_empty() || line.starts_with('%') => continue,
+                Some(_) => return Ok(Some((self.line, self.buffer.trim()))),
+            }
+        }
+    }
+}
+
+fn parse_banner(line: &str) -> Result<Header, MatrixMarketError> {
+    let invalid = MatrixMarketError::InvalidBanner { line: 1 };
+    let unsupported = |qualifier: &str| MatrixMarketError::Unsupported {
+        line: 1,
+        qualifier: qualifier.to_string(),
+    };
+    let lower = line.to_ascii_lowercase();
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let [banner, object, format, field, symmetry] = words[..] else {
+        return Err(invalid);
+    };
+    if banner != "%%matrixmarket" {
+        return Err(invalid);
+    }
+    match object {
+        "matrix" => {}
+        "vector" => return Err(unsupported(object)),
+        _ => return Err(invalid),
+    }
+    let format = match format {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        _ => return Err(invalid),
+    };
+    let field = match field {
+        "real" | "double" => Field::Real,
+        "integer" => Field::Integer,
+        "pattern" => Field::Pattern,
+        "complex" => return Err(unsupported(field)),
+        _ => return Err(invalid),
+    };
+    let symmetry = match symmetry {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => return Err(unsupported(symmetry)),
+        _ => return Err(invalid),
+    };
+    // The specification only allows `pattern` for coordinate data without skew symmetry.
+    if field == Field::Pattern && (format == Format::Array || symmetry == Symmetry::SkewSymmetric) {
+        return Err(invalid);
+    }
+    Ok(Header::new(format, field, symmetry))
+}
+
+fn parse_usizes<const N: usize>(
+    line: &str,
+    number: usize,
+) -> Result<[usize; N], MatrixMarketError> {
+    let mut values = [0; N];
+    let mut words = line.split_whitespace();
+    for value in values.iter_mut() {
+        *value = words
+            .next()
+            .and_then(|word| word.parse().ok())
+            .ok_or(MatrixMarketError::InvalidSize { line: number })?;
+    }
+    if words.next().is_some() {
+        return Err(MatrixMarketError::InvalidSize { line: number });
+    }
+    Ok(values)
+}
+
+fn parse_value(word: Option<&str>, field: Field, line: usize) -> Result<f32, MatrixMarketError> {
+    let invalid = MatrixMarketError::InvalidEntry { line };
+    let word = word.ok_or(MatrixMarketError::InvalidEntry { line })?;
+    match field {
+        Field::Real => word.parse().map_err(|_| invalid),
+        Field::Integer => word.parse::<i64>().map(|v| v as f32).map_err(|_| invalid),
+        Field::Pattern => Err(invalid),
+    }
+}
+
+// Stores `value` at (row, column) and its mirror image for symmetric storage.
+// Like `vec![value; len]`, but returns `None` instead of aborting when the
+// allocation fails.
+fn filled<T: Clone>(len: usize, value: T) -> Option<Vec<T>> {
+    let mut data = Vec::new();
+    data.try_reserve_exact(len).ok()?;
+    data.resize(len, value);
+    Some(data)
+}
+
+fn store(matrix: &mut DMatrix, symmetry: Symmetry, row: usize, column: usize, value: f32) {
+    matrix[row][column] = value;
+    match symmetry {
+        Symmetry::General => {}
+        Symmetry::Symmetric => matrix[column][row] = value,
+        Symmetry::SkewSymmetric => matrix[column][row] = -value,
+    }
+}
+
+fn check_triangle(
+    symmetry: Symmetry,
+    row: usize,
+    column: usize,
+    line: usize,
+) -> Result<(), MatrixMarketError> {
+    let stored = match symmetry {
+        Symmetry::General => true,
+        Symmetry::Symmetric => row >= column,
+        Symmetry::SkewSymmetric => row > column,
+    };
+    if stored {
+        Ok(())
+    } else {
+        Err(MatrixMarketError::NotLowerTriangular {
+            line,
+            row: row + 1,
+            column: column + 1,
+        })
+    }
+}
+
+/// Reads a Matrix Market file into a dense [`DMatrix`], returning it together
+/// with the parsed banner.
+pub fn read<R: BufRead>(reader: R) -> Result<(Header, DMatrix), MatrixMarketError> {
+    let mut lines = Lines {
+        reader,
+        line: 0,
+        buffer: String::new(),
+    };
+    let banner = lines
+        .next_raw()?
+        .ok_or(MatrixMarketError::InvalidBanner { line: 1 })?;
+    let header = parse_banner(banner)?;
+
+    let missing_size = MatrixMarketError::InvalidSize {
+        line: lines.line + 1,
+    };
+    let (size_line, size) = lines.next_data()?.ok_or(missing_size)?;
+    let (rows, columns, expected) = match header.format {
+        Format::Coordinate => {
+            let [rows, columns, entries] = parse_usizes::<3>(size, size_line)?;
+            (rows, columns, entries)
+        }
+        Format::Array => {
+            let [rows, columns] = parse_usizes::<2>(size, size_line)?;
+            let entries = match header.symmetry {
+                Symmetry::General => rows.checked_mul(columns),
+                Symmetry::Symmetric => columns
+                    .checked_add(1)
+                    .and_then(|n| n.checked_mul(columns))
+                    .map(|n| n / 2),
+                Symmetry::SkewSymmetric => columns
+                    .checked_mul(columns.saturating_sub(1))
+                    .map(|n| n / 2),
+            };
+            let entries = entries.ok_or(MatrixMarketError::InvalidSize { line: size_line })?;
+            (rows, columns, entries)
+        }
+    };
+    if header.symmetry != Symmetry::General && rows != columns {
+        return Err(MatrixMarketError::InvalidSize { line: size_line });
+    }
+    // The dense matrix must fit in memory that can be addressed at all.
+    let elements = rows
+        .checked_mul(columns)
+        .filter(|&elements| elements <= isize::MAX as usize / size_of::<f32>());
+    if elements.is_none() {
+        return Err(MatrixMarketError::InvalidSize { line: size_line });
+    }
+
+    let too_large = || MatrixMarketError::TooLarge { line: size_line };
+    let data = filled(rows * columns, 0.0).ok_or_else(too_large)?;
+    let mut matrix = DMatrix::new(rows, columns, data);
+    // Only coordinate entries name their position, so only they can repeat one.
+    let mut seen = match header.format {
+        Format::Coordinate => filled(rows * columns, false).ok_or_else(too_large)?,
+        Format::Array => Vec::new(),
+    };
+    let mut found = 0;
+    // Array entries are listed column by column, covering only the stored triangle.
+    let mut next_position = match header.symmetry {
+        Symmetry::SkewSymmetric => (1, 0),
+        _ => (0, 0),
+    };
+    while let Some((line, entry)) = lines.next_data()? {
+        if found == expected {
+            return Err(MatrixMarketError::EntryCount {
+                expected,
+                found: found + 1,
+            });
+        }
+        let mut words = entry.split_whitespace();
+        match header.format {
+            Format::Coordinate => {
+                let mut index = || {
+                    words
+                        .next()
+                        .and_then(|word| word.parse::<usize>().ok())
+                        .ok_or(MatrixMarketError::InvalidEntry { line })
+                };
+                let (row, column) = (index()?, index()?);
+                if row == 0 || column == 0 || row > rows || column > columns {
+                    return Err(MatrixMarketError::IndexOutOfBounds { line, row, column });
+                }
+                let (row, column) = (row - 1, column - 1);
+                check_triangle(header.symmetry, row, column, line)?;
+                let value = match header.field {
+                    Field::Pattern => 1.0,
+                    field => parse_value(words.next(), field, line)?,
+                };
+                if std::mem::replace(&mut seen[row * columns + column], true) {
+                    return Err(MatrixMarketError::DuplicateEntry {
+                        line,
+                        row: row + 1,
+                        column: column + 1,
+                    });
+                }
+                store(&mut matrix, header.symmetry, row, column, value);
+            }
+            Format::Array => {
+                let value = parse_value(words.next(), header.field, line)?;
+                let (row, column) = next_position;
+                store(&mut matrix, header.symmetry, row, column, value);
+                next_position = if row + 1 < rows {
+                    (row + 1, column)
+                } else {
+                    let column = column + 1;
+                    match header.symmetry {
+                        Symmetry::General => (0, column),
+                        Symmetry::Symmetric => (column, column),
+                        Symmetry::SkewSymmetric => (column + 1, column),
+                    }
+                };
+            }
+        }
+        if words.next().is_some() {
+            return Err(MatrixMarketError::InvalidEntry { line });
+        }
+        found += 1;
+    }
+    if found != expected {
+        return Err(MatrixMarketError::EntryCount { expected, found });
+    }
+
+    Ok((header, matrix))
+}
+
+/// Reads a Matrix Market file into a fixed-size [`Matrix`], failing if the
+/// file declares a different shape.
+pub fn read_matrix<const ROWS: usize, const COLUMNS: usize, R: BufRead>(
+    reader: R,
+) -> Result<Matrix<ROWS, COLUMNS>, MatrixMarketError> {
+    let (_, matrix) = read(reader)?;
+    if matrix.size() != (ROWS, COLUMNS) {
+        return Err(MatrixMarketError::ShapeMismatch {
+            expected: (ROWS, COLUMNS),
+            found: matrix.size(),
+        });
+    }
+    Ok(Matrix::from_fn(|r, c| matrix[r][c]))
+}
+
+fn check_representable(matrix: &DMatrix, header: Header) -> Result<(), MatrixMarketError> {
+    let (rows, columns) = matrix.size();
+    if header.symmetry != Symmetry::General && rows != columns {
+        return Err(MatrixMarketError::ShapeMismatch {
+            expected: (columns, columns),
+            found: (rows, columns),
+        });
+    }
+    for r in 0..rows {
+        for c in 0..columns {
+            let value = matrix[r][c];
+            let valid = match header.symmetry {
+                Symmetry::General => true,
+                Symmetry::Symmetric => value == matrix[c][r],
+                Symmetry::SkewSymmetric => value == -matrix[c][r],
+            } && match header.field {
+                Field::Real => true,
+                Field::Integer => value.is_finite() && value.fract() == 0.0,
+                Field::Pattern => value == 0.0 || value == 1.0,
+            };
+            if !valid {
+                return Err(MatrixMarketError::NotRepresentable {
+                    row: r + 1,
+                    column: c + 1,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn format_banner(header: Header) -> String {
+    let format = match header.format {
+        Format::Coordinate => "coordinate",
+        Format::Array => "array",
+    };
+    let field = match header.field {
+        Field::Real => "real",
+        Field::Integer => "integer",
+        Field::Pattern => "pattern",
+    };
+    let symmetry = match header.symmetry {
+        Symmetry::General => "general",
+        Symmetry::Symmetric => "symmetric",
+        Symmetry::SkewSymmetric => "skew-symmetric",
+    };
+    format!("%%MatrixMarket matrix {} {} {}", format, field, symmetry)
+}
+
+/// Writes `matrix` in the format described by `header`, failing if the matrix
+/// cannot be represented exactly (see [`MatrixMarketError::NotRepresentable`]).
+pub fn write<W: Write>(
+    mut writer: W,
+    matrix: &DMatrix,
+    header: Header,
+) -> Result<(), MatrixMarketError> {
+    if header.field == Field::Pattern
+        && (header.format == Format::Array || header.symmetry == Symmetry::SkewSymmetric)
+    {
+        return Err(MatrixMarketError::Unsupported {
+            line: 1,
+            qualifier: "pattern".to_string(),
+        });
+    }
+    check_representable(matrix, header)?;
+
+    let (rows, columns) = matrix.size();
+    // Entries in the stored triangle, in column-major order.
+    let stored: Vec<(usize, usize)> = (0..columns)
+        .flat_map(|c| (0..rows).map(move |r| (r, c)))
+        .filter(|&(r, c)| match header.symmetry {
+            Symmetry::General => true,
+            Symmetry::Symmetric => r >= c,
+            Symmetry::SkewSymmetric => r > c,
+        })
+        .collect();
+
+    writeln!(writer, "{}", format_banner(header))?;
+    match header.format {
+        Format::Coordinate => {
+            let entries: Vec<_> = stored
+                .into_iter()
+                .filter(|&(r, c)| matrix[r][c] != 0.0)
+                .collect();
+            writeln!(writer, "{} {} {}", rows, columns, entries.len())?;
+            for (r, c) in entries {
+                match header.field {
+                    Field::Pattern => writeln!(writer, "{} {}", r + 1, c + 1)?,
+                    Field::Integer => {
+                        writeln!(writer, "{} {} {}", r + 1, c + 1, matrix[r][c] as i64)?
+                    }
+                    Field::Real => writeln!(writer, "{} {} {:?}", r + 1, c + 1, matrix[r][c])?,
+                }
+            }
+        }
+        Format::Array => {
+            writeln!(writer, "{} {}", rows, columns)?;
+            for (r, c) in stored {
+                match header.field {
+                    Field::Integer => writeln!(writer, "{}", matrix[r][c] as i64)?,
+                    _ => writeln!(writer, "{:?}", matrix[r][c])?,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes a fixed-size [`Matrix`]; see [`write`].
+pub fn write_matrix<const ROWS: usize, const COLUMNS: usize, W: Write>(
+    writer: W,
+    matrix: &Matrix<ROWS, COLUMNS>,
+    header: Header,
+) -> Result<(), MatrixMarketError> {
+    write(writer, &DMatrix::from(*matrix), header)
+}
@@ -0,0 +1,3 @@
+pub mod csv;
+pub mod matrix_market;
+pub mod npy;
@@ -0,0 +1,686 @@
+//! NumPy `.npy` arrays and uncompressed `.npz` archives.
+//!
+//! Arrays of little- or big-endian `f32`/`f64` in C or Fortran order can be
+//! read; `f64` values are rounded to `f32`. Any other dtype is rejected with
+//! [`NpyError::UnsupportedDtype`]. Arrays are written in format version 1.0,
+//! with the dtype and order chosen through [`NpyOptions`].
+
+use std::{
+    fmt::Display,
+    io::{self, Read, Seek, SeekFrom, Write},
+};
+
+use crate::{
+    crc32::crc32,
+    linear_algebra::{
+        dmatrix::DMatrix,
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+    },
+};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DType {
+    F32(Endian),
+    F64(Endian),
+}
+
+impl DType {
+    fn descr(&self) -> &'static str {
+        match self {
+            Self::F32(Endian::Little) => "<f4",
+            Self::F32(Endian::Big) => ">f4",
+            Self::F64(Endian::Little) => "<f8",
+            Self::F64(Endian::Big) => ">f8",
+        }
+    }
+
+    fn from_descr(descr: &str) -> Option<Self> {
+        let native = if cfg!(target_endian = "big") {
+            Endian::Big
+        } else {
+            Endian::Little
+        };
+        let (endian, kind) = match descr.split_at_checked(1)? {
+            ("<", kind) => (Endian::Little, kind),
+            (">", kind) => (Endian::Big, kind),
+            ("=", kind) => (native, kind),
+            _ => return None,
+        };
+        match kind {
+            "f4" => Some(Self::F32(endian)),
+            "f8" => Some(Self::F64(endian)),
+            _ => None,
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Self::F32(_) => 4,
+            Self::F64(_) => 8,
+        }
+    }
+
+    fn decode(&self, bytes: &[u8]) -> f32 {
+        match *self {
+            Self::F32(Endian::Little) => f32::from_le_bytes(bytes.try_into().unwrap()),
+            Self::F32(Endian::Big) => f32::from_be_bytes(bytes.try_into().unwrap()),
+            Self::F64(Endian::Little) => f64::from_le_bytes(bytes.try_into().unwrap()) as f32,
+            Self::F64(Endian::Big) => f64::from_be_bytes(bytes.try_into().unwrap()) as f32,
+        }
+    }
+
+    fn encode(&self, value: f32, out: &mut Vec<u8>) {
+        match *self {
+            Self::F32(Endian::Little) => out.extend_from_slice(&value.to_le_bytes()),
+            Self::F32(Endian::Big) => out.extend_from_slice(&value.to_be_bytes()),
+            Self::F64(Endian::Little) => out.extend_from_slice(&(value as f64).to_le_bytes()),
+            Self::F64(Endian::Big) => out.extend_from_slice(&(value as f64).to_be_bytes()),
+        }
+    }
+}
+
+/// Memory layout of a multi-dimensional array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    /// Row-major.
+    C,
+    /// Column-major.
+    Fortran,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NpyOptions {
+    pub dtype: DType,
+    pub order: Order,
+}
+
+impl Default for NpyOptions {
+    fn default() -> Self {
+        Self {
+            dtype: DType::F32(Endian::Little),
+            order: Order::C,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum NpyError {
+    Io(io::Error),
+    InvalidMagic,
+    UnsupportedVersion(u8, u8),
+    InvalidHeader,
+    UnsupportedDtype(String),
+    /// The file ends before all elements declared by its shape were read.
+    TruncatedData,
+    ShapeMismatch {
+        expected: Vec<usize>,
+        found: Vec<usize>,
+    },
+    InvalidArchive,
+    UnsupportedCompression {
+        name: String,
+        method: u16,
+    },
+    ChecksumMismatch {
+        name: String,
+    },
+    MissingArray(String),
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {}", error),
+            Self::InvalidMagic => write!(f, "not a .npy file"),
+            Self::UnsupportedVersion(major, minor) => {
+                write!(f, "unsupported .npy format version {}.{}", major, minor)
+            }
+            Self::InvalidHeader => write!(f, "invalid .npy header"),
+            Self::UnsupportedDtype(descr) => write!(f, "unsupported dtype '{}'", descr),
+            Self::TruncatedData => write!(f, "array data is truncated"),
+            Self::ShapeMismatch { expected, found } => {
+                write!(f, "expected shape {:?}, found {:?}", expected, found)
+            }
+            Self::InvalidArchive => write!(f, "invalid .npz archive"),
+            Self::UnsupportedCompression { name, method } => write!(
+                f,
+                "'{}' uses compression method {}, only stored entries are supported",
+                name, method
+            ),
+            Self::ChecksumMismatch { name } => write!(f, "checksum mismatch for '{}'", name),
+            Self::MissingArray(name) => write!(f, "archive has no array named '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(error: io::Error) -> Self {
+        if error.kind() == io::ErrorKind::UnexpectedEof {
+            Self::TruncatedData
+        } else {
+            Self::Io(error)
+        }
+    }
+}
+
+/// An array read from a `.npy` file. `data` is always stored in C order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NpyArray {
+    pub shape: Vec<usize>,
+    pub data: Vec<f32>,
+}
+
+impl NpyArray {
+    pub fn to_dmatrix(&self) -> Result<DMatrix, NpyError> {
+        match self.shape[..] {
+            [rows, columns] => Ok(DMatrix::new(rows, columns, self.data.clone())),
+            [rows] => Ok(DMatrix::new(rows, 1, self.data.clone())),
+            _ => Err(self.mismatch(&[])),
+        }
+    }
+
+    /// Converts a 2-D array of shape `(ROWS, COLUMNS)`, or a 1-D array of
+    /// length `ROWS` when `COLUMNS` is 1.
+    pub fn to_matrix<const ROWS: usize, const COLUMNS: usize>(
+        &self,
+    ) -> Result<Matrix<ROWS, COLUMNS>, NpyError> {
+        let fits = match self.shape[..] {
+            [rows, columns] => (rows, columns) == (ROWS, COLUMNS),
+            [rows] => (rows, 1) == (ROWS, COLUMNS),
+            _ => false,
+        };
+        if !fits {
+            return Err(self.mismatch(&[ROWS, COLUMNS]));
+        }
+        Ok(Matrix::from_fn(|r, c| self.data[r * COLUMNS + c]))
+    }
+
+    pub fn to_vector2(&self) -> Result<Vector2, NpyError> {
+        let [x, y] = self.to_components()?;
+        Ok(Vector2::new(x, y))
+    }
+
+    pub fn to_vector3(&self) -> Result<Vector3, NpyError> {
+        let [x, y, z] = self.to_components()?;
+        Ok(Vector3::new(x, y, z))
+    }
+
+    // Accepts shapes (N,), (N, 1) and (1, N).
+    fn to_components<const N: usize>(&self) -> Result<[f32; N], NpyError> {
+        let fits = matches!(self.shape[..], [n] | [n, 1] | [1, n] if n == N);
+        if !fits {
+            return Err(self.mismatch(&[N]));
+        }
+        Ok(self.data[..].try_into().unwrap())
+    }
+
+    fn mismatch(&self, expected: &[usize]) -> NpyError {
+        NpyError::ShapeMismatch {
+            expected: expected.to_vec(),
+            found: self.shape.clone(),
+        }
+    }
+}
+
+struct Header {
+    dtype: DType,
+    order: Order,
+    shape: Vec<usize>,
+}
+
+// Minimal parser for the Python dict literal in the header, e.g.
+// `{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }`.
+fn parse_header(text: &str) -> Result<Header, NpyError> {
+    let invalid = || NpyError::InvalidHeader;
+    let body = text
+        .trim()
+        .strip_prefix('{')
+        .and_then(|t| t.strip_suffix('}'))
+        .ok_or_else(invalid)?;
+
+    let value_after = |key: &str| -> Result<&str, NpyError> {
+        let start = body
+            .find(&format!("'{}'", key))
+            .or_else(|| body.find(&format!("\"{}\"", key)))
+            .ok_or_else(invalid)?;
+        let rest = body[start + key.len() + 2..].trim_start();
+        Ok(rest.strip_prefix(':').ok_or_else(invalid)?.trim_start())
+    };
+
+    let descr = value_after("descr")?;
+    let quote = descr
+        .chars()
+        .next()
+        .filter(|&c| c == '\'' || c == '"')
+        .ok_or_else(invalid)?;
+    let descr = &descr[1..];
+    let descr = &descr[..descr.find(quote).ok_or_else(invalid)?];
+    let dtype =
+        DType::from_descr(descr).ok_or_else(|| NpyError::UnsupportedDtype(descr.to_string()))?;
+
+    let fortran = value_after("fortran_order")?;
+    let order = if fortran.starts_with("True") {
+        Order::Fortran
+    } else if fortran.starts_with("False") {
+        Order::C
+    } else {
+        return Err(invalid());
+    };
+
+    let shape = value_after("shape")?
+        .strip_prefix('(')
+        .ok_or_else(invalid)?;
+    let shape = &shape[..shape.find(')').ok_or_else(invalid)?];
+    let shape = shape
+        .split(',')
+        .map(str::trim)
+        .filter(|dimension| !dimension.is_empty())
+        .map(|dimension| dimension.parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    Ok(Header {
+        dtype,
+        order,
+        shape,
+    })
+}
+
+// Row-major storage index of each element, visited in Fortran (column-major) order.
+fn fortran_order_indices(shape: &[usize]) -> impl Iterator<Item = usize> + '_ {
+    let mut c_strides = vec![1; shape.len()];
+    for k in (0..shape.len().saturating_sub(1)).rev() {
+        c_strides[k] = c_strides[k + 1] * shape[k + 1];
+    }
+    (0..shape.iter().product()).map(move |i: usize| {
+        let mut remainder = i;
+        shape
+            .iter()
+            .zip(&c_strides)
+            .fold(0, |index, (&dimension, &stride)| {
+                let position = remainder % dimension;
+                remainder /= dimension;
+                index + position * stride
+            })
+    })
+}
+
+// Reads exactly `length` bytes. The buffer grows as data arrives, so a corrupt
+// length fails with `TruncatedData` instead of allocating it up front.
+fn read_bytes<R: Read>(reader: &mut R, length: u64) -> Result<Vec<u8>, NpyError> {
+    let mut bytes = Vec::new();
+    reader.take(length).read_to_end(&mut bytes)?;
+    if (bytes.len() as u64) < length {
+        return Err(NpyError::TruncatedData);
+    }
+    Ok(bytes)
+}
+
+/// Reads a single `.npy` array.
+pub fn read<R: Read>(mut reader: R) -> Result<NpyArray, NpyError> {
+    let mut magic = [0; 6];
+    reader
+        .read_exact(&mut magic)
+        .map_err(|_| NpyError::InvalidMagic)?;
+    if &magic != MAGIC {
+        return Err(NpyError::InvalidMagic);
+    }
+    let mut version = [0; 2];
+    reader.read_exact(&mut version)?;
+    let header_len = match version {
+        [1, 0] => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        [2, 0] | [3, 0] => {
+            let mut len = [0; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        [major, minor] => return Err(NpyError::UnsupportedVersion(major, minor)),
+    };
+    let header = read_bytes(&mut reader, header_len as u64)?;
+    let header = std::str::from_utf8(&header).map_err(|_| NpyError::InvalidHeader)?;
+    let Header {
+        dtype,
+        order,
+        shape,
+    } = parse_header(header)?;
+
+    // Bounding the product of the nonzero dimensions also keeps the strides
+    // of a Fortran-order array in range.
+    shape
+        .iter()
+        .filter(|&&dimension| dimension != 0)
+        .try_fold(1usize, |count, &dimension| count.checked_mul(dimension))
+        .ok_or(NpyError::InvalidHeader)?;
+    let count: usize = shape.iter().product();
+    let length = count
+        .checked_mul(dtype.size())
+        .ok_or(NpyError::InvalidHeader)?;
+    let bytes = read_bytes(&mut reader, length as u64)?;
+    let values = bytes
+        .chunks_exact(dtype.size())
+        .map(|chunk| dtype.decode(chunk));
+    let data = match order {
+        Order::C => values.collect(),
+        Order::Fortran => {
+            let mut data = vec![0.0; count];
+            for (index, value) in fortran_order_indices(&shape).zip(values) {
+                data[index] = value;
+            }
+            data
+        }
+    };
+
+    Ok(NpyArray { shape, data })
+}
+
+pub fn read_dmatrix<R: Read>(reader: R) -> Result<DMatrix, NpyError> {
+    read(reader)?.to_dmatrix()
+}
+
+pub fn read_matrix<const ROWS: usize, const COLUMNS: usize, R: Read>(
+    reader: R,
+) -> Result<Matrix<ROWS, COLUMNS>, NpyError> {
+    read(reader)?.to_matrix()
+}
+
+pub fn read_vector2<R: Read>(reader: R) -> Result<Vector2, NpyError> {
+    read(reader)?.to_vector2()
+}
+
+pub fn read_vector3<R: Read>(reader: R) -> Result<Vector3, NpyError> {
+    read(reader)?.to_vector3()
+}
+
+/// Writes an array given its shape and row-major `data`.
+pub fn write<W: Write>(
+    mut writer: W,
+    shape: &[usize],
+    data: &[f32],
+    options: NpyOptions,
+) -> Result<(), NpyError> {
+    let count: usize = shape.iter().product();
+    if count != data.len() {
+        return Err(NpyError::ShapeMismatch {
+            expected: shape.to_vec(),
+            found: vec![data.len()],
+        });
+    }
+
+    let dimensions: Vec<String> = shape.iter().map(usize::to_string).collect();
+    let shape_text = match dimensions.len() {
+        1 => format!("({},)", dimensions[0]),
+        _ => format!("({})", dimensions.join(", ")),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+        options.dtype.descr(),
+        if options.order == Order::Fortran {
+            "True"
+        } else {
+            "False"
+        },
+        shape_text
+    );
+    // Pad with spaces so the data starts on a 64-byte boundary.
+    let unpadded = MAGIC.len() + 2 + 2 + header.len() + 1;
+    header.extend(std::iter::repeat_n(
+        ' ',
+        unpadded.next_multiple_of(64) - unpadded,
+    ));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(unpadded + count * options.dtype.size());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    match options.order {
+        Order::C => data
+            .iter()
+            .for_each(|&v| options.dtype.encode(v, &mut bytes)),
+        Order::Fortran => {
+            fortran_order_indices(shape).for_each(|i| options.dtype.encode(data[i], &mut bytes))
+        }
+    }
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+pub fn write_dmatrix<W: Write>(
+    writer: W,
+    matrix: &DMatrix,
+    options: NpyOptions,
+) -> Result<(), NpyError> {
+    let (rows, columns) = matrix.size();
+    write(writer, &[rows, columns], matrix.as_slice(), options)
+}
+
+pub fn write_matrix<const ROWS: usize, const COLUMNS: usize, W: Write>(
+    writer: W,
+    matrix: &Matrix<ROWS, COLUMNS>,
+    options: NpyOptions,
+) -> Result<(), NpyError> {
+    write(
+        writer,
+        &[ROWS, COLUMNS],
+        matrix.as_array().as_flattened(),
+        options,
+    )
+}
+
+/// Writes the vector as a 1-D array of length 2.
+pub fn write_vector2<W: Write>(
+    writer: W,
+    vector: &Vector2,
+    options: NpyOptions,
+) -> Result<(), NpyError> {
+    write(writer, &[2], &[vector.x, vector.y], options)
+}
+
+/// Writes the vector as a 1-D array of length 3.
+pub fn write_vector3<W: Write>(
+    writer: W,
+    vector: &Vector3,
+    options: NpyOptions,
+) -> Result<(), NpyError> {
+    write(writer, &[3], &[vector.x, vector.y, vector.z], options)
+}
+
+struct Entry {
+    name: String,
+    method: u16,
+    crc: u32,
+    size: u64,
+    offset: u64,
+}
+
+fn u16_at(bytes: &[u8], at: usize) -> Result<u16, NpyError> {
+    let slice = bytes.get(at..at + 2).ok_or(NpyError::InvalidArchive)?;
+    Ok(u16::from_le_bytes(slice.try_into().unwrap()))
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> Result<u32, NpyError> {
+    let slice = bytes.get(at..at + 4).ok_or(NpyError::InvalidArchive)?;
+    Ok(u32::from_le_bytes(slice.try_into().unwrap()))
+}
+
+fn u64_at(bytes: &[u8], at: usize) -> Result<u64, NpyError> {
+    let slice = bytes.get(at..at + 8).ok_or(NpyError::InvalidArchive)?;
+    Ok(u64::from_le_bytes(slice.try_into().unwrap()))
+}
+
+/// Reader for `.npz` archives written by `numpy.savez` (not `savez_compressed`).
+pub struct NpzReader<R> {
+    reader: R,
+    length: u64,
+    entries: Vec<Entry>,
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    pub fn new(mut reader: R) -> Result<Self, NpyError> {
+        const END_OF_DIRECTORY: u32 = 0x0605_4b50;
+        const DIRECTORY_ENTRY: u32 = 0x0201_4b50;
+        const ZIP64_END_OF_DIRECTORY: u32 = 0x0606_4b50;
+
+        // The end-of-central-directory record is within the last 64 KiB + 22 bytes.
+        let length = reader.seek(SeekFrom::End(0))?;
+        let tail_length = length.min(65_557);
+        reader.seek(SeekFrom::Start(length - tail_length))?;
+        let mut tail = vec![0; tail_length as usize];
+        reader.read_exact(&mut tail)?;
+        let end = (0..tail.len().saturating_sub(21))
+            .rev()
+            .find(|&i| u32_at(&tail, i).ok() == Some(END_OF_DIRECTORY))
+            .ok_or(NpyError::InvalidArchive)?;
+        let mut count = u16_at(&tail, end + 10)? as u64;
+        let mut directory_size = u32_at(&tail, end + 12)? as u64;
+        let mut directory_offset = u32_at(&tail, end + 16)? as u64;
+
+        // A zip64 end-of-central-directory record (and its 20-byte locator) may precede it.
+        if directory_offset == u32::MAX as u64 || count == u16::MAX as u64 {
+            let record = end.checked_sub(20 + 56).ok_or(NpyError::InvalidArchive)?;
+            if u32_at(&tail, record)? != ZIP64_END_OF_DIRECTORY {
+                return Err(NpyError::InvalidArchive);
+            }
+            count = u64_at(&tail, record + 32)?;
+            directory_size = u64_at(&tail, record + 40)?;
+            directory_offset = u64_at(&tail, record + 48)?;
+        }
+
+        if directory_offset
+            .checked_add(directory_size)
+            .is_none_or(|end| end > length)
+        {
+            return Err(NpyError::TruncatedData);
+        }
+        reader.seek(SeekFrom::Start(directory_offset))?;
+        let mut directory = vec![0; directory_size as usize];
+        reader.read_exact(&mut directory)?;
+
+        let mut entries = Vec::new();
+        let mut at = 0;
+        for _ in 0..count {
+            if u32_at(&directory, at)? != DIRECTORY_ENTRY {
+                return Err(NpyError::InvalidArchive);
+            }
+            let method = u16_at(&directory, at + 10)?;
+            let crc = u32_at(&directory, at + 16)?;
+            let mut size = u32_at(&directory, at + 24)? as u64;
+            let name_length = u16_at(&directory, at + 28)? as usize;
+            let extra_length = u16_at(&directory, at + 30)? as usize;
+            let comment_length = u16_at(&directory, at + 32)? as usize;
+            let mut offset = u32_at(&directory, at + 42)? as u64;
+            let name = directory
+                .get(at + 46..at + 46 + name_length)
+                .ok_or(NpyError::InvalidArchive)?;
+            let name = String::from_utf8_lossy(name).into_owned();
+
+            // Fields that overflowed 32 bits live in the zip64 extra field, in order.
+            let extra = directory
+                .get(at + 46 + name_length..at + 46 + name_length + extra_length)
+                .ok_or(NpyError::InvalidArchive)?;
+            let mut field = 0;
+            while field + 4 <= extra.len() {
+                let id = u16_at(extra, field)?;
+                let length = u16_at(extra, field + 2)? as usize;
+                if id == 0x0001 {
+                    let mut value = field + 4;
+                    if size == u32::MAX as u64 {
+                        size = u64_at(extra, value)?;
+                        value += 8;
+                    }
+                    if u32_at(&directory, at + 20)? == u32::MAX {
+                        value += 8;
+                    }
+                    if offset == u32::MAX as u64 {
+                        offset = u64_at(extra, value)?;
+                    }
+                }
+                field += 4 + length;
+            }
+
+            entries.push(Entry {
+                name,
+                method,
+                crc,
+                size,
+                offset,
+            });
+            at += 46 + name_length + extra_length + comment_length;
+        }
+
+        Ok(Self {
+            reader,
+            length,
+            entries,
+        })
+    }
+
+    /// Names of the stored arrays, without the `.npy` suffix.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .map(|entry| entry.name.strip_suffix(".npy").unwrap_or(&entry.name))
+    }
+
+    pub fn array(&mut self, name: &str) -> Result<NpyArray, NpyError> {
+        const LOCAL_HEADER: u32 = 0x0403_4b50;
+
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.name == name || entry.name.strip_suffix(".npy") == Some(name))
+            .ok_or_else(|| NpyError::MissingArray(name.to_string()))?;
+        if entry.method != 0 {
+            return Err(NpyError::UnsupportedCompression {
+                name: entry.name.clone(),
+                method: entry.method,
+            });
+        }
+
+        self.reader.seek(SeekFrom::Start(entry.offset))?;
+        let mut local = [0; 30];
+        self.reader.read_exact(&mut local)?;
+        if u32_at(&local, 0)? != LOCAL_HEADER {
+            return Err(NpyError::InvalidArchive);
+        }
+        let skip = u16_at(&local, 26)? as i64 + u16_at(&local, 28)? as i64;
+        let start = self.reader.seek(SeekFrom::Current(skip))?;
+        if start
+            .checked_add(entry.size)
+            .is_none_or(|end| end > self.length)
+        {
+            return Err(NpyError::TruncatedData);
+        }
+
+        let mut bytes = vec![0; entry.size as usize];
+        self.reader.read_exact(&mut bytes)?;
+        if crc32(&bytes) != entry.crc {
+            return Err(NpyError::ChecksumMismatch {
+                name: entry.name.clone(),
+            });
+        }
+        read(bytes.as_slice())
+    }
+}
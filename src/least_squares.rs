@@ -0,0 +1,373 @@
+//! Nonlinear least squares.
+//!
+//! [`levenberg_marquardt`] and [`gauss_newton`] minimize `½‖r(x)‖²` for a
+//! residual function `r` from `N` parameters to `M` residuals. The Jacobian
+//! of `r` is optional; without it, central differences are used. Both return
+//! a [`Fit`] with the parameters, an estimate of their covariance and the
+//! same [`Termination`] reasons as the [`optimize`](crate::optimize)
+//! methods.
+//!
+//! ```
+//! # use minmath::{least_squares::{levenberg_marquardt, LeastSquaresOptions}, linear_algebra::matrix::Matrix};
+//! // Fit `y = a e^(b t)` to samples of `2 e^(-t / 2)`.
+//! let t = [0.0, 1.0, 2.0, 3.0, 4.0];
+//! let y = t.map(|t: f32| 2.0 * (-0.5 * t).exp());
+//! let fit = levenberg_marquardt(
+//!     |p: &Matrix<2, 1>| Matrix::<5, 1>::from_fn(|i, _| p[0][0] * (p[1][0] * t[i]).exp() - y[i]),
+//!     None,
+//!     Matrix::new([[1.0], [0.0]]),
+//!     LeastSquaresOptions::default(),
+//! );
+//! assert!(fit.converged());
+//! assert!((fit.x - Matrix::new([[2.0], [-0.5]])).max_norm() < 1e-4);
+//! ```
+
+use core::array;
+
+use crate::{error::MathError, linear_algebra::matrix::Matrix, optimize::Termination};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LeastSquaresOptions {
+    /// The maximum number of linear solves.
+    pub max_iterations: usize,
+    /// Stop once the gradient `Jᵀr` has at most this Euclidean norm.
+    pub gradient_tolerance: f32,
+    /// Stop once a step is at most this times `max(‖x‖, 1)`.
+    pub step_tolerance: f32,
+    /// Stop once a step lowers the cost by at most this fraction.
+    pub value_tolerance: f32,
+    /// The first Levenberg–Marquardt damping, relative to the largest
+    /// diagonal entry of `JᵀJ`.
+    pub initial_damping: f32,
+    /// The central-difference step for a missing Jacobian, relative to
+    /// `max(|x_j|, 1)`.
+    pub finite_difference_step: f32,
+}
+
+impl Default for LeastSquaresOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: 200,
+            gradient_tolerance: 1e-6,
+            step_tolerance: 1e-7,
+            value_tolerance: 1e-9,
+            initial_damping: 1e-3,
+            finite_difference_step: 5e-3,
+        }
+    }
+}
+
+/// The outcome of a least-squares fit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fit<const M: usize, const N: usize> {
+    pub x: Matrix<N, 1>,
+    pub residuals: Matrix<M, 1>,
+    /// `½‖r‖²` at `x`.
+    pub cost: f32,
+    /// The covariance estimate `s² (JᵀJ)⁻¹` with `s² = ‖r‖² / (M - N)`, or
+    /// `None` if `M <= N` or `JᵀJ` is singular.
+    pub covariance: Option<Matrix<N, N>>,
+    /// The Euclidean norm of the gradient `Jᵀr` at `x`.
+    pub gradient_norm: f32,
+    pub iterations: usize,
+    /// The number of times the residuals were evaluated, including for
+    /// finite differences.
+    pub evaluations: usize,
+    /// The number of Jacobians computed, by the closure or by finite
+    /// differences.
+    pub jacobian_evaluations: usize,
+    pub termination: Termination,
+}
+
+impl<const M: usize, const N: usize> Fit<M, N> {
+    /// Whether the solver stopped because a tolerance was met.
+    pub fn converged(&self) -> bool {
+        matches!(
+            self.termination,
+            Termination::GradientTolerance
+                | Termination::StepTolerance
+                | Termination::ValueTolerance
+        )
+    }
+
+    /// The square roots of the covariance's diagonal.
+    pub fn standard_errors(&self) -> Option<Matrix<N, 1>> {
+        let covariance = self.covariance?;
+        Some(Matrix::from_fn(|i, _| covariance[i][i].sqrt()))
+    }
+}
+
+/// Levenberg–Marquardt: Gauss–Newton steps damped towards scaled gradient
+/// descent, each solved by a Cholesky factorization of
+/// `JᵀJ + λ diag(JᵀJ)`.
+pub fn levenberg_marquardt<const M: usize, const N: usize>(
+    residuals: impl FnMut(&Matrix<N, 1>) -> Matrix<M, 1>,
+    jacobian: Option<&mut dyn FnMut(&Matrix<N, 1>) -> Matrix<M, N>>,
+    x0: Matrix<N, 1>,
+    options: LeastSquaresOptions,
+) -> Fit<M, N> {
+    let mut problem = Problem::new(residuals, jacobian, options);
+    let mut current = problem.start(x0);
+    let mut damping = options.initial_damping * largest_diagonal(&normal_matrix(&current.jacobian));
+    // The factor by which the next rejected step raises the damping.
+    let mut growth = 2.0;
+    let mut iterations = 0;
+
+    let termination = loop {
+        if let Some(termination) = problem.check(&current, iterations) {
+            break termination;
+        }
+        iterations += 1;
+
+        let a = normal_matrix(&current.jacobian);
+        let largest = largest_diagonal(&a);
+        // Marquardt's scaling, with a floor for parameters that the
+        // residuals do not depend on.
+        let scale: [f32; N] = array::from_fn(|i| a[i][i].max(f32::EPSILON * largest));
+        let mut damped = a;
+        for i in 0..N {
+            damped[i][i] += damping * scale[i];
+        }
+        let solution = damped
+            .cholesky()
+            .ok_or(MathError::Singular)
+            .and_then(|cholesky| cholesky.checked_solve(&current.gradient.map(|g| -g)));
+        let Ok(delta) = solution else {
+            damping *= growth;
+            growth *= 2.0;
+            continue;
+        };
+        if problem.small_step(&delta, &current.x) {
+            break Termination::StepTolerance;
+        }
+
+        let x = current.x + column(&delta);
+        let (residuals, cost) = problem.residuals(&x);
+        // The decrease predicted by the damped linear model.
+        let predicted: f64 = (0..N)
+            .map(|i| {
+                0.5 * (delta[i] * (damping * scale[i] * delta[i] - current.gradient[i])) as f64
+            })
+            .sum();
+        let ratio = (current.cost - cost) / predicted;
+        if ratio.is_nan() || ratio <= 0.0 {
+            damping *= growth;
+            growth *= 2.0;
+            continue;
+        }
+        let stalled = current.cost - cost <= options.value_tolerance as f64 * current.cost;
+        current = problem.state(x, residuals, cost);
+        damping *= (1.0 - (2.0 * ratio as f32 - 1.0).powi(3)).max(1.0 / 3.0);
+        growth = 2.0;
+        if stalled {
+            break Termination::ValueTolerance;
+        }
+    };
+    problem.fit(current, iterations, termination)
+}
+
+/// Gauss–Newton: each step solves the linearized problem `J δ ≈ -r` by QR
+/// factorization, then is halved until the cost decreases enough.
+///
+/// Converges quickly near a good fit, but needs a Jacobian with independent
+/// columns at every step; [`levenberg_marquardt`] is more robust from a poor
+/// start. Panics if `M < N`.
+pub fn gauss_newton<const M: usize, const N: usize>(
+    residuals: impl FnMut(&Matrix<N, 1>) -> Matrix<M, 1>,
+    jacobian: Option<&mut dyn FnMut(&Matrix<N, 1>) -> Matrix<M, N>>,
+    x0: Matrix<N, 1>,
+    options: LeastSquaresOptions,
+) -> Fit<M, N> {
+    let mut problem = Problem::new(residuals, jacobian, options);
+    let mut current = problem.start(x0);
+    let mut iterations = 0;
+
+    let termination = loop {
+        if let Some(termination) = problem.check(&current, iterations) {
+            break termination;
+        }
+        iterations += 1;
+
+        let b: [f32; M] = array::from_fn(|i| -current.residuals[i][0]);
+        let delta = match current.jacobian.qr().checked_solve(&b) {
+            Ok(delta) => delta,
+            Err(MathError::Singular) => break Termination::Singular,
+            Err(_) => break Termination::NonFinite,
+        };
+        if problem.small_step(&delta, &current.x) {
+            break Termination::StepTolerance;
+        }
+
+        let slope: f64 = (0..N)
+            .map(|i| (current.gradient[i] * delta[i]) as f64)
+            .sum();
+        let mut t = 1.0;
+        let accepted = (0..MAX_HALVINGS).find_map(|_| {
+            let x = current.x + column(&delta) * t;
+            let (residuals, cost) = problem.residuals(&x);
+            if cost <= current.cost + 1e-4 * t as f64 * slope {
+                return Some((x, residuals, cost));
+            }
+            t *= 0.5;
+            None
+        });
+        let Some((x, residuals, cost)) = accepted else {
+            break Termination::LineSearchFailed;
+        };
+        let stalled = current.cost - cost <= options.value_tolerance as f64 * current.cost;
+        current = problem.state(x, residuals, cost);
+        if stalled {
+            break Termination::ValueTolerance;
+        }
+    };
+    problem.fit(current, iterations, termination)
+}
+
+const MAX_HALVINGS: usize = 30;
+
+struct Problem<'a, const M: usize, const N: usize, F> {
+    residuals: F,
+    jacobian: Option<&'a mut dyn FnMut(&Matrix<N, 1>) -> Matrix<M, N>>,
+    options: LeastSquaresOptions,
+    evaluations: usize,
+    jacobian_evaluations: usize,
+}
+
+// A point with everything the solvers need there.
+struct State<const M: usize, const N: usize> {
+    x: Matrix<N, 1>,
+    residuals: Matrix<M, 1>,
+    cost: f64,
+    jacobian: Matrix<M, N>,
+    gradient: [f32; N],
+}
+
+impl<'a, const M: usize, const N: usize, F: FnMut(&Matrix<N, 1>) -> Matrix<M, 1>>
+    Problem<'a, M, N, F>
+{
+    fn new(
+        residuals: F,
+        jacobian: Option<&'a mut dyn FnMut(&Matrix<N, 1>) -> Matrix<M, N>>,
+        options: LeastSquaresOptions,
+    ) -> Self {
+        Self {
+            residuals,
+            jacobian,
+            options,
+            evaluations: 0,
+            jacobian_evaluations: 0,
+        }
+    }
+
+    fn start(&mut self, x0: Matrix<N, 1>) -> State<M, N> {
+        let (residuals, cost) = self.residuals(&x0);
+        self.state(x0, residuals, cost)
+    }
+
+    // The residuals at `x` and half their squared norm.
+    fn residuals(&mut self, x: &Matrix<N, 1>) -> (Matrix<M, 1>, f64) {
+        self.evaluations += 1;
+        let residuals = (self.residuals)(x);
+        let cost = 0.5 * residuals.iter().map(|&r| r as f64 * r as f64).sum::<f64>();
+        (residuals, cost)
+    }
+
+    fn state(&mut self, x: Matrix<N, 1>, residuals: Matrix<M, 1>, cost: f64) -> State<M, N> {
+        let jacobian = self.jacobian(&x);
+        let gradient = array::from_fn(|j| {
+            (0..M)
+                .map(|i| jacobian[i][j] as f64 * residuals[i][0] as f64)
+                .sum::<f64>() as f32
+        });
+        State {
+            x,
+            residuals,
+            cost,
+            jacobian,
+            gradient,
+        }
+    }
+
+    fn jacobian(&mut self, x: &Matrix<N, 1>) -> Matrix<M, N> {
+        self.jacobian_evaluations += 1;
+        if let Some(jacobian) = self.jacobian.as_mut() {
+            return jacobian(x);
+        }
+        let mut jacobian = Matrix::ZERO;
+        for j in 0..N {
+            let h = self.options.finite_difference_step * x[j][0].abs().max(1.0);
+            let (mut plus, mut minus) = (*x, *x);
+            plus[j][0] += h;
+            minus[j][0] -= h;
+            // The spacing after rounding, which may differ from `2 h`.
+            let width = plus[j][0] - minus[j][0];
+            let (plus, minus) = (self.residuals(&plus).0, self.residuals(&minus).0);
+            for i in 0..M {
+                jacobian[i][j] = (plus[i][0] - minus[i][0]) / width;
+            }
+        }
+        jacobian
+    }
+
+    fn check(&self, state: &State<M, N>, iterations: usize) -> Option<Termination> {
+        if !state.cost.is_finite() || !state.gradient.iter().all(|g| g.is_finite()) {
+            Some(Termination::NonFinite)
+        } else if norm(&state.gradient) <= self.options.gradient_tolerance {
+            Some(Termination::GradientTolerance)
+        } else if iterations == self.options.max_iterations {
+            Some(Termination::MaxIterations)
+        } else {
+            None
+        }
+    }
+
+    fn small_step(&self, delta: &[f32; N], x: &Matrix<N, 1>) -> bool {
+        let length = x.iter().map(|x| x * x).sum::<f32>().sqrt();
+        norm(delta) <= self.options.step_tolerance * length.max(1.0)
+    }
+
+    fn fit(&self, state: State<M, N>, iterations: usize, termination: Termination) -> Fit<M, N> {
+        let covariance = if M > N {
+            let variance = (2.0 * state.cost / (M - N) as f64) as f32;
+            let a = normal_matrix(&state.jacobian);
+            a.cholesky()
+                .map(|cholesky| cholesky.inverse().map(|c| c * variance))
+                .filter(Matrix::is_finite)
+        } else {
+            None
+        };
+        Fit {
+            x: state.x,
+            residuals: state.residuals,
+            cost: state.cost as f32,
+            covariance,
+            gradient_norm: norm(&state.gradient),
+            iterations,
+            evaluations: self.evaluations,
+            jacobian_evaluations: self.jacobian_evaluations,
+            termination,
+        }
+    }
+}
+
+// `JᵀJ`, accumulated in `f64` and without the finiteness checks of the
+// operators, which would reject a Jacobian that the solvers report on.
+fn normal_matrix<const M: usize, const N: usize>(jacobian: &Matrix<M, N>) -> Matrix<N, N> {
+    Matrix::from_fn(|j, k| {
+        (0..M)
+            .map(|i| jacobian[i][j] as f64 * jacobian[i][k] as f64)
+            .sum::<f64>() as f32
+    })
+}
+
+fn largest_diagonal<const N: usize>(a: &Matrix<N, N>) -> f32 {
+    (0..N).map(|i| a[i][i]).fold(0.0, f32::max)
+}
+
+fn column<const N: usize>(values: &[f32; N]) -> Matrix<N, 1> {
+    Matrix::from_fn(|i, _| values[i])
+}
+
+fn norm(values: &[f32]) -> f32 {
+    values.iter().map(|v| v * v).sum::<f32>().sqrt()
+}
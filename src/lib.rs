@@ -1 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod complex;
+mod crc32;
+pub mod dual;
+pub mod error;
+pub mod finite;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "std")]
+pub mod least_squares;
 pub mod linear_algebra;
+pub mod math;
+#[cfg(feature = "std")]
+pub mod optimize;
+pub mod rational;
+pub mod scalar;
+#[cfg(feature = "std")]
+pub mod tape;
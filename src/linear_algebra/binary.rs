@@ -0,0 +1,362 @@
+//! A compact binary encoding for matrices and vectors, and zero-copy byte
+//! views of their memory.
+//!
+//! Encoded values are laid out as follows, with every integer and element
+//! stored little-endian regardless of the host:
+//!
+//! | bytes | contents                                            |
+//! |-------|-----------------------------------------------------|
+//! | 4     | magic `MNMX`                                        |
+//! | 1     | format version, currently 1                         |
+//! | 1     | kind: 0 = `Matrix`, 1 = `Vector2`, 2 = `Vector3`    |
+//! | 2     | reserved, zero                                      |
+//! | 4     | rows (`u32`)                                        |
+//! | 4     | columns (`u32`)                                     |
+//! | 4·n   | elements (`f32`), row-major                         |
+//! | 4     | CRC-32 (IEEE) of all preceding bytes                |
+//!
+//! Vectors are encoded as column vectors, so a `Vector3` has shape 3x1.
+//!
+//! `as_bytes` and `from_bytes_ref` instead expose the in-memory
+//! representation, which is native-endian and only meant for handing data to
+//! GPU buffers, memory maps and the like on the same machine.
+
+use core::{fmt::Display, mem};
+
+use crate::{
+    crc32::crc32,
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+    },
+};
+
+const MAGIC: &[u8; 4] = b"MNMX";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 16;
+const CHECKSUM_LEN: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryError {
+    /// The buffer passed to `write_bytes` cannot hold the encoding.
+    BufferTooSmall {
+        needed: usize,
+        found: usize,
+    },
+    /// The input is shorter or longer than its header implies.
+    Length {
+        expected: usize,
+        found: usize,
+    },
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    /// The input encodes a different type, for example a `Matrix<3, 1>` read
+    /// as a `Vector3`.
+    KindMismatch,
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    ChecksumMismatch,
+    /// The input of a zero-copy conversion is not aligned for `f32`.
+    Misaligned,
+}
+
+impl Display for BinaryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BufferTooSmall { needed, found } => write!(
+                f,
+                "buffer of {} bytes is too small, {} are needed",
+                found, needed
+            ),
+            Self::Length { expected, found } => {
+                write!(f, "expected {} bytes, found {}", expected, found)
+            }
+            Self::InvalidMagic => write!(f, "not an encoded matrix or vector"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {}", version)
+            }
+            Self::KindMismatch => write!(f, "encoded value has a different type"),
+            Self::ShapeMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Self::ChecksumMismatch => write!(f, "checksum mismatch"),
+            Self::Misaligned => write!(f, "bytes are not aligned for f32"),
+        }
+    }
+}
+
+impl core::error::Error for BinaryError {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Matrix = 0,
+    Vector2 = 1,
+    Vector3 = 2,
+}
+
+const fn encoded_len(elements: usize) -> usize {
+    HEADER_LEN + 4 * elements + CHECKSUM_LEN
+}
+
+fn encode(
+    kind: Kind,
+    (rows, columns): (usize, usize),
+    values: &[f32],
+    out: &mut [u8],
+) -> Result<usize, BinaryError> {
+    let needed = encoded_len(values.len());
+    if out.len() < needed {
+        return Err(BinaryError::BufferTooSmall {
+            needed,
+            found: out.len(),
+        });
+    }
+    let out = &mut out[..needed];
+    out[..4].copy_from_slice(MAGIC);
+    out[4] = VERSION;
+    out[5] = kind as u8;
+    out[6..8].fill(0);
+    out[8..12].copy_from_slice(&(rows as u32).to_le_bytes());
+    out[12..16].copy_from_slice(&(columns as u32).to_le_bytes());
+    let (body, checksum) = out.split_at_mut(needed - CHECKSUM_LEN);
+    for (chunk, value) in body[HEADER_LEN..].chunks_exact_mut(4).zip(values) {
+        chunk.copy_from_slice(&value.to_le_bytes());
+    }
+    checksum.copy_from_slice(&crc32(body).to_le_bytes());
+    Ok(needed)
+}
+
+fn read_u32(bytes: &[u8]) -> usize {
+    u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize
+}
+
+// Validates an encoding and fills `values` from it.
+fn decode(
+    kind: Kind,
+    shape: (usize, usize),
+    bytes: &[u8],
+    values: &mut [f32],
+) -> Result<(), BinaryError> {
+    let minimum = encoded_len(0);
+    if bytes.len() < minimum {
+        return Err(BinaryError::Length {
+            expected: minimum,
+            found: bytes.len(),
+        });
+    }
+    if &bytes[..4] != MAGIC {
+        return Err(BinaryError::InvalidMagic);
+    }
+    if bytes[4] != VERSION {
+        return Err(BinaryError::UnsupportedVersion(bytes[4]));
+    }
+    if bytes[5] != kind as u8 {
+        return Err(BinaryError::KindMismatch);
+    }
+    let found = (read_u32(&bytes[8..]), read_u32(&bytes[12..]));
+    if found != shape {
+        return Err(BinaryError::ShapeMismatch {
+            expected: shape,
+            found,
+        });
+    }
+    let expected = encoded_len(values.len());
+    if bytes.len() != expected {
+        return Err(BinaryError::Length {
+            expected,
+            found: bytes.len(),
+        });
+    }
+    let (body, checksum) = bytes.split_at(expected - CHECKSUM_LEN);
+    if crc32(body).to_le_bytes() != checksum {
+        return Err(BinaryError::ChecksumMismatch);
+    }
+    for (value, chunk) in values.iter_mut().zip(body[HEADER_LEN..].chunks_exact(4)) {
+        *value = f32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    Ok(())
+}
+
+// SAFETY (for the helpers below): `T` must be `#[repr(C)]` and consist only
+// of `f32` fields, so it has no padding, every bit pattern is valid and its
+// alignment is that of `f32`.
+
+unsafe fn slice_as_bytes<T>(slice: &[T]) -> &[u8] {
+    // SAFETY: see above; the byte slice covers exactly the memory of `slice`.
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), mem::size_of_val(slice)) }
+}
+
+unsafe fn slice_from_bytes<T>(bytes: &[u8]) -> Result<&[T], BinaryError> {
+    let size = mem::size_of::<T>();
+    if size == 0 || !bytes.len().is_multiple_of(size) {
+        return Err(BinaryError::Length {
+            expected: bytes.len().next_multiple_of(size.max(1)),
+            found: bytes.len(),
+        });
+    }
+    if !bytes.as_ptr().cast::<T>().is_aligned() {
+        return Err(BinaryError::Misaligned);
+    }
+    // SAFETY: see above; the pointer is aligned and the length is a whole
+    // number of `T`s.
+    Ok(unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / size) })
+}
+
+unsafe fn from_bytes_ref<T>(bytes: &[u8]) -> Result<&T, BinaryError> {
+    let expected = mem::size_of::<T>();
+    if bytes.len() != expected {
+        return Err(BinaryError::Length {
+            expected,
+            found: bytes.len(),
+        });
+    }
+    if !bytes.as_ptr().cast::<T>().is_aligned() {
+        return Err(BinaryError::Misaligned);
+    }
+    // SAFETY: see above; the pointer is aligned and covers exactly one `T`.
+    Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
+    /// The length of the encoding produced by [`Matrix::write_bytes`].
+    pub const ENCODED_LEN: usize = encoded_len(ROWS * COLUMNS);
+
+    /// Encodes the matrix into the start of `out`, returning the number of
+    /// bytes written.
+    pub fn write_bytes(&self, out: &mut [u8]) -> Result<usize, BinaryError> {
+        encode(
+            Kind::Matrix,
+            (ROWS, COLUMNS),
+            self.as_array().as_flattened(),
+            out,
+        )
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; Self::ENCODED_LEN];
+        self.write_bytes(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Decodes a matrix written by [`Matrix::write_bytes`], which must make
+    /// up all of `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryError> {
+        let mut data = [[0.0; COLUMNS]; ROWS];
+        decode(
+            Kind::Matrix,
+            (ROWS, COLUMNS),
+            bytes,
+            data.as_flattened_mut(),
+        )?;
+        Ok(Self::new(data))
+    }
+
+    /// The matrix's in-memory, native-endian representation.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `Matrix` is `#[repr(C)]` around `[[f32; COLUMNS]; ROWS]`.
+        unsafe { slice_as_bytes(core::slice::from_ref(self)) }
+    }
+
+    /// Reinterprets native-endian bytes as a matrix without copying.
+    pub fn from_bytes_ref(bytes: &[u8]) -> Result<&Self, BinaryError> {
+        // SAFETY: as for `as_bytes`.
+        unsafe { from_bytes_ref(bytes) }
+    }
+
+    pub fn slice_as_bytes(matrices: &[Self]) -> &[u8] {
+        // SAFETY: as for `as_bytes`.
+        unsafe { slice_as_bytes(matrices) }
+    }
+
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], BinaryError> {
+        // SAFETY: as for `as_bytes`.
+        unsafe { slice_from_bytes(bytes) }
+    }
+}
+
+impl Vector2 {
+    pub const ENCODED_LEN: usize = encoded_len(2);
+
+    pub fn write_bytes(&self, out: &mut [u8]) -> Result<usize, BinaryError> {
+        encode(Kind::Vector2, (2, 1), &[self.x, self.y], out)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; Self::ENCODED_LEN];
+        self.write_bytes(&mut bytes).unwrap();
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryError> {
+        let mut values = [0.0; 2];
+        decode(Kind::Vector2, (2, 1), bytes, &mut values)?;
+        Ok(Self::new(values[0], values[1]))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `Vector2` is `#[repr(C)]` with two `f32` fields.
+        unsafe { slice_as_bytes(core::slice::from_ref(self)) }
+    }
+
+    pub fn from_bytes_ref(bytes: &[u8]) -> Result<&Self, BinaryError> {
+        // SAFETY: as for `as_bytes`.
+        unsafe { from_bytes_ref(bytes) }
+    }
+
+    pub fn slice_as_bytes(vectors: &[Self]) -> &[u8] {
+        // SAFETY: as for `as_bytes`.
+        unsafe { slice_as_bytes(vectors) }
+    }
+
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], BinaryError> {
+        // SAFETY: as for `as_bytes`.
+        unsafe { slice_from_bytes(bytes) }
+    }
+}
+
+impl Vector3 {
+    pub const ENCODED_LEN: usize = encoded_len(3);
+
+    pub fn write_bytes(&self, out: &mut [u8]) -> Result<usize, BinaryError> {
+        encode(Kind::Vector3, (3, 1), &[self.x, self.y, self.z], out)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; Self::ENCODED_LEN];
+        self.write_bytes(&mut bytes).unwrap();
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryError> {
+        let mut values = [0.0; 3];
+        decode(Kind::Vector3, (3, 1), bytes, &mut values)?;
+        Ok(Self::new(values[0], values[1], values[2]))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `Vector3` is `#[repr(C)]` with three `f32` fields.
+        unsafe { slice_as_bytes(core::slice::from_ref(self)) }
+    }
+
+    pub fn from_bytes_ref(bytes: &[u8]) -> Result<&Self, BinaryError> {
+        // SAFETY: as for `as_bytes`.
+        unsafe { from_bytes_ref(bytes) }
+    }
+
+    pub fn slice_as_bytes(vectors: &[Self]) -> &[u8] {
+        // SAFETY: as for `as_bytes`.
+        unsafe { slice_as_bytes(vectors) }
+    }
+
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], BinaryError> {
+        // SAFETY: as for `as_bytes`.
+        unsafe { slice_from_bytes(bytes) }
+    }
+}
@@ -0,0 +1,89 @@
+//! Cholesky factorization of symmetric positive definite matrices.
+
+use core::array;
+
+use crate::{
+    error::MathError,
+    linear_algebra::matrix::Matrix,
+    scalar::{Real, Scalar},
+};
+
+/// The result of [`Matrix::cholesky`]: `A = L * Lᵀ` with `L` lower
+/// triangular.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MatrixCholesky<const N: usize, T = f32> {
+    lower: Matrix<N, N, T>,
+}
+
+impl<const N: usize, T: Real> Matrix<N, N, T> {
+    /// Cholesky decomposition of a symmetric positive definite matrix, or
+    /// `None` if it is not positive definite. Only the lower triangle is read.
+    pub fn cholesky(&self) -> Option<MatrixCholesky<N, T>> {
+        let mut lower = Matrix::ZERO;
+        for j in 0..N {
+            let mut pivot = self[j][j];
+            for k in 0..j {
+                pivot -= lower[j][k] * lower[j][k];
+            }
+            if !pivot.is_finite() || pivot.to_f32() <= 0.0 {
+                return None;
+            }
+            let pivot = pivot.sqrt();
+            lower[j][j] = pivot;
+            for i in j + 1..N {
+                let mut sum = self[i][j];
+                for k in 0..j {
+                    sum -= lower[i][k] * lower[j][k];
+                }
+                lower[i][j] = sum / pivot;
+            }
+        }
+        Some(MatrixCholesky { lower })
+    }
+}
+
+impl<const N: usize, T: Real> MatrixCholesky<N, T> {
+    pub fn lower(&self) -> Matrix<N, N, T> {
+        self.lower
+    }
+
+    pub fn determinant(&self) -> T {
+        (0..N).fold(T::ONE, |det, i| det * self.lower[i][i] * self.lower[i][i])
+    }
+
+    pub fn checked_solve(&self, b: &[T; N]) -> Result<[T; N], MathError> {
+        let x = self.solve(b);
+        if x.iter().all(Scalar::is_finite) {
+            Ok(x)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
+
+    /// Solves `A x = b`.
+    pub fn solve(&self, b: &[T; N]) -> [T; N] {
+        let l = &self.lower;
+        let mut x = *b;
+        for i in 0..N {
+            for j in 0..i {
+                x[i] -= l[i][j] * x[j];
+            }
+            x[i] /= l[i][i];
+        }
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                x[i] -= l[j][i] * x[j];
+            }
+            x[i] /= l[i][i];
+        }
+        x
+    }
+
+    /// The inverse of `A`, one [`solve`](Self::solve) per column.
+    pub fn inverse(&self) -> Matrix<N, N, T> {
+        let columns: [[T; N]; N] = array::from_fn(|j| {
+            self.solve(&array::from_fn(|i| if i == j { T::ONE } else { T::ZERO }))
+        });
+        Matrix::from_fn(|i, j| columns[j][i])
+    }
+}
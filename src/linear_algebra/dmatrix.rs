@@ -0,0 +1,405 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Index, IndexMut, Mul, Sub},
+};
+
+use crate::{
+    error::MathError,
+    finite::audited,
+    linear_algebra::{format::MatrixFormat, matrix::Matrix},
+};
+
+/// A heap-allocated matrix whose shape is only known at runtime.
+///
+/// Elements are stored row-major. Indexing works the same way as for
+/// [`Matrix`]: `m[row]` yields a row slice, so `m[row][column]` is an element.
+#[derive(Clone, PartialEq)]
+pub struct DMatrix {
+    rows: usize,
+    columns: usize,
+    data: Vec<f32>,
+}
+
+impl DMatrix {
+    pub fn new(rows: usize, columns: usize, data: Vec<f32>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * columns,
+            "Expected {} elements for a {}x{} matrix, got {}",
+            rows * columns,
+            rows,
+            columns,
+            data.len()
+        );
+        Self {
+            rows,
+            columns,
+            data,
+        }
+    }
+
+    pub fn zeros(rows: usize, columns: usize) -> Self {
+        Self::new(rows, columns, vec![0.0; rows * columns])
+    }
+
+    pub fn identity(size: usize) -> Self {
+        Self::from_fn(size, size, |r, c| if r == c { 1.0 } else { 0.0 })
+    }
+
+    pub fn from_fn(rows: usize, columns: usize, mut f: impl FnMut(usize, usize) -> f32) -> Self {
+        let data = (0..rows * columns)
+            .map(|i| f(i / columns, i % columns))
+            .collect();
+        Self::new(rows, columns, data)
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns a [`MatrixFormat`] for choosing how the matrix is displayed.
+    pub fn format(&self) -> MatrixFormat<'_> {
+        MatrixFormat::dmatrix(&self.data, self.rows, self.columns)
+    }
+
+    pub fn as_slice(&self) -> &[f32] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.data
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.columns, self.rows, |r, c| self[c][r])
+    }
+
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        Self::new(
+            self.rows,
+            self.columns,
+            self.data.iter().map(|&v| f(v)).collect(),
+        )
+    }
+
+    /// Whether no element is infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.data.iter().all(|value| value.is_finite())
+    }
+
+    pub fn has_nan(&self) -> bool {
+        self.data.iter().any(|value| value.is_nan())
+    }
+
+    // Fails with `NonFinite` if any element is infinite or NaN.
+    fn finite(self) -> Result<Self, MathError> {
+        if self.is_finite() {
+            Ok(self)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
+
+    fn check_same_size(&self, rhs: &Self) -> Result<(), MathError> {
+        if self.size() != rhs.size() {
+            return Err(MathError::ShapeMismatch {
+                expected: self.size(),
+                found: rhs.size(),
+            });
+        }
+        Ok(())
+    }
+
+    // Combines equally sized matrices element by element.
+    fn zip_with(&self, rhs: &Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        let data = self
+            .data
+            .iter()
+            .zip(&rhs.data)
+            .map(|(&a, &b)| f(a, b))
+            .collect();
+        Self::new(self.rows, self.columns, data)
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, MathError> {
+        self.check_same_size(rhs)?;
+        self.zip_with(rhs, |a, b| a + b).finite()
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, MathError> {
+        self.check_same_size(rhs)?;
+        self.zip_with(rhs, |a, b| a - b).finite()
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, MathError> {
+        if self.columns != rhs.rows {
+            return Err(MathError::ShapeMismatch {
+                expected: (self.columns, rhs.columns),
+                found: rhs.size(),
+            });
+        }
+        let mut out = DMatrix::zeros(self.rows, rhs.columns);
+        multiply_rows(self, rhs, 0, &mut out.data);
+        out.finite()
+    }
+
+    pub fn checked_div(&self, rhs: f32) -> Result<Self, MathError> {
+        if rhs == 0.0 {
+            return Err(MathError::Domain("division by zero"));
+        }
+        self.map(|v| v / rhs).finite()
+    }
+
+    pub fn checked_inverse(&self) -> Result<Self, MathError> {
+        if self.rows != self.columns {
+            return Err(MathError::ShapeMismatch {
+                expected: (self.rows, self.rows),
+                found: self.size(),
+            });
+        }
+        let lu = self.lu().ok_or(MathError::Singular)?;
+        let n = self.rows;
+        let mut inverse = DMatrix::zeros(n, n);
+        let mut unit = vec![0.0; n];
+        for c in 0..n {
+            unit[c] = 1.0;
+            for (r, value) in lu.solve(&unit).into_iter().enumerate() {
+                inverse[r][c] = value;
+            }
+            unit[c] = 0.0;
+        }
+        inverse.finite()
+    }
+
+    /// LU decomposition with partial pivoting, or `None` if the matrix is
+    /// singular.
+    pub fn lu(&self) -> Option<DMatrixLu> {
+        assert_eq!(
+            self.rows, self.columns,
+            "LU decomposition requires a square matrix"
+        );
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..self.rows).collect();
+        let mut sign = 1.0;
+
+        for k in 0..self.rows {
+            lu.pivot(k, &mut permutation, &mut sign)?;
+            let (upper, lower) = lu.data.split_at_mut((k + 1) * self.columns);
+            let pivot_row = &upper[k * self.columns..];
+            for row in lower.chunks_mut(self.columns) {
+                eliminate_row(row, pivot_row, k);
+            }
+        }
+
+        Some(DMatrixLu {
+            lu,
+            permutation,
+            sign,
+        })
+    }
+
+    // Swaps the largest remaining entry of column `k` onto the diagonal.
+    pub(crate) fn pivot(
+        &mut self,
+        k: usize,
+        permutation: &mut [usize],
+        sign: &mut f32,
+    ) -> Option<()> {
+        let pivot = (k..self.rows)
+            .max_by(|&a, &b| self[a][k].abs().total_cmp(&self[b][k].abs()))
+            .unwrap();
+        if self[pivot][k] == 0.0 {
+            return None;
+        }
+        if pivot != k {
+            for c in 0..self.columns {
+                self.data
+                    .swap(k * self.columns + c, pivot * self.columns + c);
+            }
+            permutation.swap(k, pivot);
+            *sign = -*sign;
+        }
+        Some(())
+    }
+}
+
+// Stores the multiplier for column `k` in `row` and subtracts the scaled pivot row.
+pub(crate) fn eliminate_row(row: &mut [f32], pivot_row: &[f32], k: usize) {
+    let factor = row[k] / pivot_row[k];
+    row[k] = factor;
+    for c in k + 1..row.len() {
+        row[c] -= factor * pivot_row[c];
+    }
+}
+
+// Computes `out = lhs * rhs` for the rows of `lhs` starting at `first_row`.
+pub(crate) fn multiply_rows(lhs: &DMatrix, rhs: &DMatrix, first_row: usize, out: &mut [f32]) {
+    // An empty product has nothing to compute, and may have no columns to
+    // chunk by.
+    if out.is_empty() {
+        return;
+    }
+    for (i, out_row) in out.chunks_mut(rhs.columns).enumerate() {
+        let lhs_row = &lhs[first_row + i];
+        for (k, &a) in lhs_row.iter().enumerate() {
+            for (o, &b) in out_row.iter_mut().zip(&rhs[k]) {
+                *o += a * b;
+            }
+        }
+    }
+}
+
+/// The result of [`DMatrix::lu`]: `P * A = L * U`, with `L` and `U` packed
+/// into one matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DMatrixLu {
+    pub(crate) lu: DMatrix,
+    pub(crate) permutation: Vec<usize>,
+    pub(crate) sign: f32,
+}
+
+impl DMatrixLu {
+    pub fn lower(&self) -> DMatrix {
+        DMatrix::from_fn(self.lu.rows, self.lu.columns, |r, c| match r.cmp(&c) {
+            std::cmp::Ordering::Greater => self.lu[r][c],
+            std::cmp::Ordering::Equal => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+        })
+    }
+
+    pub fn upper(&self) -> DMatrix {
+        DMatrix::from_fn(self.lu.rows, self.lu.columns, |r, c| {
+            if r <= c { self.lu[r][c] } else { 0.0 }
+        })
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn determinant(&self) -> f32 {
+        (0..self.lu.rows).fold(self.sign, |det, i| det * self.lu[i][i])
+    }
+
+    pub fn checked_solve(&self, b: &[f32]) -> Result<Vec<f32>, MathError> {
+        let n = self.lu.rows;
+        if b.len() != n {
+            return Err(MathError::ShapeMismatch {
+                expected: (n, 1),
+                found: (b.len(), 1),
+            });
+        }
+        let x = self.solve(b);
+        if x.iter().all(|value| value.is_finite()) {
+            Ok(x)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
+
+    pub fn solve(&self, b: &[f32]) -> Vec<f32> {
+        let n = self.lu.rows;
+        assert_eq!(b.len(), n, "Expected a right-hand side of length {}", n);
+        let mut x: Vec<f32> = self.permutation.iter().map(|&p| b[p]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+            x[i] /= self.lu[i][i];
+        }
+        x
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> From<Matrix<ROWS, COLUMNS>> for DMatrix {
+    fn from(matrix: Matrix<ROWS, COLUMNS>) -> Self {
+        Self::from_fn(ROWS, COLUMNS, |r, c| matrix[r][c])
+    }
+}
+
+impl Add for &DMatrix {
+    type Output = DMatrix;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.size(), rhs.size(), "Matrix dimension mismatch");
+        audited(self.zip_with(rhs, |a, b| a + b), "DMatrix + DMatrix")
+    }
+}
+
+impl Sub for &DMatrix {
+    type Output = DMatrix;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.size(), rhs.size(), "Matrix dimension mismatch");
+        audited(self.zip_with(rhs, |a, b| a - b), "DMatrix - DMatrix")
+    }
+}
+
+impl Mul for &DMatrix {
+    type Output = DMatrix;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(
+            self.columns, rhs.rows,
+            "Matrix dimension mismatch: Expected left matrix with shape (a * b) and right matrix with shape (b * c), \
+             but got shapes ({}*{}) and ({}*{}).",
+            self.rows, self.columns, rhs.rows, rhs.columns
+        );
+        let mut out = DMatrix::zeros(self.rows, rhs.columns);
+        multiply_rows(self, rhs, 0, &mut out.data);
+        audited(out, "DMatrix * DMatrix")
+    }
+}
+
+impl Mul<f32> for &DMatrix {
+    type Output = DMatrix;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul(self, rhs: f32) -> Self::Output {
+        audited(self.map(|v| v * rhs), "DMatrix * f32")
+    }
+}
+
+impl Debug for DMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "DMatrix ({}x{}):", self.rows, self.columns)?;
+        for r in 0..self.rows {
+            for value in &self[r] {
+                write!(f, "{:?} ", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for DMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+impl Index<usize> for DMatrix {
+    type Output = [f32];
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.data[row * self.columns..(row + 1) * self.columns]
+    }
+}
+
+impl IndexMut<usize> for DMatrix {
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        &mut self.data[row * self.columns..(row + 1) * self.columns]
+    }
+}
@@ -0,0 +1,368 @@
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::scalar::Scalar;
+
+/// How [`MatrixFormat`] lays out a matrix.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Style {
+    /// A `Matrix (RxC):` header followed by one row per line. This is the
+    /// `Display` output and can be parsed back with `str::parse`.
+    #[default]
+    Plain,
+    /// Rows enclosed in box-drawing corners and bars.
+    Box,
+    /// Rows enclosed in tall square brackets.
+    Brackets,
+    /// A LaTeX `bmatrix` environment.
+    Latex,
+    /// A Markdown table with the column indices as its header.
+    Markdown,
+    /// A Rust expression constructing the matrix. Elision is never applied.
+    Rust,
+}
+
+#[derive(Clone, Copy)]
+enum Source {
+    Matrix,
+    #[cfg(feature = "std")]
+    DMatrix,
+}
+
+#[derive(Clone, Copy)]
+enum Cell<T> {
+    Value(T),
+    Elided(&'static str),
+}
+
+/// A configurable [`Display`] adapter for matrices, returned by
+/// [`Matrix::format`](crate::linear_algebra::matrix::Matrix::format).
+///
+/// Columns are right-aligned to their widest entry. A precision or minimum
+/// width that is not set on the builder is taken from the format string, so
+/// `format!("{:8.3}", m.format())` works like it does for `f32`.
+///
+/// `COLUMNS` is the column count of a fixed-size matrix and sizes the buffer
+/// the column widths are kept in; for a `DMatrix` the buffer is allocated.
+#[derive(Clone, Copy)]
+pub struct MatrixFormat<'a, T = f32, const COLUMNS: usize = 0> {
+    data: &'a [T],
+    rows: usize,
+    columns: usize,
+    source: Source,
+    style: Style,
+    precision: Option<usize>,
+    width: Option<usize>,
+    max_rows: usize,
+    max_columns: usize,
+}
+
+// Counts the characters written to it.
+pub(crate) struct Counter(pub(crate) usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+// The indices shown along one axis when at most `max` of `n` fit: a head, an
+// elided marker (`None`) and a tail.
+fn visible(n: usize, max: usize) -> impl Iterator<Item = Option<usize>> + Clone {
+    let elided = n > max;
+    let (head, tail) = if elided {
+        (max.div_ceil(2), max / 2)
+    } else {
+        (n, 0)
+    };
+    let shown = head + tail + elided as usize;
+    (0..shown).map(move |i| {
+        if i < head {
+            Some(i)
+        } else if elided && i == head {
+            None
+        } else {
+            Some(n - (shown - i))
+        }
+    })
+}
+
+impl<'a> MatrixFormat<'a> {
+    #[cfg(feature = "std")]
+    pub(crate) fn dmatrix(data: &'a [f32], rows: usize, columns: usize) -> Self {
+        Self::new(data, rows, columns, Source::DMatrix)
+    }
+}
+
+impl<'a, T: Scalar, const COLUMNS: usize> MatrixFormat<'a, T, COLUMNS> {
+    fn new(data: &'a [T], rows: usize, columns: usize, source: Source) -> Self {
+        Self {
+            data,
+            rows,
+            columns,
+            source,
+            style: Style::Plain,
+            precision: None,
+            width: None,
+            max_rows: usize::MAX,
+            max_columns: usize::MAX,
+        }
+    }
+
+    pub(crate) fn matrix(data: &'a [T], rows: usize, columns: usize) -> Self {
+        Self::new(data, rows, columns, Source::Matrix)
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Prints every element with `precision` digits after the decimal point.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Pads every element to at least `width` characters.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Replaces the middle rows with a row of `…` when there are more than
+    /// `max_rows` of them.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Replaces the middle columns with a column of `…` when there are more
+    /// than `max_columns` of them.
+    pub fn max_columns(mut self, max_columns: usize) -> Self {
+        self.max_columns = max_columns;
+        self
+    }
+
+    fn visible_rows(&self) -> impl Iterator<Item = Option<usize>> + Clone {
+        match self.style {
+            Style::Rust => visible(self.rows, usize::MAX),
+            _ => visible(self.rows, self.max_rows),
+        }
+    }
+
+    fn visible_columns(&self) -> impl Iterator<Item = Option<usize>> + Clone {
+        match self.style {
+            Style::Rust => visible(self.columns, usize::MAX),
+            _ => visible(self.columns, self.max_columns),
+        }
+    }
+
+    fn cell(&self, row: Option<usize>, column: Option<usize>) -> Cell<T> {
+        match (row, column, self.style) {
+            (Some(r), Some(c), _) => Cell::Value(self.data[r * self.columns + c]),
+            (None, Some(_), Style::Latex) => Cell::Elided("\\vdots"),
+            (Some(_), None, Style::Latex) => Cell::Elided("\\cdots"),
+            (None, None, Style::Latex) => Cell::Elided("\\ddots"),
+            _ => Cell::Elided("…"),
+        }
+    }
+
+    fn write_cell<W: Write>(
+        &self,
+        out: &mut W,
+        cell: Cell<T>,
+        precision: Option<usize>,
+    ) -> fmt::Result {
+        let value = match cell {
+            Cell::Elided(symbol) => return out.write_str(symbol),
+            Cell::Value(value) => value,
+        };
+        match (self.style, precision) {
+            (Style::Rust, _) => value.write_rust(out, precision),
+            (_, Some(precision)) => write!(out, "{:.*}", precision, value),
+            (_, None) => write!(out, "{}", value),
+        }
+    }
+
+    fn cell_width(&self, cell: Cell<T>, precision: Option<usize>) -> usize {
+        let mut counter = Counter(0);
+        // Writing to a `Counter` cannot fail.
+        let _ = self.write_cell(&mut counter, cell, precision);
+        counter.0
+    }
+
+    fn column_width(&self, column: Option<usize>, precision: Option<usize>, width: usize) -> usize {
+        let widest = self
+            .visible_rows()
+            .map(|r| self.cell_width(self.cell(r, column), precision))
+            .max()
+            .unwrap_or(0);
+        let label = match (self.style, column) {
+            (Style::Markdown, Some(c)) => {
+                let mut counter = Counter(0);
+                let _ = write!(counter, "{}", c);
+                counter.0
+            }
+            _ => 0,
+        };
+        widest.max(label).max(width)
+    }
+
+    // The width of each visible column, in order.
+    fn column_widths(
+        &self,
+        precision: Option<usize>,
+        width: usize,
+    ) -> impl Iterator<Item = usize> + '_ {
+        self.visible_columns()
+            .map(move |c| self.column_width(c, precision, width))
+    }
+
+    fn line_width(&self, separator: &str, widths: &[usize]) -> usize {
+        let separators = widths.len().saturating_sub(1) * separator.chars().count();
+        widths.iter().sum::<usize>() + separators
+    }
+
+    fn write_row(
+        &self,
+        f: &mut Formatter<'_>,
+        row: Option<usize>,
+        separator: &str,
+        precision: Option<usize>,
+        widths: &[usize],
+    ) -> fmt::Result {
+        for (i, (c, &column_width)) in self.visible_columns().zip(widths).enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+            let cell = self.cell(row, c);
+            let padding = column_width - self.cell_width(cell, precision);
+            for _ in 0..padding {
+                f.write_char(' ')?;
+            }
+            self.write_cell(f, cell, precision)?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        match self.source {
+            Source::Matrix => "Matrix",
+            #[cfg(feature = "std")]
+            Source::DMatrix => "DMatrix",
+        }
+    }
+
+    fn write(
+        &self,
+        f: &mut Formatter<'_>,
+        precision: Option<usize>,
+        widths: &[usize],
+    ) -> fmt::Result {
+        let rows = self.visible_rows().count();
+        match self.style {
+            Style::Plain => {
+                writeln!(f, "{} ({}x{}):", self.name(), self.rows, self.columns)?;
+                for r in self.visible_rows() {
+                    self.write_row(f, r, " ", precision, widths)?;
+                    writeln!(f)?;
+                }
+            }
+            Style::Box => {
+                let inner = self.line_width(" ", widths);
+                writeln!(f, "┌ {:inner$} ┐", "")?;
+                for r in self.visible_rows() {
+                    f.write_str("│ ")?;
+                    self.write_row(f, r, " ", precision, widths)?;
+                    writeln!(f, " │")?;
+                }
+                writeln!(f, "└ {:inner$} ┘", "")?;
+            }
+            Style::Brackets => {
+                for (i, r) in self.visible_rows().enumerate() {
+                    let (open, close) = match (i, rows) {
+                        (_, 1) => ("[", "]"),
+                        (0, _) => ("⎡", "⎤"),
+                        (i, _) if i + 1 == rows => ("⎣", "⎦"),
+                        _ => ("⎢", "⎥"),
+                    };
+                    write!(f, "{} ", open)?;
+                    self.write_row(f, r, " ", precision, widths)?;
+                    writeln!(f, " {}", close)?;
+                }
+            }
+            Style::Latex => {
+                writeln!(f, "\\begin{{bmatrix}}")?;
+                for (i, r) in self.visible_rows().enumerate() {
+                    self.write_row(f, r, " & ", precision, widths)?;
+                    if i + 1 < rows {
+                        f.write_str(" \\\\")?;
+                    }
+                    writeln!(f)?;
+                }
+                writeln!(f, "\\end{{bmatrix}}")?;
+            }
+            Style::Markdown => {
+                for (c, &column_width) in self.visible_columns().zip(widths) {
+                    match c {
+                        Some(c) => write!(f, "| {:>column_width$} ", c)?,
+                        None => write!(f, "| {:>column_width$} ", "…")?,
+                    }
+                }
+                writeln!(f, "|")?;
+                for &column_width in widths {
+                    let dashes = column_width + 1;
+                    write!(f, "|{:-<dashes$}:", "")?;
+                }
+                writeln!(f, "|")?;
+                for r in self.visible_rows() {
+                    f.write_str("| ")?;
+                    self.write_row(f, r, " | ", precision, widths)?;
+                    writeln!(f, " |")?;
+                }
+            }
+            Style::Rust => match self.source {
+                Source::Matrix => {
+                    writeln!(f, "Matrix::new([")?;
+                    for r in self.visible_rows() {
+                        f.write_str("    [")?;
+                        self.write_row(f, r, ", ", precision, widths)?;
+                        writeln!(f, "],")?;
+                    }
+                    writeln!(f, "])")?;
+                }
+                #[cfg(feature = "std")]
+                Source::DMatrix => {
+                    writeln!(f, "DMatrix::new({}, {}, vec![", self.rows, self.columns)?;
+                    for r in self.visible_rows() {
+                        f.write_str("    ")?;
+                        self.write_row(f, r, ", ", precision, widths)?;
+                        writeln!(f, ",")?;
+                    }
+                    writeln!(f, "])")?;
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+impl<T: Scalar, const COLUMNS: usize> Display for MatrixFormat<'_, T, COLUMNS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = self.precision.or(f.precision());
+        let width = self.width.or(f.width()).unwrap_or(0);
+        // Each width takes a pass over the visible rows, so they are found
+        // once rather than for every cell.
+        let widths = self.column_widths(precision, width);
+        match self.source {
+            Source::Matrix => {
+                let mut buffer = [0; COLUMNS];
+                let count = buffer.iter_mut().zip(widths).map(|(b, w)| *b = w).count();
+                self.write(f, precision, &buffer[..count])
+            }
+            #[cfg(feature = "std")]
+            Source::DMatrix => self.write(f, precision, &widths.collect::<Vec<_>>()),
+        }
+    }
+}
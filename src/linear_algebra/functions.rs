@@ -0,0 +1,254 @@
+//! Matrix functions: exponential, logarithm, square root and powers.
+//!
+//! Everything is computed in `f64` and rounded to `f32` at the end, so the
+//! results are accurate to `f32` precision for well-conditioned inputs.
+
+use core::array;
+
+use crate::{error::MathError, linear_algebra::matrix::Matrix};
+
+type Square<const N: usize> = [[f64; N]; N];
+
+// Degree of the diagonal Padé approximant used by `expm`. With the argument
+// scaled to a 1-norm of at most 1/2 its error is below `f64::EPSILON`.
+const PADE_DEGREE: usize = 6;
+
+// Upper bound on Denman–Beavers iterations; quadratic convergence normally
+// needs fewer than 20.
+const MAX_SQRT_ITERATIONS: usize = 64;
+
+// Upper bound on the square roots `logm` takes to bring its argument near the
+// identity.
+const MAX_LOG_SQUARE_ROOTS: usize = 64;
+
+impl<const N: usize> Matrix<N, N> {
+    /// The matrix exponential `e^A`, by scaling and squaring with a Padé
+    /// approximant.
+    pub fn expm(&self) -> Self {
+        let a = widen(self);
+        let norm = one_norm(&a);
+        if !norm.is_finite() {
+            return Self::filled(f32::NAN);
+        }
+        let mut squarings = 0;
+        let mut scale = 1.0;
+        while norm * scale > 0.5 {
+            scale /= 2.0;
+            squarings += 1;
+        }
+        let x = map(&a, |value| value * scale);
+
+        let mut numerator = identity();
+        let mut denominator = identity();
+        let mut power = identity();
+        let mut coefficient = 1.0;
+        for k in 1..=PADE_DEGREE {
+            coefficient *= (PADE_DEGREE - k + 1) as f64 / ((2 * PADE_DEGREE - k + 1) * k) as f64;
+            power = multiply(&power, &x);
+            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+            numerator = add(&numerator, &power, coefficient);
+            denominator = add(&denominator, &power, sign * coefficient);
+        }
+        // The denominator is well conditioned for arguments this small.
+        let mut result = match inverse(&denominator) {
+            Some(inverse) => multiply(&inverse, &numerator),
+            None => return Self::filled(f32::NAN),
+        };
+        for _ in 0..squarings {
+            result = multiply(&result, &result);
+        }
+        narrow(&result)
+    }
+
+    /// The principal square root, the unique square root whose eigenvalues
+    /// all have positive real part, by the product form of the Denman–Beavers
+    /// iteration.
+    ///
+    /// It exists when no eigenvalue lies on the closed negative real axis.
+    /// Returns [`MathError::Singular`] for a singular matrix and
+    /// [`MathError::NotConverged`] if the iteration fails, which is what
+    /// happens for negative real eigenvalues.
+    pub fn sqrtm(&self) -> Result<Self, MathError> {
+        self.finite()?;
+        narrow(&sqrtm(&widen(self))?).finite()
+    }
+
+    /// The principal logarithm, the unique logarithm whose eigenvalues have
+    /// imaginary parts in `(-π, π)`, by inverse scaling and squaring.
+    ///
+    /// The same conditions and errors as [`sqrtm`](Self::sqrtm) apply.
+    pub fn logm(&self) -> Result<Self, MathError> {
+        let mut a = widen(&self.finite()?);
+        let mut roots = 0;
+        while one_norm(&add(&a, &identity(), -1.0)) > 0.1 {
+            if roots == MAX_LOG_SQUARE_ROOTS {
+                return Err(MathError::NotConverged { iterations: roots });
+            }
+            a = sqrtm(&a)?;
+            roots += 1;
+        }
+        // log(I + X) = X - X²/2 + X³/3 - …, which converges fast for ‖X‖₁ ≤ 0.1.
+        let x = add(&a, &identity(), -1.0);
+        let mut result = [[0.0; N]; N];
+        let mut power = identity();
+        for k in 1..=32 {
+            power = multiply(&power, &x);
+            let sign = if k % 2 == 0 { -1.0 } else { 1.0 };
+            result = add(&result, &power, sign / k as f64);
+            if one_norm(&power) / (k as f64) < f64::EPSILON * one_norm(&result) {
+                break;
+            }
+        }
+        let factor = (1u64 << roots) as f64;
+        narrow(&map(&result, |value| value * factor)).finite()
+    }
+
+    /// Raises the matrix to a non-negative integer power by repeated squaring.
+    pub fn pow(&self, exponent: u32) -> Self {
+        narrow(&pow(&widen(self), exponent.into()))
+    }
+
+    /// Raises the matrix to an integer power. Negative powers invert the
+    /// matrix first and return [`MathError::Singular`] if it has no inverse.
+    pub fn checked_powi(&self, exponent: i32) -> Result<Self, MathError> {
+        self.integer_power(exponent.unsigned_abs().into(), exponent < 0)
+    }
+
+    /// Raises the matrix to a real power as `e^(p log A)`. For a
+    /// diagonalizable matrix `V D V⁻¹` this is `V D^p V⁻¹`, using the principal
+    /// branch of each eigenvalue's power.
+    ///
+    /// Integer exponents, including those outside the `i32` range, use the
+    /// repeated squaring of [`checked_powi`](Self::checked_powi), so they work
+    /// for any invertible matrix. Other exponents have the same
+    /// requirements as [`logm`](Self::logm).
+    pub fn powf(&self, exponent: f32) -> Result<Self, MathError> {
+        if !exponent.is_finite() {
+            return Err(MathError::Domain("exponent is not finite"));
+        }
+        // Every finite f32 magnitude fits in a u128, so the round trip is
+        // exact for integers and truncates anything with a fractional part.
+        let magnitude = exponent.abs() as u128;
+        if magnitude as f32 == exponent.abs() {
+            return self.integer_power(magnitude, exponent < 0.0);
+        }
+        (self.logm()? * exponent).expm().finite()
+    }
+
+    fn integer_power(&self, exponent: u128, invert: bool) -> Result<Self, MathError> {
+        let a = widen(self);
+        let base = if invert {
+            inverse(&a).ok_or(MathError::Singular)?
+        } else {
+            a
+        };
+        narrow(&pow(&base, exponent)).finite()
+    }
+}
+
+fn widen<const N: usize>(matrix: &Matrix<N, N>) -> Square<N> {
+    array::from_fn(|r| array::from_fn(|c| matrix[r][c] as f64))
+}
+
+fn narrow<const N: usize>(a: &Square<N>) -> Matrix<N, N> {
+    Matrix::from_fn(|r, c| a[r][c] as f32)
+}
+
+fn identity<const N: usize>() -> Square<N> {
+    array::from_fn(|r| array::from_fn(|c| if r == c { 1.0 } else { 0.0 }))
+}
+
+fn map<const N: usize>(a: &Square<N>, f: impl Fn(f64) -> f64) -> Square<N> {
+    a.map(|row| row.map(&f))
+}
+
+// Returns `a + factor * b`.
+fn add<const N: usize>(a: &Square<N>, b: &Square<N>, factor: f64) -> Square<N> {
+    array::from_fn(|r| array::from_fn(|c| a[r][c] + factor * b[r][c]))
+}
+
+fn multiply<const N: usize>(a: &Square<N>, b: &Square<N>) -> Square<N> {
+    array::from_fn(|r| array::from_fn(|c| (0..N).map(|k| a[r][k] * b[k][c]).sum()))
+}
+
+fn one_norm<const N: usize>(a: &Square<N>) -> f64 {
+    (0..N)
+        .map(|c| a.iter().map(|row| row[c].abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+fn pow<const N: usize>(a: &Square<N>, mut exponent: u128) -> Square<N> {
+    let mut result = identity();
+    let mut base = *a;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(&result, &base);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = multiply(&base, &base);
+        }
+    }
+    result
+}
+
+// Gauss-Jordan elimination with partial pivoting.
+fn inverse<const N: usize>(a: &Square<N>) -> Option<Square<N>> {
+    let mut a = *a;
+    let mut inverse = identity::<N>();
+    for k in 0..N {
+        let pivot = (k..N).max_by(|&x, &y| a[x][k].abs().total_cmp(&a[y][k].abs()))?;
+        if a[pivot][k] == 0.0 || !a[pivot][k].is_finite() {
+            return None;
+        }
+        a.swap(k, pivot);
+        inverse.swap(k, pivot);
+        let divisor = a[k][k];
+        a[k].iter_mut().for_each(|value| *value /= divisor);
+        inverse[k].iter_mut().for_each(|value| *value /= divisor);
+        let (pivot_row, pivot_inverse) = (a[k], inverse[k]);
+        for r in (0..N).filter(|&r| r != k) {
+            let factor = a[r][k];
+            for (value, p) in a[r].iter_mut().zip(pivot_row) {
+                *value -= factor * p;
+            }
+            for (value, p) in inverse[r].iter_mut().zip(pivot_inverse) {
+                *value -= factor * p;
+            }
+        }
+    }
+    Some(inverse)
+}
+
+// Product form of the Denman–Beavers iteration: `Y → A^½` as `M → I`.
+fn sqrtm<const N: usize>(a: &Square<N>) -> Result<Square<N>, MathError> {
+    let mut y = *a;
+    let mut m = *a;
+    for iteration in 0..MAX_SQRT_ITERATIONS {
+        let m_inverse = match inverse(&m) {
+            Some(inverse) => inverse,
+            None if iteration == 0 => return Err(MathError::Singular),
+            None => {
+                return Err(MathError::NotConverged {
+                    iterations: iteration,
+                });
+            }
+        };
+        y = map(&multiply(&y, &add(&identity(), &m_inverse, 1.0)), |v| {
+            v * 0.5
+        });
+        m = map(&add(&add(&m, &m_inverse, 1.0), &identity(), 2.0), |v| {
+            v * 0.25
+        });
+        let error = one_norm(&add(&m, &identity(), -1.0));
+        if !error.is_finite() {
+            break;
+        }
+        if error <= 1e-14 * N as f64 {
+            return Ok(y);
+        }
+    }
+    Err(MathError::NotConverged {
+        iterations: MAX_SQRT_ITERATIONS,
+    })
+}
@@ -0,0 +1,51 @@
+//! Conjugate transposes and the Hermitian and unitary predicates for complex
+//! matrices.
+
+use crate::{complex::Complex, linear_algebra::matrix::Matrix, scalar::Scalar};
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Matrix<ROWS, COLUMNS, Complex<T>> {
+    /// The element-wise complex conjugate.
+    pub fn conjugate(&self) -> Self {
+        self.map(Complex::conj)
+    }
+
+    /// The conjugate transpose `Aᴴ`, also called the Hermitian adjoint.
+    pub fn conjugate_transpose(&self) -> Matrix<COLUMNS, ROWS, Complex<T>> {
+        self.transpose().conjugate()
+    }
+
+    /// The real parts.
+    pub fn re(&self) -> Matrix<ROWS, COLUMNS, T> {
+        Matrix::from_fn(|r, c| self[r][c].re)
+    }
+
+    /// The imaginary parts.
+    pub fn im(&self) -> Matrix<ROWS, COLUMNS, T> {
+        Matrix::from_fn(|r, c| self[r][c].im)
+    }
+}
+
+impl<const N: usize, T: Scalar> Matrix<N, N, Complex<T>> {
+    /// Whether the matrix equals its conjugate transpose, compared exactly.
+    pub fn is_hermitian(&self) -> bool {
+        (0..N).all(|r| (r..N).all(|c| self[r][c] == self[c][r].conj()))
+    }
+
+    /// Whether `selfᴴ * self` differs from the identity by at most
+    /// `tolerance` in magnitude in every element.
+    pub fn is_unitary(&self, tolerance: f32) -> bool {
+        let product = self.conjugate_transpose() * *self;
+        product.enumerate().all(|((r, c), &value)| {
+            let expected = if r == c { Complex::ONE } else { Complex::ZERO };
+            (value - expected).magnitude() <= tolerance as f64
+        })
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> From<Matrix<ROWS, COLUMNS>>
+    for Matrix<ROWS, COLUMNS, Complex>
+{
+    fn from(matrix: Matrix<ROWS, COLUMNS>) -> Self {
+        Matrix::from_fn(|r, c| Complex::from(matrix[r][c]))
+    }
+}
@@ -0,0 +1,122 @@
+//! LU factorization with partial pivoting for fixed-size matrices of any
+//! element type.
+
+use core::array;
+
+use crate::{error::MathError, linear_algebra::matrix::Matrix, scalar::Scalar};
+
+/// The result of [`Matrix::lu`]: `P * A = L * U`, with `L` and `U` packed
+/// into one matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MatrixLu<const N: usize, T = f32> {
+    lu: Matrix<N, N, T>,
+    permutation: [usize; N],
+    sign: T,
+}
+
+impl<const N: usize, T: Scalar> Matrix<N, N, T> {
+    /// LU decomposition with partial pivoting, choosing the pivot of largest
+    /// [`magnitude`](Scalar::magnitude), or `None` if the matrix is singular.
+    pub fn lu(&self) -> Option<MatrixLu<N, T>> {
+        let mut lu = *self;
+        let mut permutation: [usize; N] = array::from_fn(|i| i);
+        let mut sign = T::ONE;
+        for k in 0..N {
+            let pivot = (k..N)
+                .max_by(|&x, &y| lu[x][k].magnitude().total_cmp(&lu[y][k].magnitude()))
+                .unwrap();
+            if lu[pivot][k] == T::ZERO {
+                return None;
+            }
+            if pivot != k {
+                lu.swap_rows(k, pivot);
+                permutation.swap(k, pivot);
+                sign = -sign;
+            }
+            let pivot_row = lu[k];
+            for r in k + 1..N {
+                let factor = lu[r][k] / pivot_row[k];
+                lu[r][k] = factor;
+                for c in k + 1..N {
+                    lu[r][c] -= factor * pivot_row[c];
+                }
+            }
+        }
+        Some(MatrixLu {
+            lu,
+            permutation,
+            sign,
+        })
+    }
+}
+
+impl<const N: usize, T: Scalar> MatrixLu<N, T> {
+    pub fn lower(&self) -> Matrix<N, N, T> {
+        Matrix::from_fn(|r, c| match r.cmp(&c) {
+            core::cmp::Ordering::Greater => self.lu[r][c],
+            core::cmp::Ordering::Equal => T::ONE,
+            core::cmp::Ordering::Less => T::ZERO,
+        })
+    }
+
+    pub fn upper(&self) -> Matrix<N, N, T> {
+        Matrix::from_fn(|r, c| if r <= c { self.lu[r][c] } else { T::ZERO })
+    }
+
+    /// Row `i` of `P * A` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    pub fn determinant(&self) -> T {
+        (0..N).fold(self.sign, |det, i| det * self.lu[i][i])
+    }
+
+    pub fn checked_solve(&self, b: &[T; N]) -> Result<[T; N], MathError> {
+        let x = self.solve(b);
+        if x.iter().all(Scalar::is_finite) {
+            Ok(x)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
+
+    /// Solves `A x = b`.
+    pub fn solve(&self, b: &[T; N]) -> [T; N] {
+        let mut x: [T; N] = array::from_fn(|i| b[self.permutation[i]]);
+        for i in 0..N {
+            for j in 0..i {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+        }
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+            x[i] /= self.lu[i][i];
+        }
+        x
+    }
+
+    /// Solves `Aᵀ x = b`, using `Aᵀ = Uᵀ Lᵀ P`. The transpose is not
+    /// conjugated for complex elements.
+    pub fn solve_transpose(&self, b: &[T; N]) -> [T; N] {
+        let mut v = *b;
+        for i in 0..N {
+            for j in 0..i {
+                v[i] -= self.lu[j][i] * v[j];
+            }
+            v[i] /= self.lu[i][i];
+        }
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                v[i] -= self.lu[j][i] * v[j];
+            }
+        }
+        let mut x = [T::ZERO; N];
+        for (i, value) in v.into_iter().enumerate() {
+            x[self.permutation[i]] = value;
+        }
+        x
+    }
+}
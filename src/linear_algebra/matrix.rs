@@ -1,63 +1,446 @@
-use std::{
+use core::{
+    any::type_name,
     array,
     fmt::{Debug, Display},
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::linear_algebra::vector::{Vector2, Vector3};
+use crate::{
+    error::MathError,
+    finite::{audit, audited},
+    linear_algebra::{
+        format::MatrixFormat,
+        vector::{Vector2, Vector3},
+        view::MatrixView,
+    },
+    scalar::{Real, Scalar},
+};
 
+/// A `ROWS`x`COLUMNS` matrix of `T`, stored inline in row-major order.
+///
+/// The element type defaults to `f32`; see [`Scalar`] for the others.
 #[derive(Clone, Copy, PartialEq)]
-pub struct Matrix<const ROWS: usize, const COLUMNS: usize> {
-    data: [[f32; COLUMNS]; ROWS],
+#[repr(C)]
+pub struct Matrix<const ROWS: usize, const COLUMNS: usize, T = f32> {
+    data: [[T; COLUMNS]; ROWS],
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
-    pub fn new(data: [[f32; COLUMNS]; ROWS]) -> Self {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Matrix<ROWS, COLUMNS, T> {
+    pub const ZERO: Self = Self::zero();
+    pub const ONES: Self = Self::filled(T::ONE);
+
+    pub const fn new(data: [[T; COLUMNS]; ROWS]) -> Self {
         Self { data }
     }
 
-    pub fn rotation_matrix2x2(theta: f32) -> Matrix<2, 2> {
-        Matrix::new([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]])
+    pub const fn filled(value: T) -> Self {
+        Self::new([[value; COLUMNS]; ROWS])
     }
 
-    pub fn rotation_matrix3x3_x(theta: f32) -> Matrix<3, 3> {
-        Matrix::new([
-            [1.0, 0.0, 0.0],
-            [0.0, theta.cos(), -theta.sin()],
-            [0.0, theta.sin(), theta.cos()],
-        ])
+    pub const fn zero() -> Self {
+        Self::filled(T::ZERO)
     }
-    pub fn rotation_matrix3x3_y(theta: f32) -> Matrix<3, 3> {
-        Matrix::new([
-            [theta.cos(), 0.0, theta.sin()],
-            [0.0, 1.0, 0.0],
-            [-theta.sin(), 0.0, theta.cos()],
-        ])
+
+    pub const fn ones() -> Self {
+        Self::filled(T::ONE)
     }
-    pub fn rotation_matrix3x3_z(theta: f32) -> Matrix<3, 3> {
-        Matrix::new([
-            [theta.cos(), -theta.sin(), 0.0],
-            [theta.sin(), theta.cos(), 0.0],
-            [0.0, 0.0, 1.0],
-        ])
+
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self::new(array::from_fn(|r| array::from_fn(|c| f(r, c))))
     }
 
-    pub fn size(&self) -> (usize, usize) {
+    pub const fn size(&self) -> (usize, usize) {
         (ROWS, COLUMNS)
     }
 
-    pub fn transpose(&self) -> Matrix<COLUMNS, ROWS> {
-        let mut transposed = [[f32::default(); ROWS]; COLUMNS];
+    pub const fn get(&self, row: usize, column: usize) -> T {
+        self.data[row][column]
+    }
 
-        for r in 0..ROWS {
-            for c in 0..COLUMNS {
+    pub const fn set(&mut self, row: usize, column: usize, value: T) {
+        self.data[row][column] = value;
+    }
+
+    pub const fn as_array(&self) -> &[[T; COLUMNS]; ROWS] {
+        &self.data
+    }
+
+    pub const fn as_mut_array(&mut self) -> &mut [[T; COLUMNS]; ROWS] {
+        &mut self.data
+    }
+
+    pub const fn transpose(&self) -> Matrix<COLUMNS, ROWS, T> {
+        let mut transposed = [[T::ZERO; ROWS]; COLUMNS];
+
+        let mut r = 0;
+        while r < ROWS {
+            let mut c = 0;
+            while c < COLUMNS {
                 transposed[c][r] = self.data[r][c];
+                c += 1;
             }
+            r += 1;
         }
 
         Matrix::new(transposed)
     }
 
+    pub fn map(&self, mut f: impl FnMut(T) -> T) -> Self {
+        Self::from_fn(|r, c| f(self.data[r][c]))
+    }
+
+    pub fn zip_map(&self, rhs: &Self, mut f: impl FnMut(T, T) -> T) -> Self {
+        Self::from_fn(|r, c| f(self.data[r][c], rhs.data[r][c]))
+    }
+
+    pub fn fold<B>(&self, init: B, f: impl FnMut(B, T) -> B) -> B {
+        self.iter().copied().fold(init, f)
+    }
+
+    /// Returns a [`MatrixFormat`] for choosing how the matrix is displayed.
+    pub fn format(&self) -> MatrixFormat<'_, T, COLUMNS> {
+        MatrixFormat::matrix(self.data.as_flattened(), ROWS, COLUMNS)
+    }
+
+    /// Iterates over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.as_flattened().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.as_flattened_mut().iter_mut()
+    }
+
+    /// Iterates over all elements in row-major order together with their
+    /// `(row, column)` position.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.iter()
+            .enumerate()
+            .map(|(i, value)| ((i / COLUMNS, i % COLUMNS), value))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T; COLUMNS]> {
+        self.data.iter()
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = [T; ROWS]> + '_ {
+        (0..COLUMNS).map(|c| array::from_fn(|r| self.data[r][c]))
+    }
+
+    pub fn row<const I: usize>(&self) -> Matrix<1, COLUMNS, T> {
+        const { assert!(I < ROWS, "Row index out of bounds") }
+        Matrix::new([self.data[I]])
+    }
+
+    pub fn column<const J: usize>(&self) -> Matrix<ROWS, 1, T> {
+        const { assert!(J < COLUMNS, "Column index out of bounds") }
+        Matrix::from_fn(|r, _| self.data[r][J])
+    }
+
+    /// Copies the `R`x`C` block whose top-left corner is at `(R0, C0)`.
+    pub fn submatrix<const R0: usize, const C0: usize, const R: usize, const C: usize>(
+        &self,
+    ) -> Matrix<R, C, T> {
+        self.view::<R0, C0, R, C>().to_matrix()
+    }
+
+    /// Borrows the `R`x`C` block whose top-left corner is at `(R0, C0)`
+    /// without copying it.
+    pub fn view<const R0: usize, const C0: usize, const R: usize, const C: usize>(
+        &self,
+    ) -> MatrixView<'_, R, C, T> {
+        self.as_view().view::<R0, C0, R, C>()
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, ROWS, COLUMNS, T> {
+        MatrixView::new(self.data.as_flattened(), 0, COLUMNS, 1)
+    }
+
+    pub fn row_view<const I: usize>(&self) -> MatrixView<'_, 1, COLUMNS, T> {
+        self.view::<I, 0, 1, COLUMNS>()
+    }
+
+    pub fn column_view<const J: usize>(&self) -> MatrixView<'_, ROWS, 1, T> {
+        self.view::<0, J, ROWS, 1>()
+    }
+
+    pub fn transpose_view(&self) -> MatrixView<'_, COLUMNS, ROWS, T> {
+        self.as_view().transpose()
+    }
+
+    /// Overwrites the block whose top-left corner is at `(R0, C0)` with `block`.
+    pub fn set_block<const R0: usize, const C0: usize, const R: usize, const C: usize>(
+        &mut self,
+        block: &Matrix<R, C, T>,
+    ) {
+        const {
+            assert!(R0 + R <= ROWS, "Block rows out of bounds");
+            assert!(C0 + C <= COLUMNS, "Block columns out of bounds");
+        }
+        for (r, row) in block.data.iter().enumerate() {
+            self.data[R0 + r][C0..C0 + C].copy_from_slice(row);
+        }
+    }
+
+    /// Places `rhs` to the right of `self`. `OUT` must equal `COLUMNS + C`.
+    pub fn hstack<const C: usize, const OUT: usize>(
+        &self,
+        rhs: &Matrix<ROWS, C, T>,
+    ) -> Matrix<ROWS, OUT, T> {
+        const {
+            assert!(
+                OUT == COLUMNS + C,
+                "hstack output must have COLUMNS + C columns"
+            )
+        }
+        Matrix::from_fn(|r, c| {
+            if c < COLUMNS {
+                self.data[r][c]
+            } else {
+                rhs.data[r][c - COLUMNS]
+            }
+        })
+    }
+
+    /// Places `rhs` below `self`. `OUT` must equal `ROWS + R`.
+    pub fn vstack<const R: usize, const OUT: usize>(
+        &self,
+        rhs: &Matrix<R, COLUMNS, T>,
+    ) -> Matrix<OUT, COLUMNS, T> {
+        const { assert!(OUT == ROWS + R, "vstack output must have ROWS + R rows") }
+        Matrix::from_fn(|r, c| {
+            if r < ROWS {
+                self.data[r][c]
+            } else {
+                rhs.data[r - ROWS][c]
+            }
+        })
+    }
+
+    /// The Kronecker product, which replaces each element `a` of `self` with
+    /// the block `a * rhs`. `OR` must equal `ROWS * R` and `OC` must equal
+    /// `COLUMNS * C`.
+    pub fn kronecker<const R: usize, const C: usize, const OR: usize, const OC: usize>(
+        &self,
+        rhs: &Matrix<R, C, T>,
+    ) -> Matrix<OR, OC, T> {
+        const {
+            assert!(
+                OR == ROWS * R && OC == COLUMNS * C,
+                "kronecker output must have ROWS * R rows and COLUMNS * C columns"
+            )
+        }
+        Matrix::from_fn(|r, c| self.data[r / R][c / C] * rhs.data[r % R][c % C])
+    }
+
+    /// Element-wise product.
+    pub fn hadamard(&self, rhs: &Self) -> Self {
+        self.zip_map(rhs, |a, b| a * b)
+    }
+
+    /// Element-wise quotient. Division by a zero element follows the element
+    /// type's semantics: an infinity or NaN for `f32`.
+    pub fn hadamard_div(&self, rhs: &Self) -> Self {
+        self.zip_map(rhs, |a, b| a / b)
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+    }
+
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        for row in self.data.iter_mut() {
+            row.swap(a, b);
+        }
+    }
+
+    /// Returns a copy without the given row. `OUT` must equal `ROWS - 1`.
+    pub fn remove_row<const OUT: usize>(&self, row: usize) -> Matrix<OUT, COLUMNS, T> {
+        const { assert!(OUT + 1 == ROWS, "remove_row output must have ROWS - 1 rows") }
+        assert!(
+            row < ROWS,
+            "Row index {} out of bounds for {} rows",
+            row,
+            ROWS
+        );
+        Matrix::from_fn(|r, c| self.data[if r < row { r } else { r + 1 }][c])
+    }
+
+    /// Returns a copy without the given column. `OUT` must equal `COLUMNS - 1`.
+    pub fn remove_column<const OUT: usize>(&self, column: usize) -> Matrix<ROWS, OUT, T> {
+        const {
+            assert!(
+                OUT + 1 == COLUMNS,
+                "remove_column output must have COLUMNS - 1 columns"
+            )
+        }
+        assert!(
+            column < COLUMNS,
+            "Column index {} out of bounds for {} columns",
+            column,
+            COLUMNS
+        );
+        Matrix::from_fn(|r, c| self.data[r][if c < column { c } else { c + 1 }])
+    }
+
+    /// Like `*self * *rhs`, but fails with [`MathError::ShapeMismatch`] if the
+    /// inner dimensions differ and with [`MathError::NonFinite`] on overflow.
+    pub fn checked_mul<const RROWS: usize, const RCOLUMNS: usize>(
+        &self,
+        rhs: &Matrix<RROWS, RCOLUMNS, T>,
+    ) -> Result<Matrix<ROWS, RCOLUMNS, T>, MathError> {
+        if COLUMNS != RROWS {
+            return Err(MathError::ShapeMismatch {
+                expected: (COLUMNS, RCOLUMNS),
+                found: (RROWS, RCOLUMNS),
+            });
+        }
+        self.multiply(rhs).finite()
+    }
+
+    // The product without shape assertion or finite-value audit; callers
+    // ensure that COLUMNS == RROWS.
+    fn multiply<const RROWS: usize, const RCOLUMNS: usize>(
+        &self,
+        rhs: &Matrix<RROWS, RCOLUMNS, T>,
+    ) -> Matrix<ROWS, RCOLUMNS, T> {
+        let data = array::from_fn(|i| {
+            array::from_fn(|j| {
+                let mut sum = T::ZERO;
+                for k in 0..COLUMNS {
+                    sum += self.data[i][k] * rhs.data[k][j];
+                }
+                sum
+            })
+        });
+
+        Matrix { data }
+    }
+
+    pub fn checked_div(&self, rhs: T) -> Result<Self, MathError> {
+        if rhs == T::ZERO {
+            return Err(MathError::Domain("division by zero"));
+        }
+        self.map(|value| value / rhs).finite()
+    }
+
+    /// Whether no element is infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.iter().all(|value| value.is_finite())
+    }
+
+    // Fails with `NonFinite` if any element is infinite or NaN.
+    pub(crate) fn finite(self) -> Result<Self, MathError> {
+        if self.is_finite() {
+            Ok(self)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Real> Matrix<ROWS, COLUMNS, T> {
+    pub fn rotation_matrix2x2(theta: T) -> Matrix<2, 2, T> {
+        let (sin, cos) = (theta.sin(), theta.cos());
+        Matrix::new([[cos, -sin], [sin, cos]])
+    }
+
+    pub fn rotation_matrix3x3_x(theta: T) -> Matrix<3, 3, T> {
+        let (sin, cos) = (theta.sin(), theta.cos());
+        let (zero, one) = (T::ZERO, T::ONE);
+        Matrix::new([[one, zero, zero], [zero, cos, -sin], [zero, sin, cos]])
+    }
+    pub fn rotation_matrix3x3_y(theta: T) -> Matrix<3, 3, T> {
+        let (sin, cos) = (theta.sin(), theta.cos());
+        let (zero, one) = (T::ZERO, T::ONE);
+        Matrix::new([[cos, zero, sin], [zero, one, zero], [-sin, zero, cos]])
+    }
+    pub fn rotation_matrix3x3_z(theta: T) -> Matrix<3, 3, T> {
+        let (sin, cos) = (theta.sin(), theta.cos());
+        let (zero, one) = (T::ZERO, T::ONE);
+        Matrix::new([[cos, -sin, zero], [sin, cos, zero], [zero, zero, one]])
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
+    /// Element-wise sum, usable in const contexts.
+    pub const fn const_add(self, rhs: Self) -> Self {
+        let mut data = self.data;
+
+        let mut r = 0;
+        while r < ROWS {
+            let mut c = 0;
+            while c < COLUMNS {
+                data[r][c] += rhs.data[r][c];
+                c += 1;
+            }
+            r += 1;
+        }
+
+        Self { data }
+    }
+
+    /// Element-wise difference, usable in const contexts.
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        let mut data = self.data;
+
+        let mut r = 0;
+        while r < ROWS {
+            let mut c = 0;
+            while c < COLUMNS {
+                data[r][c] -= rhs.data[r][c];
+                c += 1;
+            }
+            r += 1;
+        }
+
+        Self { data }
+    }
+
+    pub const fn scale(self, factor: f32) -> Self {
+        let mut data = self.data;
+
+        let mut r = 0;
+        while r < ROWS {
+            let mut c = 0;
+            while c < COLUMNS {
+                data[r][c] *= factor;
+                c += 1;
+            }
+            r += 1;
+        }
+
+        Self { data }
+    }
+
+    pub fn has_nan(&self) -> bool {
+        self.iter().any(|value| value.is_nan())
+    }
+
+    pub fn checked_to_vector2(&self) -> Result<Vector2, MathError> {
+        if (ROWS, COLUMNS) != (2, 1) {
+            return Err(MathError::ShapeMismatch {
+                expected: (2, 1),
+                found: (ROWS, COLUMNS),
+            });
+        }
+        Ok(Vector2::new(self.data[0][0], self.data[1][0]))
+    }
+
+    pub fn checked_to_vector3(&self) -> Result<Vector3, MathError> {
+        if (ROWS, COLUMNS) != (3, 1) {
+            return Err(MathError::ShapeMismatch {
+                expected: (3, 1),
+                found: (ROWS, COLUMNS),
+            });
+        }
+        Ok(Vector3::new(
+            self.data[0][0],
+            self.data[1][0],
+            self.data[2][0],
+        ))
+    }
+
     pub fn to_vector2(&self) -> Vector2 {
         assert_eq!(ROWS, 2, "Expected a matrix with 2 rows");
         assert_eq!(COLUMNS, 1, "Expected a matrix with 1 column");
@@ -71,93 +454,183 @@ impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
     }
 }
 
-impl Matrix<2, 2> {
-    pub fn determinant(&self) -> f32 {
-        self.data[0][0] * self.data[1][1] - self.data[1][0] * self.data[0][1]
+impl<const N: usize, T: Scalar> Matrix<N, N, T> {
+    pub const IDENTITY: Self = Self::identity();
+
+    pub const fn identity() -> Self {
+        let mut data = [[T::ZERO; N]; N];
+
+        let mut i = 0;
+        while i < N {
+            data[i][i] = T::ONE;
+            i += 1;
+        }
+
+        Self { data }
+    }
+
+    /// Inverts the matrix by Gauss-Jordan elimination with partial pivoting.
+    pub fn checked_inverse(&self) -> Result<Self, MathError> {
+        let mut a = self.data;
+        let mut inverse = Self::IDENTITY.data;
+        for k in 0..N {
+            let pivot = (k..N)
+                .max_by(|&x, &y| a[x][k].magnitude().total_cmp(&a[y][k].magnitude()))
+                .unwrap();
+            if a[pivot][k] == T::ZERO {
+                return Err(MathError::Singular);
+            }
+            a.swap(k, pivot);
+            inverse.swap(k, pivot);
+            let divisor = a[k][k];
+            a[k].iter_mut().for_each(|value| *value /= divisor);
+            inverse[k].iter_mut().for_each(|value| *value /= divisor);
+            let (pivot_row, pivot_inverse) = (a[k], inverse[k]);
+            for r in (0..N).filter(|&r| r != k) {
+                let factor = a[r][k];
+                for (value, p) in a[r].iter_mut().zip(pivot_row) {
+                    *value -= factor * p;
+                }
+                for (value, p) in inverse[r].iter_mut().zip(pivot_inverse) {
+                    *value -= factor * p;
+                }
+            }
+        }
+        Self::new(inverse).finite()
+    }
+
+    /// The determinant, by the explicit formula for 2x2 matrices and by
+    /// Gaussian elimination with partial pivoting otherwise. It is exact for
+    /// exact element types such as [`Rational`](crate::rational::Rational).
+    pub fn determinant(&self) -> T {
+        if N == 2 {
+            return self.data[0][0] * self.data[1][1] - self.data[1][0] * self.data[0][1];
+        }
+        let mut a = self.data;
+        let mut determinant = T::ONE;
+        for k in 0..N {
+            let pivot = (k..N)
+                .max_by(|&x, &y| a[x][k].magnitude().total_cmp(&a[y][k].magnitude()))
+                .unwrap();
+            if a[pivot][k] == T::ZERO {
+                return T::ZERO;
+            }
+            if pivot != k {
+                a.swap(k, pivot);
+                determinant = -determinant;
+            }
+            let pivot_row = a[k];
+            determinant *= pivot_row[k];
+            for row in a[k + 1..].iter_mut() {
+                let factor = row[k] / pivot_row[k];
+                for (value, p) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *value -= factor * *p;
+                }
+            }
+        }
+        determinant
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Add for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Add for Matrix<ROWS, COLUMNS, T> {
     type Output = Self;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn add(self, rhs: Self) -> Self::Output {
         let data = array::from_fn(|r| array::from_fn(|c| self.data[r][c] + rhs.data[r][c]));
 
-        Self { data }
+        audited(Self { data }, "Matrix + Matrix")
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Add<f32> for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Add<T> for Matrix<ROWS, COLUMNS, T> {
     type Output = Self;
-    fn add(self, rhs: f32) -> Self::Output {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn add(self, rhs: T) -> Self::Output {
         let data = array::from_fn(|r| array::from_fn(|c| self.data[r][c] + rhs));
 
-        Self { data }
+        audited(Self { data }, format_args!("Matrix + {}", type_name::<T>()))
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> AddAssign for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> AddAssign for Matrix<ROWS, COLUMNS, T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn add_assign(&mut self, rhs: Self) {
         for r in 0..ROWS {
             for c in 0..COLUMNS {
                 self.data[r][c] += rhs.data[r][c];
             }
         }
+        audit(self, "Matrix += Matrix");
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> AddAssign<f32> for Matrix<ROWS, COLUMNS> {
-    fn add_assign(&mut self, rhs: f32) {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> AddAssign<T> for Matrix<ROWS, COLUMNS, T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn add_assign(&mut self, rhs: T) {
         for r in 0..ROWS {
             for c in 0..COLUMNS {
                 self.data[r][c] += rhs;
             }
         }
+        audit(self, format_args!("Matrix += {}", type_name::<T>()));
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Sub for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Sub for Matrix<ROWS, COLUMNS, T> {
     type Output = Self;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn sub(self, rhs: Self) -> Self::Output {
         let data = array::from_fn(|r| array::from_fn(|c| self.data[r][c] - rhs.data[r][c]));
 
-        Self { data }
+        audited(Self { data }, "Matrix - Matrix")
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Sub<f32> for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Sub<T> for Matrix<ROWS, COLUMNS, T> {
     type Output = Self;
-    fn sub(self, rhs: f32) -> Self::Output {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn sub(self, rhs: T) -> Self::Output {
         let data = array::from_fn(|r| array::from_fn(|c| self.data[r][c] - rhs));
 
-        Self { data }
+        audited(Self { data }, format_args!("Matrix - {}", type_name::<T>()))
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> SubAssign for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> SubAssign for Matrix<ROWS, COLUMNS, T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn sub_assign(&mut self, rhs: Self) {
         for r in 0..ROWS {
             for c in 0..COLUMNS {
                 self.data[r][c] -= rhs.data[r][c];
             }
         }
+        audit(self, "Matrix -= Matrix");
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> SubAssign<f32> for Matrix<ROWS, COLUMNS> {
-    fn sub_assign(&mut self, rhs: f32) {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> SubAssign<T> for Matrix<ROWS, COLUMNS, T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn sub_assign(&mut self, rhs: T) {
         for r in 0..ROWS {
             for c in 0..COLUMNS {
                 self.data[r][c] -= rhs;
             }
         }
+        audit(self, format_args!("Matrix -= {}", type_name::<T>()));
     }
 }
 
-impl<const LROWS: usize, const LCOLUMNS: usize, const RROWS: usize, const RCOLUMNS: usize>
-    Mul<Matrix<RROWS, RCOLUMNS>> for Matrix<LROWS, LCOLUMNS>
+impl<
+    const LROWS: usize,
+    const LCOLUMNS: usize,
+    const RROWS: usize,
+    const RCOLUMNS: usize,
+    T: Scalar,
+> Mul<Matrix<RROWS, RCOLUMNS, T>> for Matrix<LROWS, LCOLUMNS, T>
 {
-    type Output = Matrix<LROWS, RCOLUMNS>;
-    fn mul(self, rhs: Matrix<RROWS, RCOLUMNS>) -> Self::Output {
+    type Output = Matrix<LROWS, RCOLUMNS, T>;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul(self, rhs: Matrix<RROWS, RCOLUMNS, T>) -> Self::Output {
         assert_eq!(
             LCOLUMNS, RROWS,
             "Matrix dimension mismatch: Expected left matrix with shape (a * b) and right matrix with shape (b * c), \
@@ -165,60 +638,54 @@ impl<const LROWS: usize, const LCOLUMNS: usize, const RROWS: usize, const RCOLUM
             LCOLUMNS, RROWS
         );
 
-        let mut data = [[f32::default(); RCOLUMNS]; LROWS];
-
-        for i in 0..LROWS {
-            for j in 0..RCOLUMNS {
-                let mut sum = f32::default();
-                for k in 0..LCOLUMNS {
-                    sum = sum + (self.data[i][k] * rhs.data[k][j]);
-                }
-                data[i][j] = sum;
-            }
-        }
-
-        Matrix { data }
+        audited(self.multiply(&rhs), "Matrix * Matrix")
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Mul<f32> for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Mul<T> for Matrix<ROWS, COLUMNS, T> {
     type Output = Self;
-    fn mul(self, rhs: f32) -> Self::Output {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul(self, rhs: T) -> Self::Output {
         let data = array::from_fn(|r| array::from_fn(|c| self.data[r][c] * rhs));
 
-        Self { data }
+        audited(Self { data }, format_args!("Matrix * {}", type_name::<T>()))
     }
 }
-impl<const ROWS: usize, const COLUMNS: usize> MulAssign<f32> for Matrix<ROWS, COLUMNS> {
-    fn mul_assign(&mut self, rhs: f32) {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> MulAssign<T> for Matrix<ROWS, COLUMNS, T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul_assign(&mut self, rhs: T) {
         for r in 0..ROWS {
             for c in 0..COLUMNS {
                 self.data[r][c] *= rhs;
             }
         }
+        audit(self, format_args!("Matrix *= {}", type_name::<T>()));
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Div<f32> for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Div<T> for Matrix<ROWS, COLUMNS, T> {
     type Output = Self;
-    fn div(self, rhs: f32) -> Self::Output {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn div(self, rhs: T) -> Self::Output {
         let data = array::from_fn(|r| array::from_fn(|c| self.data[r][c] / rhs));
 
-        Self { data }
+        audited(Self { data }, format_args!("Matrix / {}", type_name::<T>()))
     }
 }
-impl<const ROWS: usize, const COLUMNS: usize> DivAssign<f32> for Matrix<ROWS, COLUMNS> {
-    fn div_assign(&mut self, rhs: f32) {
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> DivAssign<T> for Matrix<ROWS, COLUMNS, T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn div_assign(&mut self, rhs: T) {
         for r in 0..ROWS {
             for c in 0..COLUMNS {
                 self.data[r][c] /= rhs;
             }
         }
+        audit(self, format_args!("Matrix /= {}", type_name::<T>()));
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Debug for Matrix<ROWS, COLUMNS> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<const ROWS: usize, const COLUMNS: usize, T: Debug> Debug for Matrix<ROWS, COLUMNS, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         writeln!(f, "Matrix ({}x{}):", ROWS, COLUMNS)?;
         for r in 0..ROWS {
             for c in 0..COLUMNS {
@@ -230,27 +697,20 @@ impl<const ROWS: usize, const COLUMNS: usize> Debug for Matrix<ROWS, COLUMNS> {
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Display for Matrix<ROWS, COLUMNS> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Matrix ({}x{}):", ROWS, COLUMNS)?;
-        for r in 0..ROWS {
-            for c in 0..COLUMNS {
-                write!(f, "{} ", self.data[r][c])?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Display for Matrix<ROWS, COLUMNS, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.format(), f)
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> Index<usize> for Matrix<ROWS, COLUMNS> {
-    type Output = [f32; COLUMNS];
+impl<const ROWS: usize, const COLUMNS: usize, T> Index<usize> for Matrix<ROWS, COLUMNS, T> {
+    type Output = [T; COLUMNS];
     fn index(&self, row: usize) -> &Self::Output {
         &self.data[row]
     }
 }
 
-impl<const ROWS: usize, const COLUMNS: usize> IndexMut<usize> for Matrix<ROWS, COLUMNS> {
+impl<const ROWS: usize, const COLUMNS: usize, T> IndexMut<usize> for Matrix<ROWS, COLUMNS, T> {
     fn index_mut(&mut self, row: usize) -> &mut Self::Output {
         &mut self.data[row]
     }
@@ -1,2 +1,20 @@
+pub mod binary;
+pub mod cholesky;
+#[cfg(feature = "std")]
+pub mod dmatrix;
+pub mod format;
+pub mod functions;
+pub mod hermitian;
+pub mod lu;
 pub mod matrix;
+pub mod norm;
+pub mod orthogonal;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod parse;
+pub mod qr;
+pub mod rref;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod vector;
+pub mod view;
@@ -0,0 +1,185 @@
+//! Matrix norms and conditioning diagnostics.
+//!
+//! Singular values are computed with a one-sided Jacobi SVD carried out in
+//! `f64`, which is accurate for the small matrices this crate targets. The
+//! condition number estimate follows Higham's refinement of Hager's method and
+//! only needs an LU factorization, not the inverse.
+
+use core::array;
+
+use crate::{
+    error::MathError,
+    linear_algebra::{lu::MatrixLu, matrix::Matrix},
+    math::sqrt_f64,
+};
+
+// Upper bound on Jacobi sweeps; convergence normally takes fewer than ten.
+const MAX_SWEEPS: usize = 64;
+
+// Upper bound on Hager's iteration; it rarely needs more than two or three.
+const MAX_ESTIMATE_ITERATIONS: usize = 5;
+
+impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
+    /// Square root of the sum of all squared entries.
+    pub fn frobenius_norm(&self) -> f32 {
+        let sum = self.fold(0.0f64, |sum, value| sum + value as f64 * value as f64);
+        sqrt_f64(sum) as f32
+    }
+
+    /// Largest absolute column sum.
+    pub fn one_norm(&self) -> f32 {
+        self.columns()
+            .map(|column| column.iter().map(|value| value.abs()).sum::<f32>())
+            .fold(0.0, f32::max)
+    }
+
+    /// Largest absolute row sum.
+    pub fn infinity_norm(&self) -> f32 {
+        self.rows()
+            .map(|row| row.iter().map(|value| value.abs()).sum::<f32>())
+            .fold(0.0, f32::max)
+    }
+
+    /// Largest absolute entry.
+    pub fn max_norm(&self) -> f32 {
+        self.fold(0.0, |max, value| max.max(value.abs()))
+    }
+
+    /// Largest singular value.
+    pub fn spectral_norm(&self) -> f32 {
+        self.singular_values().first().copied().unwrap_or(0.0)
+    }
+
+    /// The singular values in descending order. A matrix with more columns
+    /// than rows has at most `ROWS` nonzero singular values; the rest are zero.
+    pub fn singular_values(&self) -> [f32; COLUMNS] {
+        let mut a: [[f64; COLUMNS]; ROWS] =
+            array::from_fn(|r| array::from_fn(|c| self[r][c] as f64));
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..COLUMNS {
+                for q in p + 1..COLUMNS {
+                    let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                    for row in &a {
+                        alpha += row[p] * row[p];
+                        beta += row[q] * row[q];
+                        gamma += row[p] * row[q];
+                    }
+                    if gamma == 0.0 || gamma.abs() <= f64::EPSILON * sqrt_f64(alpha * beta) {
+                        continue;
+                    }
+                    rotated = true;
+                    // Rotate columns p and q so that they become orthogonal.
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = if zeta.abs() > 1e150 {
+                        0.5 / zeta
+                    } else {
+                        zeta.signum() / (zeta.abs() + sqrt_f64(1.0 + zeta * zeta))
+                    };
+                    let cos = 1.0 / sqrt_f64(1.0 + t * t);
+                    let sin = cos * t;
+                    for row in a.iter_mut() {
+                        let (x, y) = (row[p], row[q]);
+                        row[p] = cos * x - sin * y;
+                        row[q] = sin * x + cos * y;
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        let mut values: [f32; COLUMNS] =
+            array::from_fn(|c| sqrt_f64(a.iter().map(|row| row[c] * row[c]).sum()) as f32);
+        values.sort_unstable_by(|x, y| y.total_cmp(x));
+        values
+    }
+
+    /// Number of singular values above `max(ROWS, COLUMNS) * σ_max * f32::EPSILON`.
+    pub fn rank(&self) -> usize {
+        let values = self.singular_values();
+        let largest = values.first().copied().unwrap_or(0.0);
+        let tolerance = ROWS.max(COLUMNS) as f32 * largest * f32::EPSILON;
+        values.iter().filter(|&&value| value > tolerance).count()
+    }
+
+    /// Number of singular values strictly greater than `tolerance`.
+    pub fn rank_with_tolerance(&self, tolerance: f32) -> usize {
+        self.singular_values()
+            .iter()
+            .filter(|&&value| value > tolerance)
+            .count()
+    }
+}
+
+impl<const N: usize> Matrix<N, N> {
+    /// Sum of the diagonal entries.
+    pub fn trace(&self) -> f32 {
+        (0..N).map(|i| self[i][i]).sum()
+    }
+
+    /// The exact 2-norm condition number, `σ_max / σ_min`. Singular matrices
+    /// have an infinite condition number.
+    pub fn condition_number(&self) -> f32 {
+        let values = self.singular_values();
+        match (values.first(), values.last()) {
+            (Some(_), Some(&0.0)) => f32::INFINITY,
+            (Some(&largest), Some(&smallest)) => largest / smallest,
+            _ => 1.0,
+        }
+    }
+
+    /// Estimates the 1-norm condition number, `‖A‖₁ ‖A⁻¹‖₁`, without forming
+    /// the inverse. The estimate never exceeds the true value and is usually
+    /// exact or within a small factor of it.
+    ///
+    /// Returns [`MathError::NonFinite`] if an entry is infinite or NaN, and
+    /// [`MathError::Singular`] if LU factorization meets a zero pivot.
+    pub fn condition_estimate(&self) -> Result<f32, MathError> {
+        if N == 0 {
+            return Ok(1.0);
+        }
+        let lu = self.finite()?.lu().ok_or(MathError::Singular)?;
+        Ok(self.one_norm() * inverse_one_norm_estimate(&lu))
+    }
+}
+
+// Hager's estimate of `‖A⁻¹‖₁` from the LU factorization of `A`, taking the
+// larger of it and Higham's alternating-sign lower bound to guard against the
+// method's known worst cases.
+fn inverse_one_norm_estimate<const N: usize>(lu: &MatrixLu<N>) -> f32 {
+    let one_norm = |v: &[f32; N]| v.iter().map(|value| value.abs()).sum::<f32>();
+    let mut x = [1.0 / N as f32; N];
+    let mut estimate = 0.0;
+    let mut previous = None;
+    for iteration in 0..MAX_ESTIMATE_ITERATIONS {
+        let y = lu.solve(&x);
+        let norm = one_norm(&y);
+        if iteration > 0 && norm <= estimate {
+            break;
+        }
+        estimate = norm;
+        let signs = y.map(|value| if value >= 0.0 { 1.0 } else { -1.0 });
+        let z = lu.solve_transpose(&signs);
+        let (j, largest) = z
+            .iter()
+            .map(|value| value.abs())
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        let zx: f32 = z.iter().zip(&x).map(|(z, x)| z * x).sum();
+        if largest <= zx || previous == Some(j) {
+            break;
+        }
+        previous = Some(j);
+        x = [0.0; N];
+        x[j] = 1.0;
+    }
+    let denominator = N.saturating_sub(1).max(1) as f32;
+    let alternating: [f32; N] = array::from_fn(|i| {
+        let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+        sign * (1.0 + i as f32 / denominator)
+    });
+    let bound = 2.0 * one_norm(&lu.solve(&alternating)) / (3.0 * N as f32);
+    estimate.max(bound)
+}
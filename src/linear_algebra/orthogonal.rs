@@ -0,0 +1,99 @@
+//! Orthonormalization and structural predicates.
+
+use crate::{error::MathError, linear_algebra::matrix::Matrix, math::sqrt};
+
+// Upper bound on Newton iterations for the polar decomposition; quadratic
+// convergence normally needs fewer than ten from a drifted rotation.
+const MAX_POLAR_ITERATIONS: usize = 32;
+
+impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
+    /// Whether every element off the main diagonal is zero.
+    pub fn is_diagonal(&self) -> bool {
+        self.enumerate()
+            .all(|((r, c), &value)| r == c || value == 0.0)
+    }
+
+    /// Whether every element below the main diagonal is zero.
+    pub fn is_upper_triangular(&self) -> bool {
+        self.enumerate()
+            .all(|((r, c), &value)| r <= c || value == 0.0)
+    }
+}
+
+impl<const N: usize> Matrix<N, N> {
+    pub fn is_symmetric(&self) -> bool {
+        (0..N).all(|r| (r + 1..N).all(|c| self[r][c] == self[c][r]))
+    }
+
+    /// Whether `selfᵀ * self` differs from the identity by at most `tolerance`
+    /// in every element.
+    pub fn is_orthogonal(&self, tolerance: f32) -> bool {
+        let columns = *self.transpose().as_array();
+        (0..N).all(|i| {
+            (0..N).all(|j| {
+                let dot: f32 = columns[i].iter().zip(&columns[j]).map(|(a, b)| a * b).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                (dot - expected).abs() <= tolerance
+            })
+        })
+    }
+
+    /// Orthonormalizes the columns in order with modified Gram–Schmidt, so the
+    /// first column keeps its direction and each later one is made
+    /// orthogonal to those before it.
+    ///
+    /// Returns [`MathError::Singular`] if the columns are linearly dependent.
+    pub fn gram_schmidt(&self) -> Result<Self, MathError> {
+        let mut columns = *self.transpose().as_array();
+        for i in 0..N {
+            let (done, rest) = columns.split_at_mut(i);
+            let column = &mut rest[0];
+            let original = length(column);
+            for basis in done.iter() {
+                let dot: f32 = basis.iter().zip(column.iter()).map(|(a, b)| a * b).sum();
+                for (value, b) in column.iter_mut().zip(basis) {
+                    *value -= dot * b;
+                }
+            }
+            let remaining = length(column);
+            if remaining <= N as f32 * f32::EPSILON * original {
+                return Err(MathError::Singular);
+            }
+            column.iter_mut().for_each(|value| *value /= remaining);
+        }
+        Matrix::new(columns).transpose().finite()
+    }
+
+    /// Replaces the matrix with the nearest orthogonal matrix in the Frobenius
+    /// norm, the orthogonal factor of its polar decomposition, computed with
+    /// the Newton iteration `X ← (X + X⁻ᵀ) / 2`.
+    ///
+    /// Unlike [`gram_schmidt`](Self::gram_schmidt) this spreads the correction
+    /// evenly over all columns. A matrix with a negative determinant yields a
+    /// reflection rather than a rotation. Returns [`MathError::Singular`] for
+    /// a singular matrix.
+    pub fn orthonormalize(&self) -> Result<Self, MathError> {
+        let mut x = self.finite()?;
+        for _ in 0..MAX_POLAR_ITERATIONS {
+            let inverse_transpose = x.checked_inverse()?.transpose();
+            let next = x.zip_map(&inverse_transpose, |a, b| 0.5 * (a + b));
+            let change = next.zip_map(&x, |a, b| a - b).max_norm();
+            x = next;
+            if change <= 4.0 * f32::EPSILON * x.max_norm() {
+                return Ok(x);
+            }
+        }
+        // In `f32` the iteration can stall a few ulps short of the tolerance.
+        if x.is_orthogonal(N as f32 * 8.0 * f32::EPSILON) {
+            Ok(x)
+        } else {
+            Err(MathError::NotConverged {
+                iterations: MAX_POLAR_ITERATIONS,
+            })
+        }
+    }
+}
+
+fn length(values: &[f32]) -> f32 {
+    sqrt(values.iter().map(|value| value * value).sum())
+}
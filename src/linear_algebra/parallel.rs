@@ -0,0 +1,121 @@
+//! Multithreaded [`DMatrix`] operations built on [`std::thread::scope`].
+//!
+//! Work is split into contiguous blocks of rows, one per worker. Every element
+//! is computed with exactly the same sequence of floating point operations as
+//! the serial implementation, so results are bitwise identical to
+//! `lhs * rhs`, [`DMatrix::map`] and [`DMatrix::lu`].
+
+use std::{num::NonZeroUsize, thread};
+
+use crate::linear_algebra::dmatrix::{DMatrix, DMatrixLu, eliminate_row, multiply_rows};
+
+// Below this many rows the cost of spawning workers outweighs the gain.
+const MIN_ROWS_PER_THREAD: usize = 16;
+
+/// Number of worker threads used by the `par_*` methods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Threads {
+    /// Use [`std::thread::available_parallelism`], falling back to one thread.
+    #[default]
+    Available,
+    Fixed(NonZeroUsize),
+}
+
+impl Threads {
+    pub fn fixed(count: usize) -> Self {
+        Self::Fixed(NonZeroUsize::new(count).expect("Thread count must be non-zero"))
+    }
+
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Available => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            Self::Fixed(count) => count.get(),
+        }
+    }
+}
+
+// Runs `f(first_row, block)` over row blocks of `data`, one block per worker.
+fn for_each_row_block<F>(data: &mut [f32], columns: usize, threads: Threads, f: F)
+where
+    F: Fn(usize, &mut [f32]) + Sync,
+{
+    let rows = data.len().checked_div(columns).unwrap_or(0);
+    let workers = threads.count().min(rows / MIN_ROWS_PER_THREAD).max(1);
+    if workers == 1 {
+        f(0, data);
+        return;
+    }
+
+    let rows_per_block = rows.div_ceil(workers);
+    thread::scope(|scope| {
+        for (i, block) in data.chunks_mut(rows_per_block * columns).enumerate() {
+            let f = &f;
+            scope.spawn(move || f(i * rows_per_block, block));
+        }
+    });
+}
+
+impl DMatrix {
+    pub fn par_mul(&self, rhs: &DMatrix, threads: Threads) -> DMatrix {
+        assert_eq!(
+            self.columns(),
+            rhs.rows(),
+            "Matrix dimension mismatch: Expected left matrix with shape (a * b) and right matrix with shape (b * c), \
+             but got shapes ({}*{}) and ({}*{}).",
+            self.rows(),
+            self.columns(),
+            rhs.rows(),
+            rhs.columns()
+        );
+        let mut out = DMatrix::zeros(self.rows(), rhs.columns());
+        for_each_row_block(
+            out.as_mut_slice(),
+            rhs.columns(),
+            threads,
+            |first_row, block| multiply_rows(self, rhs, first_row, block),
+        );
+        out
+    }
+
+    pub fn par_map<F>(&self, threads: Threads, f: F) -> DMatrix
+    where
+        F: Fn(f32) -> f32 + Sync,
+    {
+        let mut out = self.clone();
+        for_each_row_block(out.as_mut_slice(), self.columns(), threads, |_, block| {
+            block.iter_mut().for_each(|v| *v = f(*v))
+        });
+        out
+    }
+
+    /// Parallel version of [`DMatrix::lu`]. Pivot selection stays serial; the
+    /// elimination of the rows below each pivot is spread across workers.
+    pub fn par_lu(&self, threads: Threads) -> Option<DMatrixLu> {
+        assert_eq!(
+            self.rows(),
+            self.columns(),
+            "LU decomposition requires a square matrix"
+        );
+        let (n, columns) = self.size();
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+
+        for k in 0..n {
+            lu.pivot(k, &mut permutation, &mut sign)?;
+            let (upper, lower) = lu.as_mut_slice().split_at_mut((k + 1) * columns);
+            let pivot_row = &upper[k * columns..];
+            for_each_row_block(lower, columns, threads, |_, block| {
+                for row in block.chunks_mut(columns) {
+                    eliminate_row(row, pivot_row, k);
+                }
+            });
+        }
+
+        Some(DMatrixLu {
+            lu,
+            permutation,
+            sign,
+        })
+    }
+}
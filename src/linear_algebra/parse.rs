@@ -0,0 +1,485 @@
+//! Text parsing for [`Matrix`], [`Vector2`] and [`Vector3`].
+//!
+//! Accepted matrix formats:
+//!
+//! - the crate's own `Display`/`Debug` output (`Matrix (2x2):` followed by one
+//!   row per line),
+//! - MATLAB-style literals: `[1 2; 3 4]`, rows separated by `;` or newlines,
+//! - NumPy-style nested lists: `[[1, 2], [3, 4]]`,
+//! - bare rows: `1 2\n3 4`.
+//!
+//! Elements may be separated by whitespace and/or commas. Vectors accept any of
+//! the above as long as the shape is a single row or column, as well as
+//! `(1, 2)` and their `Debug` output (`Vector2 { x: 1.0, y: 2.0 }`).
+
+use core::{fmt::Display, iter::Peekable, num::ParseFloatError, str::Chars, str::FromStr};
+
+use crate::linear_algebra::{
+    matrix::Matrix,
+    vector::{Vector2, Vector3},
+};
+
+/// An error produced while parsing a matrix or vector, with the 1-based line
+/// and column where it was detected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseMatrixError {
+    pub kind: ParseMatrixErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseMatrixErrorKind {
+    InvalidNumber(ParseFloatError),
+    UnexpectedCharacter(char),
+    UnexpectedEnd,
+    InvalidHeader,
+    /// A row has a different number of elements than the first row.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The parsed shape (rows, columns) differs from the target type or header.
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl Display for ParseMatrixError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseMatrixErrorKind::InvalidNumber(error) => write!(f, "invalid number ({})", error),
+            ParseMatrixErrorKind::UnexpectedCharacter(c) => {
+                write!(f, "unexpected character {:?}", c)
+            }
+            ParseMatrixErrorKind::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParseMatrixErrorKind::InvalidHeader => write!(f, "invalid matrix header"),
+            ParseMatrixErrorKind::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} elements, expected {}",
+                row, found, expected
+            ),
+            ParseMatrixErrorKind::ShapeMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseMatrixError {}
+
+#[derive(Clone, Copy)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    fn error(self, kind: ParseMatrixErrorKind) -> ParseMatrixError {
+        ParseMatrixError {
+            kind,
+            line: self.line,
+            column: self.column,
+        }
+    }
+}
+
+struct Cursor<'a> {
+    chars: Peekable<Chars<'a>>,
+    rest: &'a str,
+    position: Position,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            chars: s.chars().peekable(),
+            rest: s,
+            position: Position { line: 1, column: 1 },
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        self.rest = &self.rest[c.len_utf8()..];
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, kind: ParseMatrixErrorKind) -> ParseMatrixError {
+        self.position.error(kind)
+    }
+
+    fn unexpected(&mut self) -> ParseMatrixError {
+        match self.peek() {
+            Some(c) => self.error(ParseMatrixErrorKind::UnexpectedCharacter(c)),
+            None => self.error(ParseMatrixErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseMatrixError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    // Skips spaces and tabs but not newlines, which can separate rows.
+    fn skip_spaces(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace() && c != '\n') {
+            self.bump();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if !self.rest.starts_with(keyword) {
+            return false;
+        }
+        for _ in keyword.chars() {
+            self.bump();
+        }
+        true
+    }
+
+    fn number(&mut self) -> Result<f32, ParseMatrixError> {
+        let start = self.position;
+        let rest = self.rest;
+        let mut len = 0;
+        while let Some(c) = self.peek().filter(|&c| starts_number(c)) {
+            len += c.len_utf8();
+            self.bump();
+        }
+        rest[..len]
+            .parse()
+            .map_err(|error| start.error(ParseMatrixErrorKind::InvalidNumber(error)))
+    }
+
+    fn integer(&mut self) -> Result<usize, ParseMatrixError> {
+        let start = self.position;
+        let rest = self.rest;
+        let mut len = 0;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            len += 1;
+            self.bump();
+        }
+        rest[..len]
+            .parse()
+            .map_err(|_| start.error(ParseMatrixErrorKind::InvalidHeader))
+    }
+
+    fn finish(&mut self) -> Result<(), ParseMatrixError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.unexpected()),
+        }
+    }
+}
+
+fn starts_number(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')
+}
+
+// Collects elements row by row and hands them to `push`, which reports whether
+// the element fits in the destination.
+struct Grid<F> {
+    rows: usize,
+    columns: Option<usize>,
+    current: usize,
+    row_start: Position,
+    overflow: Option<Position>,
+    push: F,
+}
+
+impl<F: FnMut(usize, usize, f32) -> bool> Grid<F> {
+    fn new(push: F) -> Self {
+        Self {
+            rows: 0,
+            columns: None,
+            current: 0,
+            row_start: Position { line: 1, column: 1 },
+            overflow: None,
+            push,
+        }
+    }
+
+    fn element(&mut self, cursor: &mut Cursor) -> Result<(), ParseMatrixError> {
+        let position = cursor.position;
+        let value = cursor.number()?;
+        if self.current == 0 {
+            self.row_start = position;
+        }
+        if !(self.push)(self.rows, self.current, value) && self.overflow.is_none() {
+            self.overflow = Some(position);
+        }
+        self.current += 1;
+        Ok(())
+    }
+
+    fn end_row(&mut self) -> Result<(), ParseMatrixError> {
+        if self.current == 0 {
+            return Ok(());
+        }
+        match self.columns {
+            None => self.columns = Some(self.current),
+            Some(expected) if expected != self.current => {
+                return Err(self.row_start.error(ParseMatrixErrorKind::RaggedRow {
+                    row: self.rows,
+                    expected,
+                    found: self.current,
+                }));
+            }
+            Some(_) => {}
+        }
+        self.rows += 1;
+        self.current = 0;
+        Ok(())
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns.unwrap_or(0))
+    }
+
+    // Reads rows until `close` (or the end of input when `close` is `None`).
+    fn rows(
+        &mut self,
+        cursor: &mut Cursor,
+        close: Option<char>,
+        newline_ends_row: bool,
+    ) -> Result<(), ParseMatrixError> {
+        // Whether a comma was read since the last element.
+        let mut separated = false;
+        loop {
+            if newline_ends_row {
+                cursor.skip_spaces();
+            } else {
+                cursor.skip_whitespace();
+            }
+            match cursor.peek() {
+                None if close.is_none() => return self.end_row(),
+                None => return Err(cursor.unexpected()),
+                Some(c) if Some(c) == close => {
+                    cursor.bump();
+                    return self.end_row();
+                }
+                Some(';') => {
+                    cursor.bump();
+                    self.end_row()?;
+                }
+                Some('\n') => {
+                    cursor.bump();
+                    self.end_row()?;
+                }
+                Some(',') if self.current > 0 && !separated => {
+                    cursor.bump();
+                    separated = true;
+                }
+                Some(c) if starts_number(c) => {
+                    self.element(cursor)?;
+                    separated = false;
+                }
+                Some(_) => return Err(cursor.unexpected()),
+            }
+        }
+    }
+
+    // Reads `[a, b], [c, d]]` after the outer opening bracket.
+    fn nested(&mut self, cursor: &mut Cursor, close: char) -> Result<(), ParseMatrixError> {
+        loop {
+            cursor.skip_whitespace();
+            cursor.expect('[')?;
+            self.rows(cursor, Some(']'), false)?;
+            cursor.skip_whitespace();
+            match cursor.peek() {
+                Some(',') => {
+                    cursor.bump();
+                    cursor.skip_whitespace();
+                    if cursor.peek() == Some(close) {
+                        cursor.bump();
+                        return Ok(());
+                    }
+                }
+                Some(c) if c == close => {
+                    cursor.bump();
+                    return Ok(());
+                }
+                Some('[') => {}
+                _ => return Err(cursor.unexpected()),
+            }
+        }
+    }
+
+    fn body(&mut self, cursor: &mut Cursor) -> Result<(), ParseMatrixError> {
+        cursor.skip_whitespace();
+        let close = match cursor.peek() {
+            Some('[') => ']',
+            Some('(') => ')',
+            _ => return self.rows(cursor, None, true),
+        };
+        cursor.bump();
+        cursor.skip_whitespace();
+        if cursor.peek() == Some('[') {
+            self.nested(cursor, close)
+        } else {
+            self.rows(cursor, Some(close), true)
+        }
+    }
+}
+
+// A header's location and the (rows, columns) shape it declares.
+type Header = (Position, (usize, usize));
+
+// Parses an optional `Matrix (RxC):` header.
+fn header(cursor: &mut Cursor) -> Result<Option<Header>, ParseMatrixError> {
+    cursor.skip_whitespace();
+    let start = cursor.position;
+    if !cursor.eat_keyword("Matrix") {
+        return Ok(None);
+    }
+    let invalid = |cursor: &Cursor| cursor.error(ParseMatrixErrorKind::InvalidHeader);
+    cursor.skip_spaces();
+    cursor.expect('(').map_err(|_| invalid(cursor))?;
+    let rows = cursor.integer()?;
+    cursor.expect('x').map_err(|_| invalid(cursor))?;
+    let columns = cursor.integer()?;
+    cursor.expect(')').map_err(|_| invalid(cursor))?;
+    cursor.expect(':').map_err(|_| invalid(cursor))?;
+    Ok(Some((start, (rows, columns))))
+}
+
+fn shape_mismatch(
+    position: Position,
+    expected: (usize, usize),
+    found: (usize, usize),
+) -> ParseMatrixError {
+    position.error(ParseMatrixErrorKind::ShapeMismatch { expected, found })
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> FromStr for Matrix<ROWS, COLUMNS> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor::new(s);
+        let mut matrix = Matrix::ZERO;
+        let expected = (ROWS, COLUMNS);
+
+        let header = header(&mut cursor)?;
+        if let Some((position, found)) = header
+            && found != expected
+        {
+            return Err(shape_mismatch(position, expected, found));
+        }
+
+        let mut grid = Grid::new(|r, c, value| {
+            let fits = r < ROWS && c < COLUMNS;
+            if fits {
+                matrix[r][c] = value;
+            }
+            fits
+        });
+        grid.body(&mut cursor)?;
+        cursor.finish()?;
+
+        let found = grid.shape();
+        if found != expected {
+            let position = grid.overflow.unwrap_or(cursor.position);
+            return Err(shape_mismatch(position, expected, found));
+        }
+        Ok(matrix)
+    }
+}
+
+// Parses a vector of length `N` given as a single row or column, or as the
+// `Debug` output of the vector type called `name`.
+fn parse_vector<const N: usize>(s: &str, name: &str) -> Result<[f32; N], ParseMatrixError> {
+    const FIELDS: [char; 3] = ['x', 'y', 'z'];
+    let mut cursor = Cursor::new(s);
+    let mut values = [0.0; N];
+
+    cursor.skip_whitespace();
+    if cursor.eat_keyword(name) {
+        cursor.skip_whitespace();
+        cursor.expect('{')?;
+        for (i, value) in values.iter_mut().enumerate() {
+            cursor.skip_whitespace();
+            cursor.expect(FIELDS[i])?;
+            cursor.skip_whitespace();
+            cursor.expect(':')?;
+            cursor.skip_whitespace();
+            *value = cursor.number()?;
+            cursor.skip_whitespace();
+            if i + 1 < N {
+                cursor.expect(',')?;
+            } else if cursor.peek() == Some(',') {
+                cursor.bump();
+            }
+        }
+        cursor.skip_whitespace();
+        cursor.expect('}')?;
+        cursor.finish()?;
+        return Ok(values);
+    }
+
+    let mut count = 0;
+    let mut grid = Grid::new(|_, _, value| {
+        let fits = count < N;
+        if fits {
+            values[count] = value;
+        }
+        count += 1;
+        fits
+    });
+    grid.body(&mut cursor)?;
+    cursor.finish()?;
+
+    let found = grid.shape();
+    if found != (N, 1) && found != (1, N) {
+        let position = grid.overflow.unwrap_or(cursor.position);
+        return Err(shape_mismatch(position, (N, 1), found));
+    }
+    Ok(values)
+}
+
+impl FromStr for Vector2 {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_vector(s, "Vector2")?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl FromStr for Vector3 {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_vector(s, "Vector3")?;
+        Ok(Self::new(x, y, z))
+    }
+}
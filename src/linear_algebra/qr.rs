@@ -0,0 +1,132 @@
+//! Householder QR factorization and linear least squares.
+
+use core::array;
+
+use crate::{
+    error::MathError,
+    linear_algebra::matrix::Matrix,
+    scalar::{Real, Scalar},
+};
+
+/// The result of [`Matrix::qr`]: `A = Q * R` with `Q` an `R`x`C` matrix of
+/// orthonormal columns and `R` a `C`x`C` upper triangular matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MatrixQr<const ROWS: usize, const COLUMNS: usize, T = f32> {
+    // The Householder vectors on and below the diagonal, and the strict
+    // upper triangle of `R` above it.
+    qr: Matrix<ROWS, COLUMNS, T>,
+    diagonal: [T; COLUMNS],
+    // `2 / (vᵀv)` for each Householder vector `v`, or zero for a reflection
+    // that was skipped because its column was already zero.
+    tau: [T; COLUMNS],
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Real> Matrix<ROWS, COLUMNS, T> {
+    /// QR decomposition by Householder reflections.
+    ///
+    /// Panics if the matrix has fewer rows than columns.
+    pub fn qr(&self) -> MatrixQr<ROWS, COLUMNS, T> {
+        assert!(
+            ROWS >= COLUMNS,
+            "QR decomposition needs at least as many rows as columns, got {}x{}",
+            ROWS,
+            COLUMNS
+        );
+        let mut qr = *self;
+        let mut diagonal = [T::ZERO; COLUMNS];
+        let mut tau = [T::ZERO; COLUMNS];
+        for k in 0..COLUMNS {
+            let norm = (k..ROWS).fold(T::ZERO, |sum, i| sum + qr[i][k] * qr[i][k]);
+            if norm == T::ZERO {
+                continue;
+            }
+            let norm = norm.sqrt();
+            // Reflecting onto the side opposite the diagonal entry avoids
+            // cancellation in `v = x - alpha e`.
+            let alpha = if qr[k][k].to_f32() >= 0.0 {
+                -norm
+            } else {
+                norm
+            };
+            qr[k][k] -= alpha;
+            let length = (k..ROWS).fold(T::ZERO, |sum, i| sum + qr[i][k] * qr[i][k]);
+            tau[k] = T::from_f32(2.0) / length;
+            diagonal[k] = alpha;
+            for j in k + 1..COLUMNS {
+                let s = (k..ROWS).fold(T::ZERO, |sum, i| sum + qr[i][k] * qr[i][j]) * tau[k];
+                for i in k..ROWS {
+                    let v = qr[i][k];
+                    qr[i][j] -= s * v;
+                }
+            }
+        }
+        MatrixQr { qr, diagonal, tau }
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Real> MatrixQr<ROWS, COLUMNS, T> {
+    /// The thin `Q`, whose columns are orthonormal.
+    pub fn q(&self) -> Matrix<ROWS, COLUMNS, T> {
+        let columns: [[T; ROWS]; COLUMNS] = array::from_fn(|j| {
+            let mut column = array::from_fn(|i| if i == j { T::ONE } else { T::ZERO });
+            for k in (0..COLUMNS).rev() {
+                self.reflect(k, &mut column);
+            }
+            column
+        });
+        Matrix::from_fn(|i, j| columns[j][i])
+    }
+
+    pub fn r(&self) -> Matrix<COLUMNS, COLUMNS, T> {
+        Matrix::from_fn(|i, j| match i.cmp(&j) {
+            core::cmp::Ordering::Less => self.qr[i][j],
+            core::cmp::Ordering::Equal => self.diagonal[i],
+            core::cmp::Ordering::Greater => T::ZERO,
+        })
+    }
+
+    /// Like [`solve`](Self::solve), but returns [`MathError::Singular`] if
+    /// the columns are linearly dependent: if a diagonal entry of `R` is no
+    /// larger than `max(ROWS, COLUMNS) * T::EPSILON` times the largest.
+    pub fn checked_solve(&self, b: &[T; ROWS]) -> Result<[T; COLUMNS], MathError> {
+        let largest = self.diagonal.iter().map(T::magnitude).fold(0.0, f64::max);
+        let tolerance = ROWS.max(COLUMNS) as f64 * T::EPSILON * largest;
+        if self.diagonal.iter().any(|d| d.magnitude() <= tolerance) {
+            return Err(MathError::Singular);
+        }
+        let x = self.solve(b);
+        if x.iter().all(Scalar::is_finite) {
+            Ok(x)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
+
+    /// The least-squares solution of `A x = b`, which minimizes `‖A x - b‖`.
+    /// It is exact when `A` is square and invertible.
+    pub fn solve(&self, b: &[T; ROWS]) -> [T; COLUMNS] {
+        let mut y = *b;
+        for k in 0..COLUMNS {
+            self.reflect(k, &mut y);
+        }
+        let mut x: [T; COLUMNS] = array::from_fn(|i| y[i]);
+        for i in (0..COLUMNS).rev() {
+            for j in i + 1..COLUMNS {
+                x[i] -= self.qr[i][j] * x[j];
+            }
+            x[i] /= self.diagonal[i];
+        }
+        x
+    }
+
+    // Applies Householder reflection `k` to `x`.
+    fn reflect(&self, k: usize, x: &mut [T; ROWS]) {
+        if self.tau[k] == T::ZERO {
+            return;
+        }
+        let s = (k..ROWS).fold(T::ZERO, |sum, i| sum + self.qr[i][k] * x[i]) * self.tau[k];
+        for (i, x) in x.iter_mut().enumerate().skip(k) {
+            *x -= s * self.qr[i][k];
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! Reduced row echelon form by Gauss–Jordan elimination.
+//!
+//! [`Matrix::rref`] returns the result directly; [`Matrix::rref_steps`]
+//! yields every row operation together with the matrix it produced, for
+//! printing the elimination one step at a time:
+//!
+//! ```
+//! # use minmath::linear_algebra::matrix::Matrix;
+//! let m = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+//! for step in m.rref_steps() {
+//!     println!("{}", step);
+//! }
+//! ```
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::{
+    linear_algebra::matrix::Matrix,
+    scalar::{Scalar, write_signed},
+};
+
+/// An elementary row operation. Rows are numbered from zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowOperation<T = f32> {
+    /// Exchange two rows.
+    Swap(usize, usize),
+    /// Multiply `row` by `factor`.
+    Scale { row: usize, factor: T },
+    /// Add `factor` times row `source` to row `target`.
+    AddMultiple {
+        target: usize,
+        source: usize,
+        factor: T,
+    },
+}
+
+// Writes `value` with the formatter's precision, if any.
+fn number<T: Display>(f: &mut Formatter<'_>, value: T) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*}", precision, value),
+        None => write!(f, "{}", value),
+    }
+}
+
+impl<T: Scalar> Display for RowOperation<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Swap(a, b) => write!(f, "R{} <-> R{}", a, b),
+            Self::Scale { row, factor } => {
+                write!(f, "R{} = ", row)?;
+                number(f, factor)?;
+                write!(f, " * R{}", row)
+            }
+            Self::AddMultiple {
+                target,
+                source,
+                factor,
+            } => {
+                write!(f, "R{} = R{}", target, target)?;
+                let precision = f.precision();
+                write_signed(f, &factor, precision, " + ", " - ")?;
+                write!(f, " * R{}", source)
+            }
+        }
+    }
+}
+
+/// One step of the elimination: the operation and the matrix after it.
+///
+/// `Display` prints the operation on its own line followed by the matrix,
+/// passing the precision on to both.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Step<const ROWS: usize, const COLUMNS: usize, T = f32> {
+    pub operation: RowOperation<T>,
+    pub matrix: Matrix<ROWS, COLUMNS, T>,
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Display for Step<ROWS, COLUMNS, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.operation, f)?;
+        writeln!(f)?;
+        Display::fmt(&self.matrix, f)
+    }
+}
+
+/// Iterator over the steps of Gauss–Jordan elimination with partial pivoting,
+/// returned by [`Matrix::rref_steps`].
+///
+/// Entries whose [`magnitude`](Scalar::magnitude) is at most the tolerance
+/// are treated as zero; when a column has no usable pivot they are set to exactly zero
+/// without a reported step.
+#[derive(Clone, Debug)]
+pub struct RrefSteps<const ROWS: usize, const COLUMNS: usize, T = f32> {
+    original: Matrix<ROWS, COLUMNS, T>,
+    matrix: Matrix<ROWS, COLUMNS, T>,
+    tolerance: f64,
+    pivots: [usize; ROWS],
+    row: usize,
+    column: usize,
+    pivoted: bool,
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> RrefSteps<ROWS, COLUMNS, T> {
+    pub fn new(matrix: &Matrix<ROWS, COLUMNS, T>, tolerance: f32) -> Self {
+        Self {
+            original: *matrix,
+            matrix: *matrix,
+            tolerance: tolerance as f64,
+            pivots: [0; ROWS],
+            row: 0,
+            column: 0,
+            pivoted: false,
+        }
+    }
+
+    /// The matrix after the steps taken so far.
+    pub fn matrix(&self) -> &Matrix<ROWS, COLUMNS, T> {
+        &self.matrix
+    }
+
+    /// Runs the remaining steps.
+    pub fn finish(mut self) -> Rref<ROWS, COLUMNS, T> {
+        while self.next().is_some() {}
+        Rref {
+            original: self.original,
+            matrix: self.matrix,
+            pivots: self.pivots,
+            rank: self.row,
+        }
+    }
+
+    fn apply(&mut self, operation: RowOperation<T>) {
+        match operation {
+            RowOperation::Swap(a, b) => self.matrix.swap_rows(a, b),
+            RowOperation::Scale { row, factor } => {
+                self.matrix[row]
+                    .iter_mut()
+                    .for_each(|value| *value *= factor);
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => {
+                let source = self.matrix[source];
+                for (value, s) in self.matrix[target].iter_mut().zip(source) {
+                    *value += factor * s;
+                }
+            }
+        }
+    }
+
+    fn step(&self, operation: RowOperation<T>) -> Option<Step<ROWS, COLUMNS, T>> {
+        Some(Step {
+            operation,
+            matrix: self.matrix,
+        })
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Iterator for RrefSteps<ROWS, COLUMNS, T> {
+    type Item = Step<ROWS, COLUMNS, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.row < ROWS && self.column < COLUMNS {
+            let (row, column) = (self.row, self.column);
+            if !self.pivoted {
+                let mut pivot = row;
+                for r in row + 1..ROWS {
+                    if self.matrix[r][column].magnitude() > self.matrix[pivot][column].magnitude() {
+                        pivot = r;
+                    }
+                }
+                if self.matrix[pivot][column].magnitude() <= self.tolerance {
+                    for r in row..ROWS {
+                        self.matrix[r][column] = T::ZERO;
+                    }
+                    self.column += 1;
+                    continue;
+                }
+                // Once the pivot is chosen it stays, even if scaling it to one
+                // leaves a larger entry below.
+                self.pivoted = true;
+                if pivot != row {
+                    let operation = RowOperation::Swap(row, pivot);
+                    self.apply(operation);
+                    return self.step(operation);
+                }
+            }
+            let value = self.matrix[row][column];
+            if value != T::ONE {
+                let operation = RowOperation::Scale {
+                    row,
+                    factor: T::ONE / value,
+                };
+                self.apply(operation);
+                // Rounding may leave the pivot a few ulps away from one.
+                self.matrix[row][column] = T::ONE;
+                return self.step(operation);
+            }
+            if let Some(target) = (0..ROWS).find(|&r| r != row && self.matrix[r][column] != T::ZERO)
+            {
+                let operation = RowOperation::AddMultiple {
+                    target,
+                    source: row,
+                    factor: -self.matrix[target][column],
+                };
+                self.apply(operation);
+                self.matrix[target][column] = T::ZERO;
+                return self.step(operation);
+            }
+            self.pivots[row] = column;
+            self.pivoted = false;
+            self.row += 1;
+            self.column += 1;
+        }
+        None
+    }
+}
+
+/// A matrix in reduced row echelon form together with the pivot columns
+/// found while reducing it, returned by [`Matrix::rref`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rref<const ROWS: usize, const COLUMNS: usize, T = f32> {
+    original: Matrix<ROWS, COLUMNS, T>,
+    matrix: Matrix<ROWS, COLUMNS, T>,
+    pivots: [usize; ROWS],
+    rank: usize,
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Rref<ROWS, COLUMNS, T> {
+    /// The reduced matrix.
+    pub fn matrix(&self) -> &Matrix<ROWS, COLUMNS, T> {
+        &self.matrix
+    }
+
+    /// The pivot columns in increasing order, one per nonzero row.
+    pub fn pivot_columns(&self) -> &[usize] {
+        &self.pivots[..self.rank]
+    }
+
+    /// The number of pivots.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// A basis of the null space, one vector per non-pivot column. The vector
+    /// for free column `f` has a one in row `f` and zeros in the other free
+    /// rows.
+    pub fn null_space(&self) -> impl Iterator<Item = Matrix<COLUMNS, 1, T>> + '_ {
+        (0..COLUMNS)
+            .filter(|column| !self.pivot_columns().contains(column))
+            .map(|free| {
+                let mut vector = Matrix::<COLUMNS, 1, T>::ZERO;
+                vector[free][0] = T::ONE;
+                for (row, &pivot) in self.pivot_columns().iter().enumerate() {
+                    vector[pivot][0] = -self.matrix[row][free];
+                }
+                vector
+            })
+    }
+
+    /// A basis of the column space: the pivot columns of the original matrix.
+    pub fn column_space(&self) -> impl Iterator<Item = Matrix<ROWS, 1, T>> + '_ {
+        self.pivot_columns()
+            .iter()
+            .map(|&column| Matrix::from_fn(|r, _| self.original[r][column]))
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> Matrix<ROWS, COLUMNS, T> {
+    /// The reduced row echelon form, treating entries of at most
+    /// `max(ROWS, COLUMNS) * T::EPSILON` times the largest magnitude as zero.
+    /// For exact types such as [`Rational`](crate::rational::Rational) only
+    /// exact zeros are.
+    pub fn rref(&self) -> Rref<ROWS, COLUMNS, T> {
+        self.rref_steps().finish()
+    }
+
+    /// The reduced row echelon form, treating entries with a magnitude of at
+    /// most `tolerance` as zero. A tolerance of zero only treats exact zeros
+    /// as zero.
+    pub fn rref_with_tolerance(&self, tolerance: f32) -> Rref<ROWS, COLUMNS, T> {
+        RrefSteps::new(self, tolerance).finish()
+    }
+
+    /// The steps [`rref`](Self::rref) takes, with the same tolerance.
+    pub fn rref_steps(&self) -> RrefSteps<ROWS, COLUMNS, T> {
+        let mut steps = RrefSteps::new(self, 0.0);
+        steps.tolerance = self.default_rref_tolerance();
+        steps
+    }
+
+    fn default_rref_tolerance(&self) -> f64 {
+        let largest = self.fold(0.0, |max: f64, value| max.max(value.magnitude()));
+        ROWS.max(COLUMNS) as f64 * T::EPSILON * largest
+    }
+}
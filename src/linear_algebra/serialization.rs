@@ -0,0 +1,279 @@
+//! `serde` support. Matrices are represented as sequences of rows and vectors
+//! as sequences of their components, so `Matrix<2, 2>::IDENTITY` becomes
+//! `[[1.0, 0.0], [0.0, 1.0]]` in JSON and `Vector3::X` becomes `[1.0, 0.0, 0.0]`.
+//! Deserializing checks that the input has exactly the expected shape.
+//!
+//! Fixed-size matrices and vectors are supported for any [`Scalar`] element
+//! type that is itself `Serialize` or `Deserialize`. A [`Rational`] is the
+//! pair `[numerator, denominator]` and is reduced when it is deserialized,
+//! and a [`Complex`] is the pair `[re, im]`.
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, IgnoredAny, SeqAccess, Visitor},
+    ser::SerializeSeq,
+};
+
+#[cfg(feature = "std")]
+use crate::linear_algebra::dmatrix::DMatrix;
+use crate::{
+    complex::Complex,
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+        view::MatrixView,
+    },
+    rational::Rational,
+    scalar::Scalar,
+};
+
+// Wraps a row so it serializes as a sequence of its elements.
+struct Row<I>(I);
+
+impl<I> Serialize for Row<I>
+where
+    I: Iterator + ExactSizeIterator + Clone,
+    I::Item: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for value in self.0.clone() {
+            seq.serialize_element(&value)?;
+        }
+        seq.end()
+    }
+}
+
+fn serialize_rows<S, R, I>(serializer: S, rows: R) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    R: Iterator<Item = I> + ExactSizeIterator,
+    I: Iterator + ExactSizeIterator + Clone,
+    I::Item: Serialize,
+{
+    let mut seq = serializer.serialize_seq(Some(rows.len()))?;
+    for row in rows {
+        seq.serialize_element(&Row(row))?;
+    }
+    seq.end()
+}
+
+// Fills `values` from a sequence with exactly `values.len()` elements.
+fn fill<'de, A: SeqAccess<'de>, T: Deserialize<'de>>(
+    seq: &mut A,
+    values: &mut [T],
+    expected: &dyn de::Expected,
+) -> Result<(), A::Error> {
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(i, expected))?;
+    }
+    if seq.next_element::<IgnoredAny>()?.is_some() {
+        return Err(de::Error::invalid_length(values.len() + 1, expected));
+    }
+    Ok(())
+}
+
+struct RowLength(usize);
+
+impl de::Expected for RowLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a row of {} numbers", self.0)
+    }
+}
+
+// Deserializes one row of a fixed-size matrix in place.
+struct RowSeed<'a, T>(&'a mut [T]);
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for RowSeed<'_, T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RowSeed<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        de::Expected::fmt(&RowLength(self.0.len()), f)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let expected = RowLength(self.0.len());
+        fill(&mut seq, self.0, &expected)
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar + Serialize> Serialize
+    for Matrix<ROWS, COLUMNS, T>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_rows(
+            serializer,
+            self.as_array().iter().map(|row| row.iter().copied()),
+        )
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar + Serialize> Serialize
+    for MatrixView<'_, ROWS, COLUMNS, T>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_rows(
+            serializer,
+            (0..ROWS).map(|r| (0..COLUMNS).map(move |c| self.get(r, c))),
+        )
+    }
+}
+
+struct MatrixVisitor<const ROWS: usize, const COLUMNS: usize, T>(PhantomData<T>);
+
+impl<'de, const ROWS: usize, const COLUMNS: usize, T: Scalar + Deserialize<'de>> Visitor<'de>
+    for MatrixVisitor<ROWS, COLUMNS, T>
+{
+    type Value = Matrix<ROWS, COLUMNS, T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a {}x{} matrix as a sequence of rows", ROWS, COLUMNS)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut data = [[T::ZERO; COLUMNS]; ROWS];
+        for (i, row) in data.iter_mut().enumerate() {
+            seq.next_element_seed(RowSeed(row))?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(ROWS + 1, &self));
+        }
+        Ok(Matrix::new(data))
+    }
+}
+
+impl<'de, const ROWS: usize, const COLUMNS: usize, T: Scalar + Deserialize<'de>> Deserialize<'de>
+    for Matrix<ROWS, COLUMNS, T>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(MatrixVisitor(PhantomData))
+    }
+}
+
+impl<T: Scalar + Serialize> Serialize for Vector2<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Row([self.x, self.y].into_iter()).serialize(serializer)
+    }
+}
+
+impl<T: Scalar + Serialize> Serialize for Vector3<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Row([self.x, self.y, self.z].into_iter()).serialize(serializer)
+    }
+}
+
+struct VectorVisitor<const N: usize, T>(PhantomData<T>);
+
+impl<'de, const N: usize, T: Scalar + Deserialize<'de>> Visitor<'de> for VectorVisitor<N, T> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} numbers", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[T; N], A::Error> {
+        let mut values = [T::ZERO; N];
+        fill(&mut seq, &mut values, &self)?;
+        Ok(values)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Vector2<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y] = deserializer.deserialize_seq(VectorVisitor(PhantomData))?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Vector3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z] = deserializer.deserialize_seq(VectorVisitor(PhantomData))?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for DMatrix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = self.size().0;
+        serialize_rows(serializer, (0..rows).map(|r| self[r].iter().copied()))
+    }
+}
+
+#[cfg(feature = "std")]
+struct DMatrixVisitor;
+
+#[cfg(feature = "std")]
+impl<'de> Visitor<'de> for DMatrixVisitor {
+    type Value = DMatrix;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a matrix as a sequence of equally long rows")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<DMatrix, A::Error> {
+        let mut data = Vec::new();
+        let mut rows = 0;
+        let mut columns = None;
+        while let Some(row) = seq.next_element::<Vec<f32>>()? {
+            let expected = *columns.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(de::Error::custom(format_args!(
+                    "row {} has {} elements, expected {}",
+                    rows,
+                    row.len(),
+                    expected
+                )));
+            }
+            data.extend(row);
+            rows += 1;
+        }
+        Ok(DMatrix::new(rows, columns.unwrap_or(0), data))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for DMatrix {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(DMatrixVisitor)
+    }
+}
+
+impl Serialize for Rational {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.numerator(), self.denominator()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rational {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (numerator, denominator) = <(i64, i64)>::deserialize(deserializer)?;
+        Rational::checked_new(numerator, denominator).map_err(de::Error::custom)
+    }
+}
+
+impl<T: Serialize> Serialize for Complex<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.re, &self.im).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Complex<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (re, im) = <(T, T)>::deserialize(deserializer)?;
+        Ok(Self { re, im })
+    }
+}
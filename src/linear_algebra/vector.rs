@@ -1,271 +1,592 @@
-use std::{
+use core::{
+    any::type_name,
     fmt::Debug,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::linear_algebra::matrix::Matrix;
+use crate::{
+    error::MathError,
+    finite::{audit, audited},
+    linear_algebra::matrix::Matrix,
+    math::sqrt,
+    scalar::{Real, Scalar},
+};
 
+/// A two-component vector of `T`, `f32` by default.
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Vector2 {
-    pub x: f32,
-    pub y: f32,
+#[repr(C)]
+pub struct Vector2<T = f32> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Vector2 {
-    pub fn new(x: f32, y: f32) -> Self {
+impl<T: Scalar> Vector2<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ONE);
+    pub const X: Self = Self::new(T::ONE, T::ZERO);
+    pub const Y: Self = Self::new(T::ZERO, T::ONE);
+
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
-    pub fn to_matrix(&self) -> Matrix<2, 1> {
+    pub const fn to_matrix(&self) -> Matrix<2, 1, T> {
         Matrix::new([[self.x], [self.y]])
     }
 
-    pub fn dot(&self, rhs: Self) -> f32 {
+    /// Whether no component is infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+}
+
+impl Vector2 {
+    pub const fn dot(&self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y
     }
+
+    /// The outer product `self * rhsᵀ`.
+    pub const fn outer(&self, rhs: Self) -> Matrix<2, 2> {
+        Matrix::new([
+            [self.x * rhs.x, self.x * rhs.y],
+            [self.y * rhs.x, self.y * rhs.y],
+        ])
+    }
+
+    /// Component-wise sum, usable in const contexts.
+    pub const fn const_add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+
+    /// Component-wise difference, usable in const contexts.
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+
+    pub const fn scale(self, factor: f32) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+
+    pub fn length(&self) -> f32 {
+        sqrt(self.dot(*self))
+    }
+
+    /// Scales the vector to unit length. A zero vector yields NaN components;
+    /// use [`Vector2::checked_normalize`] to catch that.
+    pub fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+
+    /// Like [`normalize`](Self::normalize), but scales by the largest
+    /// component first so that the squared length can neither overflow nor
+    /// underflow.
+    pub fn checked_normalize(&self) -> Result<Self, MathError> {
+        let largest = self.x.abs().max(self.y.abs());
+        if !largest.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+        if largest == 0.0 {
+            return Err(MathError::Domain("cannot normalize a zero vector"));
+        }
+        let scaled = self.quotient(largest);
+        scaled.quotient(scaled.length()).finite()
+    }
+
+    pub fn checked_div(&self, rhs: f32) -> Result<Self, MathError> {
+        if rhs == 0.0 {
+            return Err(MathError::Domain("division by zero"));
+        }
+        self.quotient(rhs).finite()
+    }
+
+    // Division without the finite-value audit of the `/` operator.
+    fn quotient(&self, rhs: f32) -> Self {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+
+    pub fn has_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    fn finite(self) -> Result<Self, MathError> {
+        if self.is_finite() {
+            Ok(self)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
 }
 
-impl Add for Vector2 {
+impl<T: Scalar> Add for Vector2<T> {
     type Output = Self;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+        audited(
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            },
+            "Vector2 + Vector2",
+        )
     }
 }
 
-impl Add<f32> for Vector2 {
+impl<T: Scalar> Add<T> for Vector2<T> {
     type Output = Self;
-    fn add(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x + rhs,
-            y: self.y + rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn add(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x + rhs,
+                y: self.y + rhs,
+            },
+            format_args!("Vector2 + {}", type_name::<T>()),
+        )
     }
 }
 
-impl AddAssign for Vector2 {
+impl<T: Scalar> AddAssign for Vector2<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
+        audit(self, "Vector2 += Vector2");
     }
 }
 
-impl AddAssign<f32> for Vector2 {
-    fn add_assign(&mut self, rhs: f32) {
+impl<T: Scalar> AddAssign<T> for Vector2<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn add_assign(&mut self, rhs: T) {
         self.x += rhs;
         self.y += rhs;
+        audit(self, format_args!("Vector2 += {}", type_name::<T>()));
     }
 }
 
-impl Sub for Vector2 {
+impl<T: Scalar> Sub for Vector2<T> {
     type Output = Self;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
+        audited(
+            Self {
+                x: self.x - rhs.x,
+                y: self.y - rhs.y,
+            },
+            "Vector2 - Vector2",
+        )
     }
 }
 
-impl Sub<f32> for Vector2 {
+impl<T: Scalar> Sub<T> for Vector2<T> {
     type Output = Self;
-    fn sub(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x - rhs,
-            y: self.y - rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn sub(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x - rhs,
+                y: self.y - rhs,
+            },
+            format_args!("Vector2 - {}", type_name::<T>()),
+        )
     }
 }
 
-impl SubAssign for Vector2 {
+impl<T: Scalar> SubAssign for Vector2<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
+        audit(self, "Vector2 -= Vector2");
     }
 }
 
-impl SubAssign<f32> for Vector2 {
-    fn sub_assign(&mut self, rhs: f32) {
+impl<T: Scalar> SubAssign<T> for Vector2<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn sub_assign(&mut self, rhs: T) {
         self.x -= rhs;
         self.y -= rhs;
+        audit(self, format_args!("Vector2 -= {}", type_name::<T>()));
     }
 }
 
-impl Mul<f32> for Vector2 {
+impl<T: Scalar> Mul<T> for Vector2<T> {
     type Output = Self;
-    fn mul(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x * rhs,
+                y: self.y * rhs,
+            },
+            format_args!("Vector2 * {}", type_name::<T>()),
+        )
     }
 }
 
-impl MulAssign<f32> for Vector2 {
-    fn mul_assign(&mut self, rhs: f32) {
+impl<T: Scalar> MulAssign<T> for Vector2<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
+        audit(self, format_args!("Vector2 *= {}", type_name::<T>()));
     }
 }
 
-impl Div<f32> for Vector2 {
+impl<T: Scalar> Div<T> for Vector2<T> {
     type Output = Self;
-    fn div(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn div(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x / rhs,
+                y: self.y / rhs,
+            },
+            format_args!("Vector2 / {}", type_name::<T>()),
+        )
     }
 }
 
-impl DivAssign<f32> for Vector2 {
-    fn div_assign(&mut self, rhs: f32) {
+impl<T: Scalar> DivAssign<T> for Vector2<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn div_assign(&mut self, rhs: T) {
         self.x /= rhs;
         self.y /= rhs;
+        audit(self, format_args!("Vector2 /= {}", type_name::<T>()));
     }
 }
 
+/// A three-component vector of `T`, `f32` by default.
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Vector3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+#[repr(C)]
+pub struct Vector3<T = f32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Vector3 {
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
+impl<T: Scalar> Vector3<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ONE, T::ONE);
+    pub const X: Self = Self::new(T::ONE, T::ZERO, T::ZERO);
+    pub const Y: Self = Self::new(T::ZERO, T::ONE, T::ZERO);
+    pub const Z: Self = Self::new(T::ZERO, T::ZERO, T::ONE);
+
+    pub const fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
     }
 
-    pub fn to_matrix(&self) -> Matrix<3, 1> {
+    pub const fn to_matrix(&self) -> Matrix<3, 1, T> {
         Matrix::new([[self.x], [self.y], [self.z]])
     }
 
-    pub fn dot(&self, rhs: Self) -> f32 {
+    /// Whether no component is infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
+impl Vector3 {
+    pub const fn dot(&self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
     }
 
-    pub fn cross(&self, rhs: Self) -> Self {
+    /// Component-wise sum, usable in const contexts.
+    pub const fn const_add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+
+    /// Component-wise difference, usable in const contexts.
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+
+    pub const fn scale(self, factor: f32) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+
+    pub const fn cross(&self, rhs: Self) -> Self {
         Self {
             x: self.y * rhs.z - self.z * rhs.y,
             y: self.z * rhs.x - self.x * rhs.z,
             z: self.x * rhs.y - self.y * rhs.x,
         }
     }
+
+    /// The outer product `self * rhsᵀ`.
+    pub const fn outer(&self, rhs: Self) -> Matrix<3, 3> {
+        Matrix::new([
+            [self.x * rhs.x, self.x * rhs.y, self.x * rhs.z],
+            [self.y * rhs.x, self.y * rhs.y, self.y * rhs.z],
+            [self.z * rhs.x, self.z * rhs.y, self.z * rhs.z],
+        ])
+    }
+
+    /// The skew-symmetric matrix `[v]×` with `[v]× * u == v.cross(u)`.
+    pub const fn cross_matrix(&self) -> Matrix<3, 3> {
+        Matrix::new([
+            [0.0, -self.z, self.y],
+            [self.z, 0.0, -self.x],
+            [-self.y, self.x, 0.0],
+        ])
+    }
+
+    /// Two unit vectors `(b1, b2)` that together with `self` form a
+    /// right-handed orthonormal basis, so `b1.cross(b2) == *self`. `self` must
+    /// have unit length.
+    ///
+    /// Uses the branchless construction of Duff et al., "Building an
+    /// Orthonormal Basis, Revisited" (2017), which is continuous except where
+    /// `z` changes sign.
+    pub fn orthonormal_basis(&self) -> (Self, Self) {
+        let sign = 1.0f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Self::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Self::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    pub fn length(&self) -> f32 {
+        sqrt(self.dot(*self))
+    }
+
+    /// Scales the vector to unit length. A zero vector yields NaN components;
+    /// use [`Vector3::checked_normalize`] to catch that.
+    pub fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+
+    /// Like [`normalize`](Self::normalize), but scales by the largest
+    /// component first so that the squared length can neither overflow nor
+    /// underflow.
+    pub fn checked_normalize(&self) -> Result<Self, MathError> {
+        let largest = self.x.abs().max(self.y.abs()).max(self.z.abs());
+        if !largest.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+        if largest == 0.0 {
+            return Err(MathError::Domain("cannot normalize a zero vector"));
+        }
+        let scaled = self.quotient(largest);
+        scaled.quotient(scaled.length()).finite()
+    }
+
+    pub fn checked_div(&self, rhs: f32) -> Result<Self, MathError> {
+        if rhs == 0.0 {
+            return Err(MathError::Domain("division by zero"));
+        }
+        self.quotient(rhs).finite()
+    }
+
+    // Division without the finite-value audit of the `/` operator.
+    fn quotient(&self, rhs: f32) -> Self {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+
+    pub fn has_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    fn finite(self) -> Result<Self, MathError> {
+        if self.is_finite() {
+            Ok(self)
+        } else {
+            Err(MathError::NonFinite)
+        }
+    }
 }
 
-impl Add for Vector3 {
+impl<T: Scalar> Add for Vector3<T> {
     type Output = Self;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-        }
+        audited(
+            Self {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+                z: self.z + rhs.z,
+            },
+            "Vector3 + Vector3",
+        )
     }
 }
 
-impl Add<f32> for Vector3 {
+impl<T: Scalar> Add<T> for Vector3<T> {
     type Output = Self;
-    fn add(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x + rhs,
-            y: self.y + rhs,
-            z: self.z + rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn add(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x + rhs,
+                y: self.y + rhs,
+                z: self.z + rhs,
+            },
+            format_args!("Vector3 + {}", type_name::<T>()),
+        )
     }
 }
 
-impl AddAssign for Vector3 {
+impl<T: Scalar> AddAssign for Vector3<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
         self.z += rhs.z;
+        audit(self, "Vector3 += Vector3");
     }
 }
 
-impl AddAssign<f32> for Vector3 {
-    fn add_assign(&mut self, rhs: f32) {
+impl<T: Scalar> AddAssign<T> for Vector3<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn add_assign(&mut self, rhs: T) {
         self.x += rhs;
         self.y += rhs;
         self.z += rhs;
+        audit(self, format_args!("Vector3 += {}", type_name::<T>()));
     }
 }
 
-impl Sub for Vector3 {
+impl<T: Scalar> Sub for Vector3<T> {
     type Output = Self;
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
-        }
+        audited(
+            Self {
+                x: self.x - rhs.x,
+                y: self.y - rhs.y,
+                z: self.z - rhs.z,
+            },
+            "Vector3 - Vector3",
+        )
     }
 }
 
-impl Sub<f32> for Vector3 {
+impl<T: Scalar> Sub<T> for Vector3<T> {
     type Output = Self;
-    fn sub(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x - rhs,
-            y: self.y - rhs,
-            z: self.z - rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn sub(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x - rhs,
+                y: self.y - rhs,
+                z: self.z - rhs,
+            },
+            format_args!("Vector3 - {}", type_name::<T>()),
+        )
     }
 }
 
-impl SubAssign for Vector3 {
+impl<T: Scalar> SubAssign for Vector3<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
         self.z -= rhs.z;
+        audit(self, "Vector3 -= Vector3");
     }
 }
 
-impl SubAssign<f32> for Vector3 {
-    fn sub_assign(&mut self, rhs: f32) {
+impl<T: Scalar> SubAssign<T> for Vector3<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn sub_assign(&mut self, rhs: T) {
         self.x -= rhs;
         self.y -= rhs;
         self.z -= rhs;
+        audit(self, format_args!("Vector3 -= {}", type_name::<T>()));
     }
 }
 
-impl Mul<f32> for Vector3 {
+impl<T: Scalar> Mul<T> for Vector3<T> {
     type Output = Self;
-    fn mul(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x * rhs,
-            y: self.y * rhs,
-            z: self.z * rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x * rhs,
+                y: self.y * rhs,
+                z: self.z * rhs,
+            },
+            format_args!("Vector3 * {}", type_name::<T>()),
+        )
     }
 }
 
-impl MulAssign<f32> for Vector3 {
-    fn mul_assign(&mut self, rhs: f32) {
+impl<T: Scalar> MulAssign<T> for Vector3<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
         self.z *= rhs;
+        audit(self, format_args!("Vector3 *= {}", type_name::<T>()));
     }
 }
 
-impl Div<f32> for Vector3 {
+impl<T: Scalar> Div<T> for Vector3<T> {
     type Output = Self;
-    fn div(self, rhs: f32) -> Self::Output {
-        Self {
-            x: self.x / rhs,
-            y: self.y / rhs,
-            z: self.z / rhs,
-        }
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn div(self, rhs: T) -> Self::Output {
+        audited(
+            Self {
+                x: self.x / rhs,
+                y: self.y / rhs,
+                z: self.z / rhs,
+            },
+            format_args!("Vector3 / {}", type_name::<T>()),
+        )
     }
 }
 
-impl DivAssign<f32> for Vector3 {
-    fn div_assign(&mut self, rhs: f32) {
+impl<T: Scalar> DivAssign<T> for Vector3<T> {
+    #[cfg_attr(feature = "debug-finite", track_caller)]
+    fn div_assign(&mut self, rhs: T) {
         self.x /= rhs;
         self.y /= rhs;
         self.z /= rhs;
+        audit(self, format_args!("Vector3 /= {}", type_name::<T>()));
+    }
+}
+
+/// Vector products for any [`Real`] element type, such as the automatic
+/// differentiation types. `f32` vectors have inherent `const` versions, which
+/// take precedence.
+pub trait RealVector: Copy {
+    type Element: Real;
+
+    fn dot(&self, rhs: Self) -> Self::Element;
+
+    fn length(&self) -> Self::Element {
+        self.dot(*self).sqrt()
+    }
+}
+
+/// The cross product of three-component vectors of any [`Real`] element type.
+pub trait Cross {
+    fn cross(&self, rhs: Self) -> Self;
+}
+
+impl<T: Real> RealVector for Vector2<T> {
+    type Element = T;
+
+    fn dot(&self, rhs: Self) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+}
+
+impl<T: Real> RealVector for Vector3<T> {
+    type Element = T;
+
+    fn dot(&self, rhs: Self) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+}
+
+impl<T: Real> Cross for Vector3<T> {
+    fn cross(&self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
     }
 }
@@ -0,0 +1,110 @@
+use core::ops::Index;
+
+use crate::{linear_algebra::matrix::Matrix, scalar::Scalar};
+
+/// A borrowed, read-only window into a [`Matrix`].
+///
+/// Views are strided, so rows, columns, blocks and transposes can all be
+/// looked at without copying any elements. Elements are accessed with
+/// `view[(row, column)]` or [`MatrixView::get`].
+#[derive(Clone, Copy)]
+pub struct MatrixView<'a, const ROWS: usize, const COLUMNS: usize, T = f32> {
+    data: &'a [T],
+    offset: usize,
+    row_stride: usize,
+    column_stride: usize,
+}
+
+impl<'a, const ROWS: usize, const COLUMNS: usize, T: Scalar> MatrixView<'a, ROWS, COLUMNS, T> {
+    pub(crate) fn new(
+        data: &'a [T],
+        offset: usize,
+        row_stride: usize,
+        column_stride: usize,
+    ) -> Self {
+        Self {
+            data,
+            offset,
+            row_stride,
+            column_stride,
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (ROWS, COLUMNS)
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> T {
+        self[(row, column)]
+    }
+
+    pub fn transpose(&self) -> MatrixView<'a, COLUMNS, ROWS, T> {
+        MatrixView::new(self.data, self.offset, self.column_stride, self.row_stride)
+    }
+
+    pub fn view<const R0: usize, const C0: usize, const R: usize, const C: usize>(
+        &self,
+    ) -> MatrixView<'a, R, C, T> {
+        const {
+            assert!(R0 + R <= ROWS, "View rows out of bounds");
+            assert!(C0 + C <= COLUMNS, "View columns out of bounds");
+        }
+        MatrixView::new(
+            self.data,
+            self.offset + R0 * self.row_stride + C0 * self.column_stride,
+            self.row_stride,
+            self.column_stride,
+        )
+    }
+
+    /// Iterates over the viewed elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..ROWS * COLUMNS).map(|i| self.get(i / COLUMNS, i % COLUMNS))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<ROWS, COLUMNS, T> {
+        Matrix::from_fn(|r, c| self.get(r, c))
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T> Index<(usize, usize)>
+    for MatrixView<'_, ROWS, COLUMNS, T>
+{
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(
+            row < ROWS && column < COLUMNS,
+            "Index ({}, {}) out of bounds for a {}x{} view",
+            row,
+            column,
+            ROWS,
+            COLUMNS
+        );
+        &self.data[self.offset + row * self.row_stride + column * self.column_stride]
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> PartialEq<Matrix<ROWS, COLUMNS, T>>
+    for MatrixView<'_, ROWS, COLUMNS, T>
+{
+    fn eq(&self, other: &Matrix<ROWS, COLUMNS, T>) -> bool {
+        other
+            .enumerate()
+            .all(|((r, c), &value)| self.get(r, c) == value)
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize, T: Scalar> core::fmt::Debug
+    for MatrixView<'_, ROWS, COLUMNS, T>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "MatrixView ({}x{}):", ROWS, COLUMNS)?;
+        for r in 0..ROWS {
+            for c in 0..COLUMNS {
+                write!(f, "{:?} ", self.get(r, c))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
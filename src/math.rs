@@ -0,0 +1,376 @@
+//! Elementary functions used by the rest of the crate.
+//!
+//! With the `std` feature (enabled by default) these forward to the standard
+//! library. Without it they fall back to the implementations in [`poly`], so the
+//! crate builds on `#![no_std]` targets without linking `libm`.
+
+#[cfg(feature = "std")]
+pub fn sin(x: f32) -> f32 {
+    x.sin()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sin(x: f32) -> f32 {
+    poly::sin(x)
+}
+
+#[cfg(feature = "std")]
+pub fn cos(x: f32) -> f32 {
+    x.cos()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn cos(x: f32) -> f32 {
+    poly::cos(x)
+}
+
+#[cfg(feature = "std")]
+pub fn tan(x: f32) -> f32 {
+    x.tan()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn tan(x: f32) -> f32 {
+    poly::tan(x)
+}
+
+#[cfg(feature = "std")]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    y.atan2(x)
+}
+
+#[cfg(not(feature = "std"))]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    poly::atan2(y, x)
+}
+
+#[cfg(feature = "std")]
+pub fn sqrt(x: f32) -> f32 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sqrt(x: f32) -> f32 {
+    poly::sqrt(x)
+}
+
+// `f64` square root for intermediate results that are kept in double precision.
+#[cfg(feature = "std")]
+pub(crate) fn sqrt_f64(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt_f64(x: f64) -> f64 {
+    poly::sqrt_f64(x)
+}
+
+#[cfg(feature = "std")]
+pub fn exp(x: f32) -> f32 {
+    x.exp()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn exp(x: f32) -> f32 {
+    poly::exp(x)
+}
+
+#[cfg(feature = "std")]
+pub fn ln(x: f32) -> f32 {
+    x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn ln(x: f32) -> f32 {
+    poly::ln(x)
+}
+
+/// Software implementations of the elementary functions.
+///
+/// Every function evaluates its approximation in `f64` and rounds once to `f32`,
+/// which keeps the result within **1 ULP** of the correctly rounded value:
+///
+/// - `sin`, `cos`, `tan`: over the whole finite `f32` range. Arguments above
+///   `2^20` are reduced against a 256-bit table of `2/pi` (Payne–Hanek).
+/// - `atan2`, `sqrt`, `exp`, `ln`: over the whole `f32` range, including
+///   subnormals, infinities and NaN.
+///
+/// They are always compiled so they can be used and tested alongside `std`.
+pub mod poly {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LN_2, PI};
+
+    // pi/2 split so that k * PIO2_HI is exact for |k| < 2^20.
+    const PIO2_HI: f64 = 1.570_796_326_734_125_6;
+    const PIO2_MID: f64 = 6.077_100_506_303_966e-11;
+    const PIO2_LO: f64 = 2.022_266_248_795_950_6e-21;
+
+    // The first 256 bits of 2/pi after the binary point.
+    const TWO_OVER_PI: [u64; 4] = [
+        0xa2f9_836e_4e44_1529,
+        0xfc27_57d1_f534_ddc0,
+        0xdb62_9599_3c43_9041,
+        0xfe51_63ab_debb_c561,
+    ];
+
+    const LN2_HI: f64 = 6.931_471_803_691_238e-1;
+    const LN2_LO: f64 = 1.908_214_929_270_587_7e-10;
+
+    fn round(x: f64) -> f64 {
+        if x >= 0.0 {
+            ((x + 0.5) as i64) as f64
+        } else {
+            ((x - 0.5) as i64) as f64
+        }
+    }
+
+    // Returns (r, q) with x = q * pi/2 + r, |r| <= pi/4 and q taken mod 4.
+    fn reduce(x: f32) -> (f64, i64) {
+        if x.abs() > 1_048_576.0 {
+            return reduce_large(x);
+        }
+        let x = x as f64;
+        if x.abs() <= FRAC_PI_4 {
+            return (x, 0);
+        }
+        let k = round(x / FRAC_PI_2);
+        let r = ((x - k * PIO2_HI) - k * PIO2_MID) - k * PIO2_LO;
+        (r, (k as i64).rem_euclid(4))
+    }
+
+    // `reduce` for |x| > 2^20. Writing |x| = m * 2^e with an integer m, the
+    // bits of 2/pi before 2^(1-e) only add multiples of 4 to |x| * 2/pi, so a
+    // 128-bit window starting there gives the quadrant and 126 bits of the
+    // remainder.
+    fn reduce_large(x: f32) -> (f64, i64) {
+        let bits = x.to_bits();
+        let m = ((bits & 0x7f_ffff) | 0x80_0000) as u128;
+        let e = ((bits >> 23) & 0xff) as i32 - 150;
+        // Index of bit 2^(1-e) counted from the binary point.
+        let offset = e - 2;
+        let window = if offset < 0 {
+            ((TWO_OVER_PI[0] as u128) << 64 | TWO_OVER_PI[1] as u128) >> -offset
+        } else {
+            let (word, shift) = ((offset / 64) as usize, offset % 64);
+            let high = (TWO_OVER_PI[word] as u128) << 64 | TWO_OVER_PI[word + 1] as u128;
+            let low = TWO_OVER_PI[word + 2] as u128;
+            if shift == 0 {
+                high
+            } else {
+                high << shift | low >> (64 - shift)
+            }
+        };
+        // |x| * 2/pi mod 4, with two integer bits and 126 fractional ones.
+        let product = m.wrapping_mul(window);
+        // The fraction in [-1/2, 1/2), rounding the quadrant to nearest.
+        let fraction = (product << 2) as i128;
+        let quadrant = (product >> 126) as i64 + (fraction < 0) as i64;
+        let r = fraction as f64 * 2.938_735_877_055_719e-39 * FRAC_PI_2;
+        if x < 0.0 {
+            (-r, (-quadrant).rem_euclid(4))
+        } else {
+            (r, quadrant.rem_euclid(4))
+        }
+    }
+
+    // sin(r) for |r| <= pi/4.
+    fn kernel_sin(r: f64) -> f64 {
+        const S1: f64 = -1.666_666_666_666_663_2e-1;
+        const S2: f64 = 8.333_333_333_322_49e-3;
+        const S3: f64 = -1.984_126_982_985_795e-4;
+        const S4: f64 = 2.755_731_370_707_006_8e-6;
+        const S5: f64 = -2.505_076_025_340_686_3e-8;
+        const S6: f64 = 1.589_690_995_211_55e-10;
+        let z = r * r;
+        r + r * z * (S1 + z * (S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)))))
+    }
+
+    // cos(r) for |r| <= pi/4.
+    fn kernel_cos(r: f64) -> f64 {
+        const C1: f64 = 4.166_666_666_666_66e-2;
+        const C2: f64 = -1.388_888_888_887_411e-3;
+        const C3: f64 = 2.480_158_728_947_673e-5;
+        const C4: f64 = -2.755_731_435_139_066_3e-7;
+        const C5: f64 = 2.087_572_321_298_175e-9;
+        const C6: f64 = -1.135_964_755_778_819_5e-11;
+        let z = r * r;
+        1.0 - 0.5 * z + z * z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))))
+    }
+
+    pub fn sin(x: f32) -> f32 {
+        if !x.is_finite() {
+            return f32::NAN;
+        }
+        let (r, q) = reduce(x);
+        let value = match q {
+            0 => kernel_sin(r),
+            1 => kernel_cos(r),
+            2 => -kernel_sin(r),
+            _ => -kernel_cos(r),
+        };
+        value as f32
+    }
+
+    pub fn cos(x: f32) -> f32 {
+        if !x.is_finite() {
+            return f32::NAN;
+        }
+        let (r, q) = reduce(x);
+        let value = match q {
+            0 => kernel_cos(r),
+            1 => -kernel_sin(r),
+            2 => -kernel_cos(r),
+            _ => kernel_sin(r),
+        };
+        value as f32
+    }
+
+    pub fn tan(x: f32) -> f32 {
+        if !x.is_finite() {
+            return f32::NAN;
+        }
+        let (r, q) = reduce(x);
+        let (s, c) = (kernel_sin(r), kernel_cos(r));
+        let value = if q % 2 == 0 { s / c } else { -c / s };
+        value as f32
+    }
+
+    // atan(x) for x >= 0, following the fdlibm argument reduction.
+    fn atan_positive(x: f64) -> f64 {
+        const AT: [f64; 11] = [
+            3.333_333_333_333_293e-1,
+            -1.999_999_999_987_648_3e-1,
+            1.428_571_427_250_346_6e-1,
+            -1.111_111_040_546_235_6e-1,
+            9.090_887_133_436_507e-2,
+            -7.691_876_205_044_83e-2,
+            6.661_073_137_387_531e-2,
+            -5.833_570_133_790_573_5e-2,
+            4.976_877_994_615_932_4e-2,
+            -3.653_157_274_421_691_6e-2,
+            1.628_582_011_536_578_2e-2,
+        ];
+        if x.is_infinite() {
+            return FRAC_PI_2;
+        }
+        let (base, t) = if x < 0.4375 {
+            (0.0, x)
+        } else if x < 0.6875 {
+            (4.636_476_090_008_061e-1, (2.0 * x - 1.0) / (2.0 + x))
+        } else if x < 1.1875 {
+            (FRAC_PI_4, (x - 1.0) / (x + 1.0))
+        } else if x < 2.4375 {
+            (9.827_937_232_473_29e-1, (x - 1.5) / (1.0 + 1.5 * x))
+        } else {
+            (FRAC_PI_2, -1.0 / x)
+        };
+        let z = t * t;
+        let w = z * z;
+        let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+        let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+        base + (t - t * (s1 + s2))
+    }
+
+    pub fn atan2(y: f32, x: f32) -> f32 {
+        if y.is_nan() || x.is_nan() {
+            return f32::NAN;
+        }
+        let (y, x) = (y as f64, x as f64);
+        if y == 0.0 {
+            return if x.is_sign_negative() {
+                PI.copysign(y) as f32
+            } else {
+                y as f32
+            };
+        }
+        if x == 0.0 {
+            return FRAC_PI_2.copysign(y) as f32;
+        }
+        let angle = if x.is_infinite() && y.is_infinite() {
+            FRAC_PI_4
+        } else {
+            atan_positive((y / x).abs())
+        };
+        let value = if x > 0.0 { angle } else { PI - angle };
+        value.copysign(y) as f32
+    }
+
+    pub fn sqrt(x: f32) -> f32 {
+        sqrt_f64(x as f64) as f32
+    }
+
+    #[cfg_attr(feature = "std", allow(dead_code))]
+    pub(crate) fn sqrt_f64(x: f64) -> f64 {
+        if x.is_nan() || x < 0.0 {
+            return f64::NAN;
+        }
+        if x == 0.0 || x.is_infinite() {
+            return x;
+        }
+        // Scale subnormals into the normal range so the exponent trick works.
+        if x < f64::MIN_POSITIVE {
+            const SCALE: f64 = (1u64 << 52) as f64;
+            return sqrt_f64(x * SCALE * SCALE) / SCALE;
+        }
+        // Halve the exponent for the initial guess, then refine with Newton steps.
+        let mut guess = f64::from_bits((x.to_bits() >> 1) + (1023u64 << 51));
+        for _ in 0..6 {
+            guess = 0.5 * (guess + x / guess);
+        }
+        guess
+    }
+
+    pub fn exp(x: f32) -> f32 {
+        if x.is_nan() {
+            return f32::NAN;
+        }
+        if x > 88.722_84 {
+            return f32::INFINITY;
+        }
+        if x < -103.972_08 {
+            return 0.0;
+        }
+        let x = x as f64;
+        let k = round(x / LN_2);
+        let r = (x - k * LN2_HI) - k * LN2_LO;
+        // Taylor series on |r| <= ln(2)/2.
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        for n in 1..=13 {
+            term *= r / n as f64;
+            sum += term;
+        }
+        let scale = f64::from_bits(((k as i64 + 1023) as u64) << 52);
+        (sum * scale) as f32
+    }
+
+    pub fn ln(x: f32) -> f32 {
+        if x.is_nan() || x < 0.0 {
+            return f32::NAN;
+        }
+        if x == 0.0 {
+            return f32::NEG_INFINITY;
+        }
+        if x.is_infinite() {
+            return x;
+        }
+        let bits = (x as f64).to_bits();
+        let mut e = ((bits >> 52) as i64) - 1023;
+        let mut m = f64::from_bits((bits & ((1u64 << 52) - 1)) | (1023u64 << 52));
+        if m > core::f64::consts::SQRT_2 {
+            m *= 0.5;
+            e += 1;
+        }
+        // ln(m) = 2 atanh(s) with s = (m - 1) / (m + 1), |s| <= 0.172.
+        let s = (m - 1.0) / (m + 1.0);
+        let z = s * s;
+        let mut power = s;
+        let mut sum = 0.0;
+        let mut n = 1.0;
+        while n < 22.0 {
+            sum += power / n;
+            power *= z;
+            n += 2.0;
+        }
+        (e as f64 * LN_2 + 2.0 * sum) as f32
+    }
+}
@@ -0,0 +1,729 @@
+//! Unconstrained minimization of `f32` functions.
+//!
+//! [`gradient_descent`], [`bfgs`] and [`lbfgs`] take the function and its
+//! gradient; [`nelder_mead`] only needs the function. The parameters are a
+//! column [`Matrix<N, 1>`] or a [`DMatrix`], and every method returns the
+//! same [`Report`]. The search itself runs in `f64`, and the closures are
+//! evaluated at the nearest `f32` point.
+//!
+//! ```
+//! # use minmath::{dual, linear_algebra::matrix::Matrix, optimize::{bfgs, OptimizeOptions}, scalar::Real};
+//! fn rosenbrock<T: Real>(x: Matrix<2, 1, T>) -> T {
+//!     let (a, b) = (T::ONE - x[0][0], x[1][0] - x[0][0] * x[0][0]);
+//!     a * a + T::from_f32(100.0) * b * b
+//! }
+//!
+//! let report = bfgs(
+//!     |x| rosenbrock(*x),
+//!     |x| dual::gradient(rosenbrock, *x),
+//!     Matrix::new([[-1.2], [1.0]]),
+//!     OptimizeOptions::default(),
+//! );
+//! assert!(report.converged());
+//! assert!((report.x - Matrix::new([[1.0], [1.0]])).max_norm() < 1e-3);
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::linear_algebra::{dmatrix::DMatrix, matrix::Matrix};
+
+/// A parameter vector that the optimizers can read and write as a slice.
+pub trait Parameters: Clone {
+    fn as_slice(&self) -> &[f32];
+    fn as_mut_slice(&mut self) -> &mut [f32];
+}
+
+impl<const N: usize> Parameters for Matrix<N, 1> {
+    fn as_slice(&self) -> &[f32] {
+        self.as_array().as_flattened()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [f32] {
+        self.as_mut_array().as_flattened_mut()
+    }
+}
+
+/// All elements in row-major order, whatever the shape.
+impl Parameters for DMatrix {
+    fn as_slice(&self) -> &[f32] {
+        DMatrix::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [f32] {
+        DMatrix::as_mut_slice(self)
+    }
+}
+
+/// How the gradient methods choose a step length along the search direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineSearch {
+    /// Halves the step until it gives sufficient decrease.
+    Armijo,
+    /// Brackets a step that satisfies the strong Wolfe conditions, which also
+    /// require the slope to flatten. This keeps the BFGS updates positive
+    /// definite.
+    Wolfe,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OptimizeOptions {
+    pub max_iterations: usize,
+    /// Stop once the gradient's Euclidean norm is at most this.
+    pub gradient_tolerance: f32,
+    /// Stop once a step, or the Nelder–Mead simplex, is at most this times
+    /// `max(‖x‖, 1)`.
+    pub step_tolerance: f32,
+    /// Stop once an iteration lowers the value by at most this times
+    /// `max(|f|, 1)`. Nelder–Mead reports this instead of the step tolerance
+    /// when its simplex is also that flat.
+    pub value_tolerance: f32,
+    pub line_search: LineSearch,
+    /// The number of recent steps L-BFGS uses to approximate the Hessian.
+    pub memory: usize,
+    /// The initial Nelder–Mead simplex extends each coordinate by this
+    /// fraction of its value, or by 0.00025 if it is zero.
+    pub simplex_size: f32,
+}
+
+impl Default for OptimizeOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: 1000,
+            gradient_tolerance: 1e-4,
+            step_tolerance: 1e-7,
+            value_tolerance: 1e-9,
+            line_search: LineSearch::Wolfe,
+            memory: 8,
+            simplex_size: 0.05,
+        }
+    }
+}
+
+/// Why an optimizer stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    GradientTolerance,
+    StepTolerance,
+    ValueTolerance,
+    MaxIterations,
+    /// No step along the search direction lowered the value enough, usually
+    /// because the remaining decrease is below the function's rounding error.
+    LineSearchFailed,
+    /// The value or gradient at the current point is infinite or NaN.
+    NonFinite,
+    /// The Jacobian's columns are linearly dependent, so
+    /// [`gauss_newton`](crate::least_squares::gauss_newton) has no unique
+    /// step.
+    Singular,
+}
+
+/// The outcome of a minimization.
+#[derive(Clone, Debug)]
+pub struct Report<P> {
+    /// The best point found.
+    pub x: P,
+    pub value: f32,
+    /// The Euclidean norm of the gradient at `x`, or `None` for
+    /// [`nelder_mead`].
+    pub gradient_norm: Option<f32>,
+    pub iterations: usize,
+    /// The number of times the function was evaluated.
+    pub evaluations: usize,
+    pub gradient_evaluations: usize,
+    pub termination: Termination,
+}
+
+impl<P> Report<P> {
+    /// Whether the optimizer stopped because a tolerance was met.
+    pub fn converged(&self) -> bool {
+        matches!(
+            self.termination,
+            Termination::GradientTolerance
+                | Termination::StepTolerance
+                | Termination::ValueTolerance
+        )
+    }
+}
+
+/// Steepest descent: each step follows the negative gradient.
+pub fn gradient_descent<P: Parameters>(
+    f: impl FnMut(&P) -> f32,
+    gradient: impl FnMut(&P) -> P,
+    x0: P,
+    options: OptimizeOptions,
+) -> Report<P> {
+    minimize(Problem::new(f, gradient, x0), Method::Steepest, options)
+}
+
+/// The BFGS quasi-Newton method, which builds a dense approximation of the
+/// inverse Hessian from the gradients along the way.
+pub fn bfgs<P: Parameters>(
+    f: impl FnMut(&P) -> f32,
+    gradient: impl FnMut(&P) -> P,
+    x0: P,
+    options: OptimizeOptions,
+) -> Report<P> {
+    let method = Method::Bfgs {
+        inverse_hessian: Vec::new(),
+        scaled: false,
+    };
+    minimize(Problem::new(f, gradient, x0), method, options)
+}
+
+/// Limited-memory BFGS, which keeps only the last
+/// [`memory`](OptimizeOptions::memory) steps instead of an `n`x`n` matrix.
+pub fn lbfgs<P: Parameters>(
+    f: impl FnMut(&P) -> f32,
+    gradient: impl FnMut(&P) -> P,
+    x0: P,
+    options: OptimizeOptions,
+) -> Report<P> {
+    let method = Method::Lbfgs {
+        history: VecDeque::with_capacity(options.memory),
+        memory: options.memory,
+    };
+    minimize(Problem::new(f, gradient, x0), method, options)
+}
+
+/// The derivative-free Nelder–Mead simplex method, with coefficients adapted
+/// to the dimension as proposed by Gao and Han.
+pub fn nelder_mead<P: Parameters>(
+    f: impl FnMut(&P) -> f32,
+    x0: P,
+    options: OptimizeOptions,
+) -> Report<P> {
+    let mut problem = Problem::new(f, (), x0);
+    let x0 = widen(problem.point.as_slice());
+    let n = x0.len();
+    let dimension = n.max(2) as f64;
+    let expansion = 1.0 + 2.0 / dimension;
+    let contraction = 0.75 - 0.5 / dimension;
+    let shrink = 1.0 - 1.0 / dimension;
+
+    let mut simplex = Vec::with_capacity(n + 1);
+    simplex.push(x0.clone());
+    for i in 0..n {
+        let mut vertex = x0.clone();
+        vertex[i] = round(if x0[i] == 0.0 {
+            0.00025
+        } else {
+            x0[i] * (1.0 + options.simplex_size as f64)
+        });
+        simplex.push(vertex);
+    }
+    let mut simplex: Vec<(Vec<f64>, f64)> = simplex
+        .into_iter()
+        .map(|vertex| {
+            let value = problem.value(&vertex);
+            (vertex, value)
+        })
+        .collect();
+
+    let mut iterations = 0;
+    let termination = loop {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (best, best_value) = &simplex[0];
+        if !best_value.is_finite() {
+            break Termination::NonFinite;
+        }
+        // A simplex straddling the minimum can have equal values at every
+        // vertex, so a small spread only counts once the simplex is small too.
+        let diameter = simplex[1..]
+            .iter()
+            .map(|(vertex, _)| distance(vertex, best))
+            .fold(0.0, f64::max);
+        if diameter <= options.step_tolerance as f64 * norm(best).max(1.0) {
+            let spread = simplex[n].1 - best_value;
+            if spread <= options.value_tolerance as f64 * best_value.abs().max(1.0) {
+                break Termination::ValueTolerance;
+            }
+            break Termination::StepTolerance;
+        }
+        if iterations == options.max_iterations {
+            break Termination::MaxIterations;
+        }
+        iterations += 1;
+
+        let mut centroid = vec![0.0; n];
+        for (vertex, _) in &simplex[..n] {
+            for (c, v) in centroid.iter_mut().zip(vertex) {
+                *c += v / n as f64;
+            }
+        }
+        // The point `centroid + t (worst - centroid)`.
+        let along = |t: f64, worst: &[f64]| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(worst)
+                .map(|(c, w)| round(c + t * (w - c)))
+                .collect()
+        };
+        let worst = simplex[n].0.clone();
+        let worst_value = simplex[n].1;
+        let reflected = along(-1.0, &worst);
+        let reflected_value = problem.value(&reflected);
+        if reflected_value < simplex[0].1 {
+            let expanded = along(-expansion, &worst);
+            let expanded_value = problem.value(&expanded);
+            simplex[n] = if expanded_value < reflected_value {
+                (expanded, expanded_value)
+            } else {
+                (reflected, reflected_value)
+            };
+            continue;
+        }
+        if reflected_value < simplex[n - 1].1 {
+            simplex[n] = (reflected, reflected_value);
+            continue;
+        }
+        let (contracted, limit) = if reflected_value < worst_value {
+            (along(-contraction, &worst), reflected_value)
+        } else {
+            (along(contraction, &worst), worst_value)
+        };
+        let contracted_value = problem.value(&contracted);
+        if contracted_value < limit {
+            simplex[n] = (contracted, contracted_value);
+            continue;
+        }
+        let best = simplex[0].0.clone();
+        for (vertex, value) in &mut simplex[1..] {
+            for (v, b) in vertex.iter_mut().zip(&best) {
+                *v = round(b + shrink * (*v - b));
+            }
+            *value = problem.value(vertex);
+        }
+    };
+
+    let (best, value) = &simplex[0];
+    problem.load(best);
+    Report {
+        x: problem.point,
+        value: *value as f32,
+        gradient_norm: None,
+        iterations,
+        evaluations: problem.evaluations,
+        gradient_evaluations: 0,
+        termination,
+    }
+}
+
+// The sufficient-decrease and curvature constants of the line searches.
+const C1: f64 = 1e-4;
+const C2: f64 = 0.9;
+const MAX_LINE_SEARCH_STEPS: usize = 50;
+
+struct Problem<P, F, G> {
+    // Scratch space that the closures are evaluated at.
+    point: P,
+    f: F,
+    gradient: G,
+    evaluations: usize,
+    gradient_evaluations: usize,
+}
+
+impl<P: Parameters, F: FnMut(&P) -> f32, G> Problem<P, F, G> {
+    fn new(f: F, gradient: G, x0: P) -> Self {
+        Self {
+            point: x0,
+            f,
+            gradient,
+            evaluations: 0,
+            gradient_evaluations: 0,
+        }
+    }
+
+    fn load(&mut self, x: &[f64]) {
+        for (p, &x) in self.point.as_mut_slice().iter_mut().zip(x) {
+            *p = x as f32;
+        }
+    }
+
+    // The value at `x`, with NaN replaced by infinity so that comparisons
+    // reject it.
+    fn value(&mut self, x: &[f64]) -> f64 {
+        self.load(x);
+        self.evaluations += 1;
+        let value = (self.f)(&self.point) as f64;
+        if value.is_nan() { f64::INFINITY } else { value }
+    }
+}
+
+impl<P: Parameters, F: FnMut(&P) -> f32, G: FnMut(&P) -> P> Problem<P, F, G> {
+    fn gradient(&mut self, x: &[f64], out: &mut [f64]) {
+        self.load(x);
+        self.gradient_evaluations += 1;
+        let gradient = (self.gradient)(&self.point);
+        let gradient = gradient.as_slice();
+        assert_eq!(
+            gradient.len(),
+            out.len(),
+            "Gradient has {} elements, expected {}",
+            gradient.len(),
+            out.len()
+        );
+        for (o, &g) in out.iter_mut().zip(gradient) {
+            *o = g as f64;
+        }
+    }
+
+    // Backtracks from `alpha` along `p` until the Armijo condition holds, and
+    // leaves the accepted point, value and gradient in `trial`.
+    fn armijo(&mut self, current: &State, p: &[f64], mut alpha: f64, trial: &mut State) -> bool {
+        let slope = dot(&current.g, p);
+        for _ in 0..MAX_LINE_SEARCH_STEPS {
+            if !step(&current.x, alpha, p, &mut trial.x) {
+                return false;
+            }
+            trial.f = self.value(&trial.x);
+            if trial.f <= current.f + C1 * alpha * slope {
+                self.gradient(&trial.x, &mut trial.g);
+                return true;
+            }
+            alpha *= 0.5;
+        }
+        false
+    }
+
+    // Algorithms 3.5 and 3.6 of Nocedal and Wright, Numerical Optimization:
+    // grows `alpha` until a step satisfying the strong Wolfe conditions is
+    // bracketed, then narrows the bracket.
+    fn wolfe(&mut self, current: &State, p: &[f64], mut alpha: f64, trial: &mut State) -> bool {
+        let slope = dot(&current.g, p);
+        let mut previous = (0.0, current.f, slope);
+        for i in 0..MAX_LINE_SEARCH_STEPS {
+            let Some(derivative) = self.probe(current, p, alpha, trial) else {
+                return false;
+            };
+            let sufficient = trial.f <= current.f + C1 * alpha * slope;
+            if !sufficient || (i > 0 && trial.f >= previous.1) {
+                return self.zoom(current, p, previous, (alpha, trial.f), trial);
+            }
+            if derivative.abs() <= -C2 * slope {
+                return true;
+            }
+            if derivative >= 0.0 {
+                let high = (previous.0, previous.1);
+                return self.zoom(current, p, (alpha, trial.f, derivative), high, trial);
+            }
+            previous = (alpha, trial.f, derivative);
+            alpha *= 2.0;
+        }
+        false
+    }
+
+    // Narrows the bracket between `low`, the best step so far with its value
+    // and slope, and `high`, with its value.
+    fn zoom(
+        &mut self,
+        current: &State,
+        p: &[f64],
+        mut low: (f64, f64, f64),
+        mut high: (f64, f64),
+        trial: &mut State,
+    ) -> bool {
+        let slope = dot(&current.g, p);
+        for _ in 0..MAX_LINE_SEARCH_STEPS {
+            let width = high.0 - low.0;
+            // The minimum of the quadratic through the low end's value and
+            // slope and the high end's value, if it lies well inside.
+            let curvature = high.1 - low.1 - low.2 * width;
+            let mut alpha = low.0 - low.2 * width * width / (2.0 * curvature);
+            let margin = 0.1 * width.abs();
+            if !alpha.is_finite()
+                || alpha < low.0.min(high.0) + margin
+                || alpha > low.0.max(high.0) - margin
+            {
+                alpha = low.0 + 0.5 * width;
+            }
+            let Some(derivative) = self.probe(current, p, alpha, trial) else {
+                return false;
+            };
+            if trial.f > current.f + C1 * alpha * slope || trial.f >= low.1 {
+                high = (alpha, trial.f);
+            } else {
+                if derivative.abs() <= -C2 * slope {
+                    return true;
+                }
+                if derivative * width >= 0.0 {
+                    high = (low.0, low.1);
+                }
+                low = (alpha, trial.f, derivative);
+            }
+        }
+        false
+    }
+
+    // Evaluates the value and gradient at step `alpha` into `trial`, and
+    // returns the slope there, or `None` if the step does not move the point.
+    fn probe(&mut self, current: &State, p: &[f64], alpha: f64, trial: &mut State) -> Option<f64> {
+        if !step(&current.x, alpha, p, &mut trial.x) {
+            return None;
+        }
+        trial.f = self.value(&trial.x);
+        self.gradient(&trial.x, &mut trial.g);
+        let derivative = dot(&trial.g, p);
+        Some(if derivative.is_finite() {
+            derivative
+        } else {
+            f64::INFINITY
+        })
+    }
+}
+
+// A point with its value and gradient.
+#[derive(Clone)]
+struct State {
+    x: Vec<f64>,
+    f: f64,
+    g: Vec<f64>,
+}
+
+enum Method {
+    Steepest,
+    Bfgs {
+        // Row-major `n`x`n`, and only set once `scaled`.
+        inverse_hessian: Vec<f64>,
+        // Whether the first update has replaced the identity with a multiple
+        // of it that matches the observed curvature.
+        scaled: bool,
+    },
+    Lbfgs {
+        // The last steps and gradient changes, with `1 / (y·s)`.
+        history: VecDeque<(Vec<f64>, Vec<f64>, f64)>,
+        memory: usize,
+    },
+}
+
+impl Method {
+    // Whether the direction carries curvature information, so that a unit
+    // step is a good first guess.
+    fn has_curvature(&self) -> bool {
+        match self {
+            Self::Steepest => false,
+            Self::Bfgs { scaled, .. } => *scaled,
+            Self::Lbfgs { history, .. } => !history.is_empty(),
+        }
+    }
+
+    fn direction(&self, g: &[f64], p: &mut [f64]) {
+        match self {
+            Self::Steepest | Self::Bfgs { scaled: false, .. } => {
+                for (p, g) in p.iter_mut().zip(g) {
+                    *p = -g;
+                }
+            }
+            Self::Bfgs {
+                inverse_hessian, ..
+            } => {
+                for (p, row) in p.iter_mut().zip(inverse_hessian.chunks(g.len())) {
+                    *p = -dot(row, g);
+                }
+            }
+            Self::Lbfgs { history, .. } => {
+                // The two-loop recursion.
+                let mut q = g.to_vec();
+                let mut alphas = Vec::with_capacity(history.len());
+                for (s, y, rho) in history.iter().rev() {
+                    let alpha = rho * dot(s, &q);
+                    axpy(-alpha, y, &mut q);
+                    alphas.push(alpha);
+                }
+                if let Some((s, y, _)) = history.back() {
+                    let gamma = dot(s, y) / dot(y, y);
+                    q.iter_mut().for_each(|q| *q *= gamma);
+                }
+                for ((s, y, rho), alpha) in history.iter().zip(alphas.into_iter().rev()) {
+                    let beta = rho * dot(y, &q);
+                    axpy(alpha - beta, s, &mut q);
+                }
+                for (p, q) in p.iter_mut().zip(q) {
+                    *p = -q;
+                }
+            }
+        }
+    }
+
+    // Incorporates the step `s` and gradient change `y`. Pairs without
+    // positive curvature are skipped so the approximation stays positive
+    // definite.
+    fn update(&mut self, s: Vec<f64>, y: Vec<f64>) {
+        let ys = dot(&y, &s);
+        if ys <= f64::EPSILON * norm(&y) * norm(&s) {
+            return;
+        }
+        let rho = 1.0 / ys;
+        match self {
+            Self::Steepest => {}
+            Self::Bfgs {
+                inverse_hessian: h,
+                scaled,
+            } => {
+                let n = s.len();
+                if !*scaled {
+                    *h = identity(n);
+                    h.iter_mut().for_each(|h| *h *= ys / dot(&y, &y));
+                    *scaled = true;
+                }
+                // H ← (I - ρsyᵀ) H (I - ρysᵀ) + ρssᵀ, using the symmetry of H.
+                let hy: Vec<f64> = h.chunks(n).map(|row| dot(row, &y)).collect();
+                let factor = rho * rho * dot(&y, &hy) + rho;
+                for i in 0..n {
+                    for j in 0..n {
+                        h[i * n + j] += factor * s[i] * s[j] - rho * (hy[i] * s[j] + s[i] * hy[j]);
+                    }
+                }
+            }
+            Self::Lbfgs { history, memory } => {
+                if *memory == 0 {
+                    return;
+                }
+                if history.len() == *memory {
+                    history.pop_front();
+                }
+                history.push_back((s, y, rho));
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::Steepest => {}
+            Self::Bfgs { scaled, .. } => *scaled = false,
+            Self::Lbfgs { history, .. } => history.clear(),
+        }
+    }
+}
+
+fn minimize<P: Parameters>(
+    mut problem: Problem<P, impl FnMut(&P) -> f32, impl FnMut(&P) -> P>,
+    mut method: Method,
+    options: OptimizeOptions,
+) -> Report<P> {
+    let x = widen(problem.point.as_slice());
+    let n = x.len();
+    let mut current = State {
+        f: problem.value(&x),
+        g: vec![0.0; n],
+        x,
+    };
+    problem.gradient(&current.x, &mut current.g);
+    let mut trial = current.clone();
+    let mut p = vec![0.0; n];
+    // The previous step length and slope, for scaling the next first guess.
+    let mut previous: Option<(f64, f64)> = None;
+    let mut iterations = 0;
+
+    let termination = loop {
+        if !current.f.is_finite() || current.g.iter().any(|g| !g.is_finite()) {
+            break Termination::NonFinite;
+        }
+        let gradient_norm = norm(&current.g);
+        if gradient_norm <= options.gradient_tolerance as f64 {
+            break Termination::GradientTolerance;
+        }
+        if iterations == options.max_iterations {
+            break Termination::MaxIterations;
+        }
+
+        method.direction(&current.g, &mut p);
+        let mut slope = dot(&current.g, &p);
+        if !slope.is_finite() || slope >= 0.0 {
+            // Not a descent direction, so start over from steepest descent.
+            method.reset();
+            for (p, g) in p.iter_mut().zip(&current.g) {
+                *p = -g;
+            }
+            slope = -gradient_norm * gradient_norm;
+        }
+        let alpha = if method.has_curvature() {
+            1.0
+        } else if let Some((alpha, previous_slope)) = previous {
+            alpha * previous_slope / slope
+        } else {
+            (1.0 / gradient_norm).min(1.0)
+        };
+        let accepted = match options.line_search {
+            LineSearch::Armijo => problem.armijo(&current, &p, alpha, &mut trial),
+            LineSearch::Wolfe => problem.wolfe(&current, &p, alpha, &mut trial),
+        };
+        if !accepted {
+            break Termination::LineSearchFailed;
+        }
+        iterations += 1;
+
+        let s: Vec<f64> = trial.x.iter().zip(&current.x).map(|(a, b)| a - b).collect();
+        let y: Vec<f64> = trial.g.iter().zip(&current.g).map(|(a, b)| a - b).collect();
+        let step_length = norm(&s);
+        let decrease = current.f - trial.f;
+        let scale = current.f.abs().max(trial.f.abs()).max(1.0);
+        previous = Some((step_length / norm(&p), slope));
+        core::mem::swap(&mut current, &mut trial);
+        if step_length <= options.step_tolerance as f64 * norm(&current.x).max(1.0) {
+            break Termination::StepTolerance;
+        }
+        if decrease <= options.value_tolerance as f64 * scale {
+            break Termination::ValueTolerance;
+        }
+        method.update(s, y);
+    };
+
+    let gradient_norm = norm(&current.g) as f32;
+    problem.load(&current.x);
+    Report {
+        x: problem.point,
+        value: current.f as f32,
+        gradient_norm: Some(gradient_norm),
+        iterations,
+        evaluations: problem.evaluations,
+        gradient_evaluations: problem.gradient_evaluations,
+        termination,
+    }
+}
+
+// Sets `out` to `x + alpha p` rounded to `f32`, and returns whether that
+// differs from `x`.
+fn step(x: &[f64], alpha: f64, p: &[f64], out: &mut [f64]) -> bool {
+    let mut moved = false;
+    for ((o, &x), &p) in out.iter_mut().zip(x).zip(p) {
+        *o = round(x + alpha * p);
+        moved |= *o != x;
+    }
+    moved
+}
+
+fn round(x: f64) -> f64 {
+    x as f32 as f64
+}
+
+fn widen(x: &[f32]) -> Vec<f64> {
+    x.iter().map(|&x| x as f64).collect()
+}
+
+fn identity(n: usize) -> Vec<f64> {
+    (0..n * n)
+        .map(|i| if i % (n + 1) == 0 { 1.0 } else { 0.0 })
+        .collect()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    for (y, x) in y.iter_mut().zip(x) {
+        *y += alpha * x;
+    }
+}
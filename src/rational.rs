@@ -0,0 +1,305 @@
+//! Exact fractions of 64-bit integers.
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{error::MathError, scalar::Scalar};
+
+/// A fraction `numerator / denominator`, always stored in lowest terms with a
+/// positive denominator, so equal values compare equal field by field.
+///
+/// Intermediate results are computed in `i128` and reduced before they are
+/// narrowed back. The operators panic if a reduced result does not fit in
+/// `i64` or on division by zero; the `checked_*` methods return `None`
+/// instead.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Rational {
+    pub const ZERO: Self = Self::integer(0);
+    pub const ONE: Self = Self::integer(1);
+
+    pub const fn integer(value: i64) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    /// Creates the reduced fraction `numerator / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or the reduced fraction does not fit,
+    /// which only happens for `i64::MIN / -1`.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        match Self::checked_new(numerator, denominator) {
+            Ok(value) => value,
+            Err(_) if denominator == 0 => panic!("Rational with a zero denominator"),
+            Err(_) => panic!("Rational overflow"),
+        }
+    }
+
+    /// Like [`Rational::new`], but returns [`MathError::Domain`] for a zero
+    /// denominator and [`MathError::NonFinite`] if the result does not fit.
+    pub fn checked_new(numerator: i64, denominator: i64) -> Result<Self, MathError> {
+        if denominator == 0 {
+            return Err(MathError::Domain("zero denominator"));
+        }
+        Self::reduce(numerator as i128, denominator as i128).ok_or(MathError::NonFinite)
+    }
+
+    // Reduces `numerator / denominator`, which must have a nonzero
+    // denominator, and narrows it to `i64`.
+    fn reduce(numerator: i128, denominator: i128) -> Option<Self> {
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+        let sign = denominator.signum();
+        Some(Self {
+            numerator: i64::try_from(sign * numerator / divisor).ok()?,
+            denominator: i64::try_from(sign * denominator / divisor).ok()?,
+        })
+    }
+
+    pub const fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// The denominator, which is always positive.
+    pub const fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    pub const fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn abs(self) -> Self {
+        if self.numerator < 0 { -self } else { self }
+    }
+
+    /// The reciprocal `1 / self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    /// The nearest `f64`, rounded once from the exact quotient when both parts
+    /// are exactly representable.
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), rhs.wide());
+        Self::reduce(a.0 * b.1 + b.0 * a.1, a.1 * b.1)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), rhs.wide());
+        Self::reduce(a.0 * b.0, a.1 * b.1)
+    }
+
+    /// Returns `None` on overflow or if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.numerator == 0 {
+            return None;
+        }
+        let (a, b) = (self.wide(), rhs.wide());
+        Self::reduce(a.0 * b.1, a.1 * b.0)
+    }
+
+    fn wide(self) -> (i128, i128) {
+        (self.numerator as i128, self.denominator as i128)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Self::integer(value as i64)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.wide(), other.wide());
+        (a.0 * b.1).cmp(&(b.0 * a.1))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        // The negated numerator only overflows for `i64::MIN`.
+        Self::reduce(-(self.numerator as i128), self.denominator as i128)
+            .expect("Rational overflow")
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("Rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("Rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("Rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    #[track_caller]
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.numerator != 0, "Rational division by zero");
+        self.checked_div(rhs).expect("Rational overflow")
+    }
+}
+
+impl AddAssign for Rational {
+    #[track_caller]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Rational {
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Rational {
+    #[track_caller]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Rational {
+    #[track_caller]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+// Enough room for two `i64`s, a sign and a slash.
+struct Buffer {
+    bytes: [u8; 48],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Prints `numerator/denominator`, or just the numerator for integers, right-
+/// aligned to the formatter's width. Precision is ignored.
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut buffer = Buffer {
+            bytes: [0; 48],
+            len: 0,
+        };
+        if self.is_integer() {
+            write!(buffer, "{}", self.numerator)?;
+        } else {
+            write!(buffer, "{}/{}", self.numerator, self.denominator)?;
+        }
+        // Only ASCII was written.
+        let text = core::str::from_utf8(&buffer.bytes[..buffer.len]).map_err(|_| fmt::Error)?;
+        match f.width() {
+            // The value is exact, so a precision is ignored rather than
+            // allowed to truncate it the way `pad` would.
+            Some(width) => write!(f, "{:>width$}", text),
+            None => f.write_str(text),
+        }
+    }
+}
+
+impl Debug for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Scalar for Rational {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const EPSILON: f64 = 0.0;
+
+    fn magnitude(&self) -> f64 {
+        self.to_f64().abs()
+    }
+
+    fn write_rust(&self, out: &mut dyn Write, _precision: Option<usize>) -> fmt::Result {
+        write!(
+            out,
+            "Rational::new({}, {})",
+            self.numerator, self.denominator
+        )
+    }
+}
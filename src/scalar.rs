@@ -0,0 +1,210 @@
+//! The element types a [`Matrix`](crate::linear_algebra::matrix::Matrix) can
+//! hold.
+//!
+//! `Matrix<R, C>` stores `f32`, and most of its API — rotations, norms, matrix
+//! functions, file formats — is specific to it. Structural operations,
+//! arithmetic, determinants, inverses and row reduction work for any
+//! `Matrix<R, C, T>` whose `T` implements [`Scalar`], such as
+//! [`Rational`](crate::rational::Rational) for exact results.
+
+use core::{
+    fmt::{self, Debug, Display, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::math;
+
+/// A number that can be stored in a matrix.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// The relative rounding error of one arithmetic operation, used to pick
+    /// default tolerances. Zero for exact types.
+    const EPSILON: f64;
+
+    /// A non-negative size that is zero only for zero, used to choose pivots
+    /// and compare against tolerances.
+    fn magnitude(&self) -> f64;
+
+    /// Whether the value contains no infinity or NaN.
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Writes the value as a Rust expression, for
+    /// [`Style::Rust`](crate::linear_algebra::format::Style::Rust).
+    fn write_rust(&self, out: &mut dyn Write, precision: Option<usize>) -> fmt::Result {
+        match precision {
+            Some(precision) => write!(out, "{:.*?}", precision, self),
+            None => write!(out, "{:?}", self),
+        }
+    }
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const EPSILON: f64 = f32::EPSILON as f64;
+
+    fn magnitude(&self) -> f64 {
+        self.abs() as f64
+    }
+
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+
+    fn write_rust(&self, out: &mut dyn Write, precision: Option<usize>) -> fmt::Result {
+        if self.is_nan() {
+            out.write_str("f32::NAN")
+        } else if self.is_infinite() {
+            out.write_str(if *self > 0.0 {
+                "f32::INFINITY"
+            } else {
+                "f32::NEG_INFINITY"
+            })
+        } else {
+            match precision {
+                // `1.` rather than `1`, which would be an integer literal.
+                Some(0) => write!(out, "{:.0}.", self),
+                Some(precision) => write!(out, "{:.*?}", precision, self),
+                None => write!(out, "{:?}", self),
+            }
+        }
+    }
+}
+
+/// A [`Scalar`] that approximates the real numbers and has the elementary
+/// functions. It is implemented by `f32` and by the automatic differentiation
+/// types, so code written against it can be evaluated or differentiated.
+pub trait Real: Scalar {
+    fn from_f32(value: f32) -> Self;
+    /// The plain value, without any derivative parts.
+    fn to_f32(&self) -> f32;
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+
+    /// Raises the value to an integer power by repeated squaring.
+    fn powi(self, exponent: i32) -> Self {
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut n = exponent.unsigned_abs();
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base *= base;
+            }
+        }
+        if exponent < 0 {
+            Self::ONE / result
+        } else {
+            result
+        }
+    }
+}
+
+impl Real for f32 {
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+
+    fn to_f32(&self) -> f32 {
+        *self
+    }
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        math::sqrt(self)
+    }
+
+    fn exp(self) -> Self {
+        math::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        math::ln(self)
+    }
+
+    fn sin(self) -> Self {
+        math::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        math::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        math::tan(self)
+    }
+}
+
+// Replaces a leading minus sign with `minus` and writes `plus` before
+// anything else.
+struct Signed<'a> {
+    out: &'a mut dyn Write,
+    plus: &'a str,
+    minus: &'a str,
+    started: bool,
+}
+
+impl Write for Signed<'_> {
+    fn write_str(&mut self, mut s: &str) -> fmt::Result {
+        if !self.started && !s.is_empty() {
+            self.started = true;
+            match s.strip_prefix('-') {
+                Some(rest) => {
+                    self.out.write_str(self.minus)?;
+                    s = rest;
+                }
+                None => self.out.write_str(self.plus)?,
+            }
+        }
+        self.out.write_str(s)
+    }
+}
+
+/// Writes `value` with `precision`, if any, after an infix `plus` or `minus`
+/// taken from its sign, so `-2` becomes `" - 2"` for any element type.
+pub(crate) fn write_signed(
+    out: &mut dyn Write,
+    value: &dyn Display,
+    precision: Option<usize>,
+    plus: &str,
+    minus: &str,
+) -> fmt::Result {
+    let mut signed = Signed {
+        out,
+        plus,
+        minus,
+        started: false,
+    };
+    match precision {
+        Some(precision) => write!(signed, "{:.*}", precision, value),
+        None => write!(signed, "{}", value),
+    }
+}
@@ -0,0 +1,383 @@
+//! Reverse-mode automatic differentiation.
+//!
+//! A [`Tape`] records every operation performed on its [`Var`]s. Calling
+//! [`Var::backward`] on a result walks the record once in reverse and yields
+//! the derivative of that result with respect to every variable on the tape,
+//! so the cost does not grow with the number of inputs the way
+//! [`Dual`](crate::dual::Dual) does. `Var` implements [`Real`], so it can be
+//! stored in a [`Matrix`] or [`Vector3`] and used with the existing
+//! operators, [`RealVector::dot`](crate::linear_algebra::vector::RealVector),
+//! [`Cross`](crate::linear_algebra::vector::Cross) and matrix products:
+//!
+//! ```
+//! # use minmath::{linear_algebra::{matrix::Matrix, vector::{Cross, RealVector, Vector3}}, tape::Tape};
+//! let tape = Tape::new();
+//! let a = tape.matrix(Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+//! let u = tape.vector3(Vector3::new(1.0, 0.0, 0.0));
+//! let v = tape.vector3(Vector3::new(0.0, 1.0, 0.0));
+//! let x = tape.matrix(Matrix::new([[1.0], [1.0]]));
+//! let y = (a * x).fold(u.cross(v).dot(Vector3::Z), |sum, e| sum + e);
+//! assert_eq!(y.value(), 11.0);
+//!
+//! let gradients = y.backward();
+//! assert_eq!(gradients.matrix(&a), Matrix::new([[1.0, 1.0], [1.0, 1.0]]));
+//! assert_eq!(gradients.matrix(&x), Matrix::new([[4.0], [6.0]]));
+//! assert_eq!(gradients.vector3(&u), Vector3::new(1.0, 0.0, 0.0));
+//! ```
+
+use std::{
+    cell::RefCell,
+    fmt::{self, Debug, Display, Formatter, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ptr,
+};
+
+use crate::{
+    linear_algebra::{matrix::Matrix, vector::Vector3},
+    scalar::{Real, Scalar},
+};
+
+// Marks an unused parent slot, and the index of a `Var` that is not on a tape.
+const NONE: usize = usize::MAX;
+
+// One recorded value: the indices of the up to two values it was computed
+// from and the partial derivatives with respect to them.
+#[derive(Clone, Copy)]
+struct Node {
+    parents: [(usize, f32); 2],
+}
+
+impl Node {
+    fn is_leaf(&self) -> bool {
+        self.parents.iter().all(|&(parent, _)| parent == NONE)
+    }
+}
+
+/// A record of operations, appended to by arithmetic on its [`Var`]s.
+#[derive(Default)]
+pub struct Tape {
+    nodes: RefCell<Vec<Node>>,
+}
+
+impl Tape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A new independent variable.
+    pub fn var(&self, value: f32) -> Var<'_> {
+        self.push(value, [(NONE, 0.0); 2])
+    }
+
+    /// A matrix of new independent variables.
+    pub fn matrix<const R: usize, const C: usize>(
+        &self,
+        values: Matrix<R, C>,
+    ) -> Matrix<R, C, Var<'_>> {
+        Matrix::from_fn(|i, j| self.var(values[i][j]))
+    }
+
+    /// A vector of new independent variables.
+    pub fn vector3(&self, values: Vector3) -> Vector3<Var<'_>> {
+        Vector3::new(self.var(values.x), self.var(values.y), self.var(values.z))
+    }
+
+    /// The number of recorded values, including the variables.
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forgets every recorded value so the tape can be reused, for example
+    /// between iterations of an optimizer.
+    pub fn clear(&mut self) {
+        self.nodes.get_mut().clear();
+    }
+
+    fn push(&self, value: f32, parents: [(usize, f32); 2]) -> Var<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { parents });
+        Var {
+            tape: Some(self),
+            index: nodes.len() - 1,
+            value,
+        }
+    }
+}
+
+/// A value recorded on a [`Tape`], or a constant that is not on any tape.
+///
+/// Equality compares only the value, so a variable equals a constant with
+/// the same value.
+#[derive(Clone, Copy)]
+pub struct Var<'t> {
+    tape: Option<&'t Tape>,
+    index: usize,
+    value: f32,
+}
+
+impl<'t> Var<'t> {
+    /// A value that does not depend on any variable.
+    pub const fn constant(value: f32) -> Self {
+        Self {
+            tape: None,
+            index: NONE,
+            value,
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// The derivatives of `self` with respect to every value on its tape.
+    pub fn backward(&self) -> Gradients {
+        let Some(tape) = self.tape else {
+            return Gradients {
+                adjoints: Vec::new(),
+                leaves: Vec::new(),
+            };
+        };
+        let nodes = tape.nodes.borrow();
+        let mut adjoints = vec![0.0; self.index + 1];
+        adjoints[self.index] = 1.0;
+        for (i, node) in nodes[..=self.index].iter().enumerate().rev() {
+            let adjoint = adjoints[i];
+            if adjoint == 0.0 {
+                continue;
+            }
+            for &(parent, partial) in &node.parents {
+                if parent != NONE {
+                    adjoints[parent] += partial * adjoint;
+                }
+            }
+        }
+        let leaves = (0..nodes.len()).filter(|&i| nodes[i].is_leaf()).collect();
+        Gradients { adjoints, leaves }
+    }
+
+    // The parent slot for `self` with partial derivative `partial`.
+    fn edge(self, partial: f32) -> (usize, f32) {
+        match self.tape {
+            Some(_) => (self.index, partial),
+            None => (NONE, 0.0),
+        }
+    }
+
+    // Records a function with value `f` and derivative `df` at `self.value`.
+    fn unary(self, f: f32, df: f32) -> Self {
+        match self.tape {
+            Some(tape) => tape.push(f, [self.edge(df), (NONE, 0.0)]),
+            None => Self::constant(f),
+        }
+    }
+
+    // Records a function with value `f` and partial derivatives `dself` and
+    // `drhs`.
+    fn binary(self, rhs: Self, f: f32, dself: f32, drhs: f32) -> Self {
+        let tape = match (self.tape, rhs.tape) {
+            (Some(a), Some(b)) => {
+                assert!(ptr::eq(a, b), "Vars from different tapes");
+                a
+            }
+            (Some(tape), None) | (None, Some(tape)) => tape,
+            (None, None) => return Self::constant(f),
+        };
+        tape.push(f, [self.edge(dself), rhs.edge(drhs)])
+    }
+}
+
+/// The result of [`Var::backward`]: the derivative of one output with
+/// respect to each value recorded before it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradients {
+    adjoints: Vec<f32>,
+    leaves: Vec<usize>,
+}
+
+impl Gradients {
+    /// The derivative with respect to `var`, which is zero for constants and
+    /// for values recorded after the output.
+    pub fn wrt(&self, var: Var<'_>) -> f32 {
+        match var.tape {
+            Some(_) => self.adjoints.get(var.index).copied().unwrap_or(0.0),
+            None => 0.0,
+        }
+    }
+
+    pub fn matrix<const R: usize, const C: usize>(
+        &self,
+        vars: &Matrix<R, C, Var<'_>>,
+    ) -> Matrix<R, C> {
+        Matrix::from_fn(|i, j| self.wrt(vars[i][j]))
+    }
+
+    pub fn vector3(&self, vars: &Vector3<Var<'_>>) -> Vector3 {
+        Vector3::new(self.wrt(vars.x), self.wrt(vars.y), self.wrt(vars.z))
+    }
+
+    /// The derivative with respect to each variable on the tape, in the order
+    /// they were created.
+    pub fn leaves(&self) -> impl Iterator<Item = f32> + '_ {
+        self.leaves
+            .iter()
+            .map(|&i| self.adjoints.get(i).copied().unwrap_or(0.0))
+    }
+}
+
+/// The gradient of `f` at `x`, from a single evaluation and backward pass.
+pub fn gradient<const N: usize>(
+    f: impl for<'t> FnOnce(Matrix<N, 1, Var<'t>>) -> Var<'t>,
+    x: Matrix<N, 1>,
+) -> Matrix<N, 1> {
+    let tape = Tape::new();
+    let x = tape.matrix(x);
+    f(x).backward().matrix(&x)
+}
+
+impl Neg for Var<'_> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.unary(-self.value, -1.0)
+    }
+}
+
+impl Add for Var<'_> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value + rhs.value, 1.0, 1.0)
+    }
+}
+
+impl Sub for Var<'_> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value - rhs.value, 1.0, -1.0)
+    }
+}
+
+impl Mul for Var<'_> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl Div for Var<'_> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let quotient = self.value / rhs.value;
+        self.binary(rhs, quotient, 1.0 / rhs.value, -quotient / rhs.value)
+    }
+}
+
+impl AddAssign for Var<'_> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Var<'_> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Var<'_> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Var<'_> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl PartialEq for Var<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Real for Var<'_> {
+    fn from_f32(value: f32) -> Self {
+        Self::constant(value)
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.value
+    }
+
+    fn abs(self) -> Self {
+        self.unary(self.value.abs(), self.value.signum())
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.unary(root, 0.5 / root)
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.unary(exp, exp)
+    }
+
+    fn ln(self) -> Self {
+        self.unary(self.value.ln(), 1.0 / self.value)
+    }
+
+    fn sin(self) -> Self {
+        self.unary(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.unary(self.value.cos(), -self.value.sin())
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.value.tan();
+        self.unary(tan, 1.0 + tan * tan)
+    }
+}
+
+impl Scalar for Var<'_> {
+    const ZERO: Self = Self::constant(0.0);
+    const ONE: Self = Self::constant(1.0);
+    const EPSILON: f64 = f32::EPSILON as f64;
+
+    /// The absolute value of the value.
+    fn magnitude(&self) -> f64 {
+        self.value.abs() as f64
+    }
+
+    fn is_finite(&self) -> bool {
+        self.value.is_finite()
+    }
+
+    fn write_rust(&self, out: &mut dyn Write, precision: Option<usize>) -> fmt::Result {
+        self.value.write_rust(out, precision)
+    }
+}
+
+/// Prints the value and, for a value on a tape, its position there, such as
+/// `Var(2.5 @ 3)`.
+impl Debug for Var<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.tape {
+            Some(_) => write!(f, "Var({:?} @ {})", self.value, self.index),
+            None => write!(f, "Var({:?})", self.value),
+        }
+    }
+}
+
+/// Prints the value alone, honouring the formatter's width and precision.
+impl Display for Var<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
@@ -0,0 +1,36 @@
+//! Fixtures shared by the integration tests.
+
+// Each test crate uses only some of these.
+#![allow(dead_code)]
+
+use minmath::{linear_algebra::matrix::Matrix, scalar::Real};
+
+pub fn assert_close<const R: usize, const C: usize>(
+    actual: Matrix<R, C>,
+    expected: Matrix<R, C>,
+    tolerance: f32,
+) {
+    assert!(
+        (actual - expected).max_norm() < tolerance,
+        "{}{}",
+        actual,
+        expected
+    );
+}
+
+pub fn rosenbrock<T: Real>(x: T, y: T) -> T {
+    let one = T::from_f32(1.0);
+    let a = one - x;
+    let b = y - x * x;
+    a * a + T::from_f32(100.0) * b * b
+}
+
+// Forward kinematics of a three-joint arm: rotations about z, y and x with
+// unit links along x.
+pub fn arm<T: Real>(q: Matrix<3, 1, T>) -> Matrix<3, 1, T> {
+    let link = Matrix::new([[T::ONE], [T::ZERO], [T::ZERO]]);
+    let first = Matrix::<3, 3, T>::rotation_matrix3x3_z(q[0][0]);
+    let second = first * Matrix::<3, 3, T>::rotation_matrix3x3_y(q[1][0]);
+    let third = second * Matrix::<3, 3, T>::rotation_matrix3x3_x(q[2][0]);
+    first * link + second * link + third * link
+}
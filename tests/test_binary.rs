@@ -0,0 +1,143 @@
+use minmath::linear_algebra::{
+    binary::BinaryError,
+    matrix::Matrix,
+    vector::{Vector2, Vector3},
+};
+
+#[cfg(feature = "std")]
+#[test]
+fn test_encoding_is_stable() {
+    let bytes = Vector2::new(1.0, 2.0).to_bytes();
+    assert_eq!(
+        bytes,
+        [
+            b'M', b'N', b'M', b'X', 1, 1, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64,
+            94, 211, 100, 16
+        ]
+    );
+    assert_eq!(bytes.len(), Vector2::ENCODED_LEN);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_round_trip() {
+    let m = Matrix::new([[1.0, -2.0, f32::MAX], [f32::MIN_POSITIVE, 0.5, -0.0]]);
+    let bytes = m.to_bytes();
+    assert_eq!(bytes.len(), Matrix::<2, 3>::ENCODED_LEN);
+    assert_eq!(Matrix::<2, 3>::from_bytes(&bytes), Ok(m));
+
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(Vector3::from_bytes(&v.to_bytes()), Ok(v));
+    let v = Vector2::new(-1.5, 4.0);
+    assert_eq!(Vector2::from_bytes(&v.to_bytes()), Ok(v));
+}
+
+#[test]
+fn test_write_bytes_without_allocation() {
+    let m = Matrix::<2, 2>::IDENTITY;
+    let mut buffer = [0u8; 64];
+    let written = m.write_bytes(&mut buffer).unwrap();
+    assert_eq!(written, Matrix::<2, 2>::ENCODED_LEN);
+    assert_eq!(Matrix::<2, 2>::from_bytes(&buffer[..written]), Ok(m));
+    assert_eq!(
+        m.write_bytes(&mut buffer[..10]),
+        Err(BinaryError::BufferTooSmall {
+            needed: 36,
+            found: 10
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_decode_errors() {
+    let bytes = Matrix::new([[1.0, 2.0], [3.0, 4.0]]).to_bytes();
+    assert_eq!(
+        Matrix::<4, 1>::from_bytes(&bytes),
+        Err(BinaryError::ShapeMismatch {
+            expected: (4, 1),
+            found: (2, 2)
+        })
+    );
+    assert_eq!(
+        Matrix::<2, 2>::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(BinaryError::Length {
+            expected: 36,
+            found: 35
+        })
+    );
+    assert_eq!(
+        Matrix::<2, 2>::from_bytes(&bytes[..3]),
+        Err(BinaryError::Length {
+            expected: 20,
+            found: 3
+        })
+    );
+
+    let mut corrupted = bytes.clone();
+    corrupted[20] ^= 1;
+    assert_eq!(
+        Matrix::<2, 2>::from_bytes(&corrupted),
+        Err(BinaryError::ChecksumMismatch)
+    );
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert_eq!(
+        Matrix::<2, 2>::from_bytes(&corrupted),
+        Err(BinaryError::InvalidMagic)
+    );
+    let mut corrupted = bytes;
+    corrupted[4] = 2;
+    assert_eq!(
+        Matrix::<2, 2>::from_bytes(&corrupted),
+        Err(BinaryError::UnsupportedVersion(2))
+    );
+
+    let column = Vector3::new(1.0, 2.0, 3.0).to_matrix().to_bytes();
+    assert_eq!(Vector3::from_bytes(&column), Err(BinaryError::KindMismatch));
+}
+
+#[test]
+fn test_zero_copy() {
+    let m = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let bytes = m.as_bytes();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(&bytes[4..8], &2.0f32.to_ne_bytes());
+    assert_eq!(Matrix::<2, 2>::from_bytes_ref(bytes), Ok(&m));
+
+    let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+    let bytes = Vector3::slice_as_bytes(&vectors);
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(&bytes[12..16], &4.0f32.to_ne_bytes());
+    assert_eq!(Vector3::slice_from_bytes(bytes), Ok(&vectors[..]));
+    assert_eq!(
+        Vector3::slice_from_bytes(&bytes[..20]),
+        Err(BinaryError::Length {
+            expected: 24,
+            found: 20
+        })
+    );
+
+    let points = [Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)];
+    let bytes = Vector2::slice_as_bytes(&points);
+    assert_eq!(Vector2::from_bytes_ref(&bytes[8..]), Ok(&points[1]));
+    assert_eq!(
+        Vector2::from_bytes_ref(points[0].as_bytes()),
+        Ok(&points[0])
+    );
+}
+
+#[test]
+fn test_zero_copy_alignment() {
+    let backing = Matrix::<1, 5>::ZERO;
+    let bytes = backing.as_bytes();
+    assert!(Vector2::from_bytes_ref(&bytes[..8]).is_ok());
+    assert_eq!(
+        Vector2::from_bytes_ref(&bytes[1..9]),
+        Err(BinaryError::Misaligned)
+    );
+    assert_eq!(
+        Matrix::<1, 4>::slice_from_bytes(&bytes[2..18]),
+        Err(BinaryError::Misaligned)
+    );
+}
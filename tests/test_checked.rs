@@ -0,0 +1,190 @@
+#[cfg(feature = "std")]
+use minmath::linear_algebra::dmatrix::DMatrix;
+use minmath::{
+    error::MathError,
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+    },
+};
+
+mod common;
+
+use common::assert_close;
+
+#[test]
+fn test_checked_mul() {
+    let a = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Matrix::new([[5.0], [6.0]]);
+    assert_eq!(a.checked_mul(&b), Ok(a * b));
+    let c = Matrix::<3, 1>::ONES;
+    assert_eq!(
+        a.checked_mul(&c),
+        Err(MathError::ShapeMismatch {
+            expected: (2, 1),
+            found: (3, 1)
+        })
+    );
+    let huge = Matrix::<1, 1>::filled(f32::MAX);
+    assert_eq!(huge.checked_mul(&huge), Err(MathError::NonFinite));
+}
+
+#[test]
+fn test_checked_div() {
+    let m = Matrix::new([[2.0, 4.0]]);
+    assert_eq!(m.checked_div(2.0), Ok(Matrix::new([[1.0, 2.0]])));
+    assert_eq!(
+        m.checked_div(0.0),
+        Err(MathError::Domain("division by zero"))
+    );
+    assert_eq!(m.checked_div(1e-40), Err(MathError::NonFinite));
+    assert!(matches!(
+        Vector2::new(1.0, 2.0).checked_div(0.0),
+        Err(MathError::Domain(_))
+    ));
+    assert_eq!(
+        Vector3::new(3.0, 6.0, 9.0).checked_div(3.0),
+        Ok(Vector3::new(1.0, 2.0, 3.0))
+    );
+}
+
+#[test]
+fn test_checked_inverse() {
+    let m = Matrix::new([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]);
+    let inverse = m.checked_inverse().unwrap();
+    assert_close(m * inverse, Matrix::IDENTITY, 1e-5);
+    assert_close(inverse * m, Matrix::IDENTITY, 1e-5);
+
+    let singular = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    assert_eq!(singular.checked_inverse(), Err(MathError::Singular));
+    assert_eq!(
+        Matrix::<2, 2>::ZERO.checked_inverse(),
+        Err(MathError::Singular)
+    );
+    assert_eq!(
+        Matrix::<0, 0>::IDENTITY.checked_inverse(),
+        Ok(Matrix::IDENTITY)
+    );
+}
+
+#[test]
+fn test_checked_to_vector() {
+    assert_eq!(
+        Matrix::new([[1.0], [2.0]]).checked_to_vector2(),
+        Ok(Vector2::new(1.0, 2.0))
+    );
+    assert_eq!(
+        Matrix::new([[1.0], [2.0], [3.0]]).checked_to_vector3(),
+        Ok(Vector3::new(1.0, 2.0, 3.0))
+    );
+    assert_eq!(
+        Matrix::new([[1.0, 2.0]]).checked_to_vector2(),
+        Err(MathError::ShapeMismatch {
+            expected: (2, 1),
+            found: (1, 2)
+        })
+    );
+    assert!(Matrix::<2, 1>::ZERO.checked_to_vector3().is_err());
+}
+
+#[test]
+fn test_normalize() {
+    let v = Vector3::new(3.0, 0.0, 4.0);
+    assert_eq!(v.length(), 5.0);
+    assert_eq!(v.normalize(), Vector3::new(0.6, 0.0, 0.8));
+    assert_eq!(v.checked_normalize(), Ok(Vector3::new(0.6, 0.0, 0.8)));
+    assert_eq!(
+        Vector2::ZERO.checked_normalize(),
+        Err(MathError::Domain("cannot normalize a zero vector"))
+    );
+    #[cfg(not(feature = "debug-finite"))]
+    assert!(Vector2::ZERO.normalize().x.is_nan());
+    assert_eq!(
+        Vector2::new(f32::INFINITY, 1.0).checked_normalize(),
+        Err(MathError::NonFinite)
+    );
+    assert_eq!(
+        Vector2::new(0.0, -2.0).checked_normalize(),
+        Ok(Vector2::new(0.0, -1.0))
+    );
+    assert_eq!(
+        Vector3::new(1e20, 0.0, 0.0).checked_normalize(),
+        Ok(Vector3::X)
+    );
+    assert_eq!(
+        Vector3::new(0.0, 1e30, -1e30).checked_normalize(),
+        Ok(Vector3::new(0.0, 1.0, -1.0) / 2.0f32.sqrt())
+    );
+    assert_eq!(
+        Vector2::new(1e-30, 0.0).checked_normalize(),
+        Ok(Vector2::new(1.0, 0.0))
+    );
+    assert_eq!(
+        Vector2::new(f32::MIN_POSITIVE / 4.0, -f32::MIN_POSITIVE / 4.0).checked_normalize(),
+        Ok(Vector2::new(1.0, -1.0) / 2.0f32.sqrt())
+    );
+    assert_eq!(
+        Vector3::new(f32::NAN, 1.0, 0.0).checked_normalize(),
+        Err(MathError::NonFinite)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_dmatrix_checked() {
+    let a = DMatrix::new(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
+    let b = DMatrix::new(2, 3, vec![1.0; 6]);
+    assert_eq!(a.checked_mul(&b), Ok(&a * &b));
+    assert_eq!(
+        b.checked_mul(&a),
+        Err(MathError::ShapeMismatch {
+            expected: (3, 2),
+            found: (2, 2)
+        })
+    );
+    assert!(matches!(
+        a.checked_add(&b),
+        Err(MathError::ShapeMismatch { .. })
+    ));
+    assert!(matches!(
+        a.checked_sub(&b),
+        Err(MathError::ShapeMismatch { .. })
+    ));
+    assert_eq!(a.checked_add(&a), Ok(&a * 2.0));
+    assert!(matches!(a.checked_div(0.0), Err(MathError::Domain(_))));
+
+    let inverse = a.checked_inverse().unwrap();
+    let product = &a * &inverse;
+    for (value, expected) in product.as_slice().iter().zip([1.0, 0.0, 0.0, 1.0]) {
+        assert!((value - expected).abs() < 1e-5);
+    }
+    assert!(matches!(
+        b.checked_inverse(),
+        Err(MathError::ShapeMismatch { .. })
+    ));
+    let singular = DMatrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+    assert_eq!(singular.checked_inverse(), Err(MathError::Singular));
+
+    let lu = a.lu().unwrap();
+    assert!(matches!(
+        lu.checked_solve(&[1.0]),
+        Err(MathError::ShapeMismatch { .. })
+    ));
+    assert!(lu.checked_solve(&[1.0, 2.0]).is_ok());
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        MathError::ShapeMismatch {
+            expected: (2, 1),
+            found: (3, 1)
+        }
+        .to_string(),
+        "expected a 2x1 operand, found 3x1"
+    );
+    assert_eq!(
+        MathError::NotConverged { iterations: 50 }.to_string(),
+        "did not converge after 50 iterations"
+    );
+}
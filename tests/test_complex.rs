@@ -0,0 +1,220 @@
+use core::f32::consts::{FRAC_PI_2, PI};
+
+use minmath::{
+    complex::Complex,
+    linear_algebra::{format::Style, matrix::Matrix},
+    rational::Rational,
+};
+
+fn c(re: f32, im: f32) -> Complex {
+    Complex::new(re, im)
+}
+
+fn assert_close(actual: Complex, expected: Complex) {
+    assert!(
+        (actual - expected).abs() < 1e-5,
+        "{} != {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = c(1.0, 2.0);
+    let b = c(3.0, -4.0);
+    assert_eq!(a + b, c(4.0, -2.0));
+    assert_eq!(a - b, c(-2.0, 6.0));
+    assert_eq!(a * b, c(11.0, 2.0));
+    assert_eq!(a * b / b, a);
+    assert_eq!(-a, c(-1.0, -2.0));
+    assert_eq!(a * 2.0, c(2.0, 4.0));
+    assert_eq!(a + 1.0, c(2.0, 2.0));
+    let i: Complex = Complex::I;
+    assert_eq!(i * i, -Complex::ONE);
+    assert_eq!(a.conj(), c(1.0, -2.0));
+    assert_eq!(a.norm_sqr(), 5.0);
+    assert_eq!(b.abs(), 5.0);
+
+    let mut x = a;
+    x *= b;
+    x -= c(1.0, 0.0);
+    x /= 2.0;
+    assert_eq!(x, c(5.0, 1.0));
+}
+
+#[test]
+fn test_division_avoids_overflow() {
+    let big = c(1e30, 1e30);
+    assert_close(big / big, Complex::ONE);
+    assert_eq!(c(1.0, 0.0) / c(0.0, 2.0), c(0.0, -0.5));
+}
+
+#[test]
+fn test_powi() {
+    let a = c(1.0, 1.0);
+    assert_eq!(a.powi(0), Complex::ONE);
+    assert_eq!(a.powi(2), c(0.0, 2.0));
+    assert_eq!(a.powi(4), c(-4.0, 0.0));
+    assert_eq!(a.powi(-2), c(0.0, -0.5));
+}
+
+#[test]
+fn test_polar_form() {
+    let z = Complex::from_polar(2.0, FRAC_PI_2);
+    assert_close(z, c(0.0, 2.0));
+    let (r, theta) = c(-1.0, 0.0).to_polar();
+    assert_eq!(r, 1.0);
+    assert!((theta - PI).abs() < 1e-6);
+    assert!((c(0.0, -1.0).arg() + FRAC_PI_2).abs() < 1e-6);
+    assert_eq!(c(3e30, 4e30).abs(), 5e30);
+}
+
+#[test]
+fn test_exp_and_ln() {
+    assert_close(c(0.0, PI).exp(), c(-1.0, 0.0));
+    assert_close(c(1.0, 0.0).exp(), c(core::f32::consts::E, 0.0));
+    assert_close(c(-1.0, 0.0).ln(), c(0.0, PI));
+    let z = c(0.5, -1.25);
+    assert_close(z.ln().exp(), z);
+}
+
+#[test]
+fn test_sqrt() {
+    assert_eq!(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+    assert_eq!(c(0.0, 2.0).sqrt(), c(1.0, 1.0));
+    assert_eq!(c(0.0, -2.0).sqrt(), c(1.0, -1.0));
+    assert_eq!(Complex::ZERO.sqrt(), Complex::ZERO);
+    let z = c(-3.0, 4.0);
+    assert_close(z.sqrt() * z.sqrt(), z);
+    assert!(z.sqrt().re >= 0.0);
+}
+
+#[test]
+fn test_powers() {
+    assert_close(c(-1.0, 0.0).powf(0.5), Complex::I);
+    assert_close(c(0.0, 1.0).powc(Complex::I), c((-FRAC_PI_2).exp(), 0.0));
+    assert_eq!(Complex::ZERO.powf(2.0), Complex::ZERO);
+    assert_eq!(Complex::ZERO.powc(Complex::ZERO), Complex::ONE);
+    assert_eq!(c(2.0, 3.0).powf(0.0), Complex::ONE);
+}
+
+#[test]
+fn test_exact_components() {
+    let r = |n, d| Rational::new(n, d);
+    let z = Complex::new(r(1, 2), r(1, 3));
+    let w = Complex::new(r(2, 1), r(-1, 4));
+    assert_eq!(z / w * w, z);
+    assert_eq!(z.recip(), Complex::new(r(18, 13), r(-12, 13)));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(c(1.0, 2.0).to_string(), "1+2i");
+    assert_eq!(c(1.5, -2.0).to_string(), "1.5-2i");
+    assert_eq!(format!("{:.1}", c(0.0, 1.0)), "0.0+1.0i");
+    assert_eq!(format!("{:>8}", c(1.0, -1.0)), "    1-1i");
+    assert_eq!(
+        Complex::new(Rational::new(1, 2), Rational::new(-3, 4)).to_string(),
+        "1/2-3/4i"
+    );
+}
+
+#[test]
+fn test_conjugate_transpose() {
+    let m = Matrix::new([
+        [c(1.0, 1.0), c(2.0, 0.0), c(0.0, 3.0)],
+        [c(4.0, -1.0), c(5.0, 2.0), c(6.0, 0.0)],
+    ]);
+    let adjoint = m.conjugate_transpose();
+    assert_eq!(adjoint.size(), (3, 2));
+    assert_eq!(adjoint[0][1], c(4.0, 1.0));
+    assert_eq!(adjoint[2][0], c(0.0, -3.0));
+    assert_eq!(adjoint.conjugate_transpose(), m);
+    assert_eq!(m.re(), Matrix::new([[1.0, 2.0, 0.0], [4.0, 5.0, 6.0]]));
+    assert_eq!(m.im(), Matrix::new([[1.0, 0.0, 3.0], [-1.0, 2.0, 0.0]]));
+}
+
+#[test]
+fn test_hermitian_and_unitary() {
+    let hermitian = Matrix::new([[c(2.0, 0.0), c(1.0, -1.0)], [c(1.0, 1.0), c(3.0, 0.0)]]);
+    assert!(hermitian.is_hermitian());
+    assert!(!Matrix::new([[c(0.0, 1.0)]]).is_hermitian());
+
+    let s = core::f32::consts::FRAC_1_SQRT_2;
+    let unitary = Matrix::new([[c(s, 0.0), c(0.0, s)], [c(0.0, s), c(s, 0.0)]]);
+    assert!(unitary.is_unitary(1e-6));
+    assert!(!hermitian.is_unitary(1e-6));
+}
+
+#[test]
+fn test_rotation_is_unitary() {
+    let rotation: Matrix<2, 2, Complex> = Matrix::<2, 2>::rotation_matrix2x2(0.3).into();
+    assert!(rotation.is_unitary(1e-6));
+    // The eigenvalues of a rotation by θ are e^(±iθ).
+    let eigenvalue = Complex::from_polar(1.0, 0.3);
+    let shifted = rotation - Matrix::<2, 2, Complex>::IDENTITY * eigenvalue;
+    assert!(shifted.determinant().abs() < 1e-6);
+}
+
+#[test]
+fn test_complex_lu() {
+    let m = Matrix::new([
+        [c(1.0, 1.0), c(2.0, 0.0), c(0.0, 1.0)],
+        [c(0.0, 2.0), c(1.0, -1.0), c(3.0, 0.0)],
+        [c(4.0, 0.0), c(0.0, 0.0), c(1.0, 1.0)],
+    ]);
+    let lu = m.lu().unwrap();
+    assert_eq!(lu.permutation(), &[2, 0, 1]);
+    let permuted: Matrix<3, 3, Complex> = Matrix::from_fn(|r, col| m[lu.permutation()[r]][col]);
+    let product = lu.lower() * lu.upper();
+    for ((r, col), value) in product.enumerate() {
+        assert_close(*value, permuted[r][col]);
+    }
+    assert_close(lu.determinant(), m.determinant());
+
+    let b = [c(1.0, 0.0), c(0.0, 1.0), c(2.0, -1.0)];
+    let x = lu.checked_solve(&b).unwrap();
+    for (r, expected) in b.iter().enumerate() {
+        let value = (0..3).fold(Complex::ZERO, |sum, k| sum + m[r][k] * x[k]);
+        assert_close(value, *expected);
+    }
+    let x = lu.solve_transpose(&b);
+    for (r, expected) in b.iter().enumerate() {
+        let value = (0..3).fold(Complex::ZERO, |sum, k| sum + m[k][r] * x[k]);
+        assert_close(value, *expected);
+    }
+    let inverse = m.checked_inverse().unwrap();
+    for ((r, col), value) in (m * inverse).enumerate() {
+        let expected = if r == col {
+            Complex::ONE
+        } else {
+            Complex::ZERO
+        };
+        assert_close(*value, expected);
+    }
+
+    let singular = Matrix::new([[c(1.0, 1.0), c(2.0, 2.0)], [c(1.0, 0.0), c(2.0, 0.0)]]);
+    assert!(singular.lu().is_none());
+}
+
+#[test]
+fn test_real_lu() {
+    let m = Matrix::new([[2.0, 1.0], [4.0, 3.0]]);
+    let lu = m.lu().unwrap();
+    assert_eq!(lu.lower(), Matrix::new([[1.0, 0.0], [0.5, 1.0]]));
+    assert_eq!(lu.upper(), Matrix::new([[4.0, 3.0], [0.0, -0.5]]));
+    assert_eq!(lu.determinant(), 2.0);
+    assert_eq!(lu.solve(&[3.0, 7.0]), [1.0, 1.0]);
+    assert_eq!(lu.solve_transpose(&[6.0, 4.0]), [1.0, 1.0]);
+}
+
+#[test]
+fn test_complex_matrix_format() {
+    let m = Matrix::new([[c(1.0, -1.0), c(0.5, 2.0)]]);
+    assert_eq!(m.to_string(), "Matrix (1x2):\n1-1i 0.5+2i\n");
+    assert_eq!(
+        m.format().style(Style::Rust).to_string(),
+        "Matrix::new([\n    [Complex::new(1.0, -1.0), Complex::new(0.5, 2.0)],\n])\n"
+    );
+}
@@ -0,0 +1,58 @@
+use minmath::linear_algebra::{
+    matrix::Matrix,
+    vector::{Vector2, Vector3},
+};
+
+const ROTATE_Z_90: Matrix<3, 3> = Matrix::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+const TRANSPOSED: Matrix<3, 3> = ROTATE_Z_90.transpose();
+const OFFSET: Matrix<2, 3> = Matrix::<2, 3>::ONES.scale(2.0).const_sub(Matrix::ONES);
+const SUM: Matrix<2, 2> = Matrix::<2, 2>::IDENTITY.const_add(Matrix::filled(0.5));
+const CORNER: f32 = SUM.get(0, 1);
+const LOOKUP: [Vector3; 3] = [Vector3::X, Vector3::Y, Vector3::Z];
+const DIAGONAL: Vector3 = Vector3::X.const_add(Vector3::Y).const_add(Vector3::Z);
+static UP: Vector3 = Vector3::X.cross(Vector3::Y);
+
+#[test]
+fn test_const_matrices() {
+    assert_eq!(
+        TRANSPOSED,
+        Matrix::new([[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+    );
+    assert_eq!(OFFSET, Matrix::<2, 3>::ONES);
+    assert_eq!(SUM, Matrix::new([[1.5, 0.5], [0.5, 1.5]]));
+    assert_eq!(CORNER, 0.5);
+    assert_eq!(Matrix::<2, 2>::ZERO, Matrix::zero());
+    assert_eq!(Matrix::<3, 3>::IDENTITY * ROTATE_Z_90, ROTATE_Z_90);
+}
+
+#[test]
+fn test_const_operations_match_operators() {
+    let a = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Matrix::new([[0.5, -1.0], [2.5, 8.0]]);
+    assert_eq!(a.const_add(b), a + b);
+    assert_eq!(a.const_sub(b), a - b);
+    assert_eq!(a.scale(3.0), a * 3.0);
+}
+
+#[test]
+fn test_get_and_set() {
+    let mut m = Matrix::<2, 2>::ZERO;
+    m.set(1, 0, 7.0);
+    assert_eq!(m.get(1, 0), 7.0);
+    assert_eq!(m[1][0], 7.0);
+    assert_eq!(m.as_array(), &[[0.0, 0.0], [7.0, 0.0]]);
+}
+
+#[test]
+fn test_const_vectors() {
+    assert_eq!(LOOKUP[1], Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(DIAGONAL, Vector3::ONE);
+    assert_eq!(UP, Vector3::Z);
+    assert_eq!(Vector3::ZERO, Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(Vector2::X.const_add(Vector2::Y), Vector2::ONE);
+    assert_eq!(
+        Vector2::ONE.scale(2.0).const_sub(Vector2::X),
+        Vector2::new(1.0, 2.0)
+    );
+    assert_eq!(Vector2::ZERO.dot(Vector2::X), 0.0);
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "std")]
+
+use minmath::{
+    io::csv::{self, CsvError, CsvOptions, EmptyCells, NanValues},
+    linear_algebra::{dmatrix::DMatrix, matrix::Matrix},
+};
+
+fn read_str(text: &str, options: CsvOptions) -> Result<(Option<Vec<String>>, DMatrix), CsvError> {
+    csv::read(text.as_bytes(), options)
+}
+
+fn write_string(matrix: &DMatrix, header: Option<&[&str]>, options: CsvOptions) -> String {
+    let mut buffer = Vec::new();
+    csv::write(&mut buffer, matrix, header, options).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_read_basic() {
+    let (header, m) = read_str("1,2,3\n4, 5 ,6.5\n", CsvOptions::default()).unwrap();
+    assert_eq!(header, None);
+    assert_eq!(m, DMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.5]));
+}
+
+#[test]
+fn test_read_header_and_quotes() {
+    let text = "time,\"temp, \"\"C\"\"\",\"multi\nline\"\r\n0,21.5,1\r\n\r\n1,\"22\",2\r\n";
+    let options = CsvOptions {
+        header: true,
+        ..Default::default()
+    };
+    let (header, m) = read_str(text, options).unwrap();
+    assert_eq!(header.unwrap(), vec!["time", "temp, \"C\"", "multi\nline"]);
+    assert_eq!(m, DMatrix::new(2, 3, vec![0.0, 21.5, 1.0, 1.0, 22.0, 2.0]));
+}
+
+#[test]
+fn test_read_delimiters() {
+    let options = CsvOptions {
+        delimiter: ';',
+        ..Default::default()
+    };
+    let (_, m) = read_str("1;2\n3;4", options).unwrap();
+    assert_eq!(m, DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+
+    let options = CsvOptions {
+        delimiter: '\t',
+        ..Default::default()
+    };
+    assert!(matches!(
+        read_str("1\t\t2\n", options),
+        Err(CsvError::EmptyCell { row: 1, column: 2 })
+    ));
+    let (_, m) = read_str(" 1\t2 \n3\t4\n", options).unwrap();
+    assert_eq!(m, DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+
+    let options = CsvOptions {
+        delimiter: '"',
+        ..Default::default()
+    };
+    assert!(matches!(
+        read_str("1", options),
+        Err(CsvError::InvalidDelimiter('"'))
+    ));
+}
+
+#[test]
+fn test_empty_cells() {
+    let text = "1,,3\n";
+    assert!(matches!(
+        read_str(text, CsvOptions::default()),
+        Err(CsvError::EmptyCell { row: 1, column: 2 })
+    ));
+    let zero = CsvOptions {
+        empty: EmptyCells::Zero,
+        ..Default::default()
+    };
+    assert_eq!(read_str(text, zero).unwrap().1[0], [1.0, 0.0, 3.0]);
+    let nan = CsvOptions {
+        empty: EmptyCells::Nan,
+        nan: NanValues::Reject,
+        ..Default::default()
+    };
+    assert!(read_str(text, nan).unwrap().1[0][1].is_nan());
+}
+
+#[test]
+fn test_nan_values() {
+    let text = "1,2\n3,NaN\n";
+    assert!(read_str(text, CsvOptions::default()).unwrap().1[1][1].is_nan());
+    let reject = CsvOptions {
+        nan: NanValues::Reject,
+        ..Default::default()
+    };
+    assert!(matches!(
+        read_str(text, reject),
+        Err(CsvError::NanValue { row: 2, column: 2 })
+    ));
+
+    let m = DMatrix::new(1, 2, vec![1.0, f32::NAN]);
+    assert_eq!(write_string(&m, None, CsvOptions::default()), "1.0,NaN\n");
+    let empty = CsvOptions {
+        empty: EmptyCells::Nan,
+        ..Default::default()
+    };
+    assert_eq!(write_string(&m, None, empty), "1.0,\n");
+    let column = DMatrix::new(2, 1, vec![f32::NAN, 1.0]);
+    let written = write_string(&column, None, empty);
+    assert_eq!(written, "\"\"\n1.0\n");
+    let (_, read) = read_str(&written, empty).unwrap();
+    assert!(read[0][0].is_nan() && read[1][0] == 1.0);
+    assert!(matches!(
+        csv::write(Vec::new(), &m, Some(&["a", "b"]), reject),
+        Err(CsvError::NanValue { row: 2, column: 2 })
+    ));
+}
+
+#[test]
+fn test_errors_report_position() {
+    let options = CsvOptions::default();
+    assert!(matches!(
+        read_str("1,2\n3,x4\n", options),
+        Err(CsvError::InvalidNumber { row: 2, column: 2, ref text }) if text == "x4"
+    ));
+    assert!(matches!(
+        read_str("1,2\n\n3\n", options),
+        Err(CsvError::RaggedRow {
+            row: 3,
+            expected: 2,
+            found: 1
+        })
+    ));
+    assert!(matches!(
+        read_str("1,\"2\n3\n", options),
+        Err(CsvError::UnterminatedQuote { row: 1, column: 2 })
+    ));
+    assert!(matches!(
+        read_str("1,\"2\"3\n", options),
+        Err(CsvError::InvalidQuote { row: 1, column: 2 })
+    ));
+    let header = CsvOptions {
+        header: true,
+        ..Default::default()
+    };
+    assert!(matches!(
+        read_str("a,\"b\nc\"\n1,2,3\n", header),
+        Err(CsvError::RaggedRow {
+            row: 3,
+            expected: 2,
+            found: 3
+        })
+    ));
+    let unquoted = CsvOptions {
+        quote: None,
+        ..Default::default()
+    };
+    assert!(matches!(
+        read_str("\"1\",2\n", unquoted),
+        Err(CsvError::InvalidNumber {
+            row: 1,
+            column: 1,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_read_matrix() {
+    let m: Matrix<2, 2> = csv::read_matrix("1,2\n3,4\n".as_bytes(), CsvOptions::default()).unwrap();
+    assert_eq!(m, Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+    assert!(matches!(
+        csv::read_matrix::<2, 3, _>("1,2\n3,4\n".as_bytes(), CsvOptions::default()),
+        Err(CsvError::ShapeMismatch {
+            expected: (2, 3),
+            found: (2, 2)
+        })
+    ));
+    let (header, empty) = read_str("", CsvOptions::default()).unwrap();
+    assert_eq!(header, None);
+    assert_eq!(empty.size(), (0, 0));
+}
+
+#[test]
+fn test_write_and_round_trip() {
+    let m = Matrix::new([[1.0, -2.5, 1.0e-7], [f32::MAX, 0.1, 3.0]]);
+    let options = CsvOptions {
+        delimiter: ';',
+        header: true,
+        ..Default::default()
+    };
+    let mut buffer = Vec::new();
+    csv::write_matrix(&mut buffer, &m, Some(&["x", "a;b", "say \"hi\""]), options).unwrap();
+    let text = String::from_utf8(buffer).unwrap();
+    assert!(text.starts_with("x;\"a;b\";\"say \"\"hi\"\"\"\n"));
+    let (header, read) = read_str(&text, options).unwrap();
+    assert_eq!(header.unwrap(), vec!["x", "a;b", "say \"hi\""]);
+    assert_eq!(read, DMatrix::from(m));
+
+    assert!(matches!(
+        csv::write_matrix(Vec::new(), &m, Some(&["x"]), options),
+        Err(CsvError::HeaderLength {
+            expected: 3,
+            found: 1
+        })
+    ));
+}
@@ -0,0 +1,89 @@
+use minmath::{error::MathError, linear_algebra::matrix::Matrix};
+
+mod common;
+
+use common::assert_close;
+
+#[test]
+fn test_cholesky() {
+    let a = Matrix::new([[4.0, 2.0, -2.0], [2.0, 10.0, 2.0], [-2.0, 2.0, 5.0]]);
+    let cholesky = a.cholesky().unwrap();
+    let l = cholesky.lower();
+    assert_eq!(
+        l,
+        Matrix::new([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-1.0, 1.0, 1.732_050_8]])
+    );
+    assert_close(l * l.transpose(), a, 1e-5);
+    assert!((cholesky.determinant() - a.determinant()).abs() < 1e-3);
+
+    let x = cholesky.solve(&[2.0, 14.0, 5.0]);
+    assert_close(
+        a * Matrix::new(x.map(|x| [x])),
+        Matrix::new([[2.0], [14.0], [5.0]]),
+        1e-5,
+    );
+    assert_close(cholesky.inverse() * a, Matrix::IDENTITY, 1e-5);
+    assert_eq!(cholesky.checked_solve(&[2.0, 14.0, 5.0]), Ok(x));
+}
+
+#[test]
+fn test_cholesky_rejects_indefinite() {
+    assert!(Matrix::new([[1.0, 2.0], [2.0, 1.0]]).cholesky().is_none());
+    assert!(Matrix::new([[0.0, 0.0], [0.0, 1.0]]).cholesky().is_none());
+    assert!(
+        Matrix::new([[f32::NAN, 0.0], [0.0, 1.0]])
+            .cholesky()
+            .is_none()
+    );
+    // Only the lower triangle is read.
+    let a = Matrix::new([[4.0, 100.0], [2.0, 2.0]]);
+    assert_eq!(
+        a.cholesky().unwrap().lower(),
+        Matrix::new([[2.0, 0.0], [1.0, 1.0]])
+    );
+}
+
+#[test]
+fn test_qr() {
+    let a = Matrix::new([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+    let qr = a.qr();
+    let (q, r) = (qr.q(), qr.r());
+    assert!((q * r - a).max_norm() < 1e-4);
+    assert_close(q.transpose() * q, Matrix::IDENTITY, 1e-5);
+    assert!(r.is_upper_triangular());
+    let magnitudes = Matrix::new([[14.0, 21.0, 14.0], [0.0, 175.0, 70.0], [0.0, 0.0, 35.0]]);
+    assert!((r.map(|x| x.abs()) - magnitudes).max_norm() < 1e-4);
+
+    let x = qr.solve(&[1.0, 2.0, 3.0]);
+    assert_eq!(qr.checked_solve(&[1.0, 2.0, 3.0]), Ok(x));
+    let expected = a.lu().unwrap().solve(&[1.0, 2.0, 3.0]);
+    for (x, e) in x.iter().zip(expected) {
+        assert!((x - e).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_qr_least_squares() {
+    // The line through (0, 1), (1, 2), (2, 2), (3, 4) that minimizes the
+    // squared vertical distances is y = 0.9 + 0.9 t.
+    let a = Matrix::new([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    let qr = a.qr();
+    assert_close(qr.q().transpose() * qr.q(), Matrix::IDENTITY, 1e-5);
+    assert_close(qr.q() * qr.r(), a, 1e-5);
+    let x = qr.solve(&[1.0, 2.0, 2.0, 4.0]);
+    assert!(
+        (x[0] - 0.9).abs() < 1e-5 && (x[1] - 0.9).abs() < 1e-5,
+        "{:?}",
+        x
+    );
+
+    let b = [1.0, 1.0, 1.0];
+    let zero_column = Matrix::new([[1.0, 0.0], [2.0, 0.0], [0.0, 0.0]]);
+    assert_eq!(zero_column.qr().checked_solve(&b), Err(MathError::Singular));
+}
+
+#[test]
+#[should_panic(expected = "at least as many rows as columns")]
+fn test_qr_wide() {
+    let _ = Matrix::<2, 3>::ZERO.qr();
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "std")]
+
+use minmath::linear_algebra::{dmatrix::DMatrix, matrix::Matrix};
+
+fn sample(rows: usize, columns: usize) -> DMatrix {
+    DMatrix::from_fn(rows, columns, |r, c| ((r * 7 + c * 3) % 11) as f32 - 5.0)
+}
+
+#[test]
+fn test_new_and_indexing() {
+    let m = DMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(m.size(), (2, 3));
+    assert_eq!(m[0][2], 3.0);
+    assert_eq!(m[1][0], 4.0);
+}
+
+#[test]
+#[should_panic]
+fn test_new_wrong_length() {
+    DMatrix::new(2, 2, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_from_matrix() {
+    let m = DMatrix::from(Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+    assert_eq!(m, DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+}
+
+#[test]
+fn test_add_sub() {
+    let a = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    let b = DMatrix::new(2, 2, vec![5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(&a + &b, DMatrix::new(2, 2, vec![6.0, 8.0, 10.0, 12.0]));
+    assert_eq!(&b - &a, DMatrix::new(2, 2, vec![4.0, 4.0, 4.0, 4.0]));
+}
+
+#[test]
+fn test_multiplication_matches_matrix() {
+    let a = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let b = Matrix::new([[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]]);
+    let product = &DMatrix::from(a) * &DMatrix::from(b);
+    assert_eq!(product, DMatrix::from(a * b));
+}
+
+#[test]
+fn test_empty_products() {
+    assert_eq!(
+        &DMatrix::zeros(3, 2) * &DMatrix::zeros(2, 0),
+        DMatrix::zeros(3, 0)
+    );
+    assert_eq!(
+        &DMatrix::zeros(0, 2) * &DMatrix::zeros(2, 3),
+        DMatrix::zeros(0, 3)
+    );
+    assert_eq!(
+        &DMatrix::zeros(2, 0) * &DMatrix::zeros(0, 2),
+        DMatrix::zeros(2, 2)
+    );
+    assert_eq!(
+        DMatrix::zeros(3, 2).checked_mul(&DMatrix::zeros(2, 0)),
+        Ok(DMatrix::zeros(3, 0))
+    );
+}
+
+#[test]
+fn test_transpose_and_map() {
+    let m = DMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        m.transpose(),
+        DMatrix::new(3, 2, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0])
+    );
+    assert_eq!((&m * 2.0), m.map(|v| v + v));
+}
+
+#[test]
+fn test_lu_reconstructs_matrix() {
+    let m = &sample(6, 6) + &(&DMatrix::identity(6) * 10.0);
+    let lu = m.lu().unwrap();
+    let pa = DMatrix::from_fn(6, 6, |r, c| m[lu.permutation()[r]][c]);
+    let reconstructed = &lu.lower() * &lu.upper();
+    for r in 0..6 {
+        for c in 0..6 {
+            assert!((pa[r][c] - reconstructed[r][c]).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_lu_determinant_and_solve() {
+    let m = DMatrix::new(3, 3, vec![2.0, 1.0, 1.0, 1.0, 3.0, 2.0, 1.0, 0.0, 0.0]);
+    let lu = m.lu().unwrap();
+    assert!((lu.determinant() - -1.0).abs() < 1e-5);
+    let x = lu.solve(&[4.0, 5.0, 6.0]);
+    let b = &m * &DMatrix::new(3, 1, x);
+    assert!((b[0][0] - 4.0).abs() < 1e-4);
+    assert!((b[1][0] - 5.0).abs() < 1e-4);
+    assert!((b[2][0] - 6.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_lu_singular() {
+    let m = DMatrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+    assert!(m.lu().is_none());
+}
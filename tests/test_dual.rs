@@ -0,0 +1,165 @@
+use minmath::{
+    dual::{Dual, HyperDual, gradient, hessian, jacobian},
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Cross, RealVector, Vector3},
+    },
+    scalar::Real,
+};
+
+mod common;
+
+use common::{arm, assert_close, rosenbrock};
+
+#[test]
+fn test_arithmetic_derivatives() {
+    let x = Dual::<1>::variable(2.0, 0);
+    let y = x * x * x - x / (x + Dual::constant(2.0));
+    assert_eq!(y.value, 7.5);
+    // 3x² - 2 / (x + 2)²
+    assert_eq!(y.gradient, [12.0 - 0.125]);
+    assert_eq!((-x).gradient, [-1.0]);
+    let mut z = x;
+    z *= x;
+    z -= Dual::constant(1.0);
+    z /= x;
+    assert_eq!(z.value, 1.5);
+    assert_eq!(z.gradient, [1.25]);
+}
+
+#[test]
+fn test_function_derivatives() {
+    let x = Dual::<1>::variable(0.5, 0);
+    let cases = [
+        (x.sin(), 0.5f32.cos()),
+        (x.cos(), -0.5f32.sin()),
+        (x.tan(), 1.0 / (0.5f32.cos() * 0.5f32.cos())),
+        (x.exp(), 0.5f32.exp()),
+        (x.ln(), 2.0),
+        (x.sqrt(), 0.5 / 0.5f32.sqrt()),
+        ((-x).abs(), 1.0),
+        (x.powi(3), 0.75),
+        (x.powi(-1), -4.0),
+    ];
+    for (y, expected) in cases {
+        assert!(
+            (y.gradient[0] - expected).abs() < 1e-5,
+            "{} {}",
+            y,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_gradient() {
+    let g = gradient(
+        |v| rosenbrock(v[0][0], v[1][0]),
+        Matrix::new([[0.5], [1.0]]),
+    );
+    assert_eq!(g, Matrix::new([[-151.0], [150.0]]));
+    let g = gradient(
+        |v| rosenbrock(v[0][0], v[1][0]),
+        Matrix::new([[1.0], [1.0]]),
+    );
+    assert_eq!(g, Matrix::ZERO);
+}
+
+#[test]
+fn test_hessian() {
+    let h = hessian(
+        |v| rosenbrock(v[0][0], v[1][0]),
+        Matrix::new([[0.5], [1.0]]),
+    );
+    assert_eq!(h, Matrix::new([[-98.0, -200.0], [-200.0, 200.0]]));
+
+    let h = hessian(
+        |v| (v[0][0] * v[1][0]).sin() + v[0][0].exp() / v[1][0],
+        Matrix::new([[0.3], [1.5]]),
+    );
+    let (x, y) = (0.3f32, 1.5f32);
+    let expected = Matrix::new([
+        [
+            -y * y * (x * y).sin() + x.exp() / y,
+            (x * y).cos() - x * y * (x * y).sin() - x.exp() / (y * y),
+        ],
+        [
+            (x * y).cos() - x * y * (x * y).sin() - x.exp() / (y * y),
+            -x * x * (x * y).sin() + 2.0 * x.exp() / (y * y * y),
+        ],
+    ]);
+    assert_close(h, expected, 1e-3);
+
+    let z = HyperDual::<1>::variable(2.0, 0);
+    let w = z.sqrt();
+    assert_eq!(w.value, 2.0f32.sqrt());
+    assert!((w.hessian[0][0] + 0.25 / (2.0 * 2.0f32.sqrt())).abs() < 1e-6);
+}
+
+#[test]
+fn test_jacobian_of_kinematic_chain() {
+    let q = Matrix::new([[0.3], [-0.4], [0.8]]);
+    let j = jacobian(arm, q);
+
+    let h = 1e-3;
+    let numeric = Matrix::from_fn(|r, c| {
+        let mut plus = q;
+        let mut minus = q;
+        plus[c][0] += h;
+        minus[c][0] -= h;
+        (arm(plus)[r][0] - arm(minus)[r][0]) / (2.0 * h)
+    });
+    assert_close(j, numeric, 1e-3);
+    // The last joint rotates about the last link, so it cannot move the tip.
+    assert_eq!(j.column::<2>(), Matrix::ZERO);
+}
+
+#[test]
+fn test_matrix_of_duals() {
+    let x = Dual::<1>::variable(3.0, 0);
+    let m = Matrix::new([[x, Dual::constant(1.0)], [Dual::constant(2.0), x]]);
+    // d/dx (x² - 2) = 2x
+    assert_eq!(m.determinant().gradient, [6.0]);
+    let inverse = m.checked_inverse().unwrap();
+    // d/dx x / (x² - 2) = -(x² + 2) / (x² - 2)²
+    assert!((inverse[0][0].value - 3.0 / 7.0).abs() < 1e-6);
+    assert!((inverse[0][0].gradient[0] + 11.0 / 49.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector_of_duals() {
+    let a = Vector3::new(
+        Dual::<2>::variable(1.0, 0),
+        Dual::variable(2.0, 1),
+        Dual::constant(0.0),
+    );
+    let b = Vector3::new(
+        Dual::constant(0.0),
+        Dual::constant(0.0),
+        Dual::constant(1.0),
+    );
+    assert_eq!(a.dot(a).gradient, [2.0, 4.0]);
+    assert_eq!(a.cross(b).x.gradient, [0.0, 1.0]);
+    let length = a.length();
+    assert_eq!(length.value, 5.0f32.sqrt());
+    assert!((length.gradient[0] - 1.0 / 5.0f32.sqrt()).abs() < 1e-6);
+    assert_eq!((a * Dual::constant(2.0)).y.gradient, [0.0, 2.0]);
+    // `f32` vectors keep their inherent methods with the traits in scope.
+    assert_eq!(Vector3::X.cross(Vector3::Y), Vector3::Z);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(Dual::<1>::variable(1.5, 0).to_string(), "1.5+1ε");
+    assert_eq!(
+        Dual {
+            value: 1.0,
+            gradient: [2.0, -3.0]
+        }
+        .to_string(),
+        "1+2ε0-3ε1"
+    );
+    assert_eq!(format!("{:.1}", Dual::<1>::constant(2.0)), "2.0+0.0ε");
+    let z = HyperDual::<1>::variable(2.0, 0);
+    assert_eq!((z * z).to_string(), "4+4ε+2εε");
+}
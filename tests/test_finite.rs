@@ -0,0 +1,114 @@
+#[cfg(feature = "std")]
+use minmath::linear_algebra::dmatrix::DMatrix;
+use minmath::{
+    error::MathError,
+    finite::{CheckFinite, Finite},
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+    },
+};
+
+#[test]
+fn test_is_finite_and_has_nan() {
+    let mut m = Matrix::<2, 2>::IDENTITY;
+    assert!(m.is_finite() && !m.has_nan());
+    m[1][0] = f32::INFINITY;
+    assert!(!m.is_finite() && !m.has_nan());
+    m[0][1] = f32::NAN;
+    assert!(m.has_nan());
+
+    assert!(Vector2::new(1.0, 2.0).is_finite());
+    assert!(Vector2::new(1.0, f32::NAN).has_nan());
+    assert!(!Vector3::new(f32::NEG_INFINITY, 0.0, 0.0).is_finite());
+    assert!(!Vector3::new(f32::NEG_INFINITY, 0.0, 0.0).has_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_dmatrix_is_finite() {
+    let d = DMatrix::new(1, 2, vec![0.0, f32::NAN]);
+    assert!(!d.is_finite() && d.has_nan());
+    assert!(DMatrix::identity(3).is_finite());
+    assert!(Finite::new(DMatrix::zeros(2, 2)).is_ok());
+}
+
+#[test]
+fn test_finite_wrapper() {
+    let transform = Finite::new(Matrix::<3, 3>::IDENTITY).unwrap();
+    assert_eq!(*transform, Matrix::IDENTITY);
+    assert_eq!(transform.get().size(), (3, 3));
+    let product = *transform * Matrix::<3, 1>::ONES;
+    assert_eq!(product, Matrix::ONES);
+    assert_eq!(transform.into_inner(), Matrix::IDENTITY);
+
+    assert_eq!(
+        Finite::new(Vector3::new(0.0, f32::NAN, 0.0)),
+        Err(MathError::NonFinite)
+    );
+    assert_eq!(Finite::new(f32::INFINITY), Err(MathError::NonFinite));
+    assert_eq!(*Finite::new(2.5f32).unwrap(), 2.5);
+}
+
+#[test]
+fn test_check_finite_is_generic() {
+    fn count_bad<T: CheckFinite>(values: &[T]) -> usize {
+        values.iter().filter(|value| !value.is_finite()).count()
+    }
+    let points = [
+        Vector2::new(0.0, 1.0),
+        Vector2::new(f32::NAN, 1.0),
+        Vector2::new(2.0, f32::INFINITY),
+    ];
+    assert_eq!(count_bad(&points), 2);
+}
+
+#[cfg(all(feature = "debug-finite", debug_assertions))]
+mod debug_finite {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "`Matrix / f32` produced a non-finite result")]
+    fn test_matrix_division_by_zero() {
+        let _ = Matrix::<2, 2>::IDENTITY / 0.0;
+    }
+
+    #[test]
+    #[should_panic(expected = "`Vector3 += Vector3`")]
+    fn test_assign_operator() {
+        let mut v = Vector3::new(f32::MAX, 0.0, 0.0);
+        v += Vector3::new(f32::MAX, 0.0, 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic(expected = "`DMatrix * DMatrix`")]
+    fn test_dmatrix_product() {
+        let m = DMatrix::new(1, 1, vec![f32::MAX]);
+        let _ = &m * &m;
+    }
+
+    #[test]
+    fn test_panic_points_at_caller() {
+        let location = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+        let recorded = location.clone();
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            *recorded.lock().unwrap() = info.location().unwrap().file().to_string();
+        }));
+        let result = std::panic::catch_unwind(|| Vector2::new(1.0, 0.0) * f32::INFINITY);
+        std::panic::set_hook(previous);
+        assert!(result.is_err());
+        assert!(location.lock().unwrap().ends_with("test_finite.rs"));
+    }
+
+    #[test]
+    fn test_checked_operations_do_not_panic() {
+        assert_eq!(
+            Vector2::ZERO.checked_normalize(),
+            Err(MathError::Domain("cannot normalize a zero vector"))
+        );
+        let huge = Matrix::<1, 1>::filled(f32::MAX);
+        assert_eq!(huge.checked_mul(&huge), Err(MathError::NonFinite));
+    }
+}
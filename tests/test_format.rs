@@ -0,0 +1,128 @@
+#[cfg(feature = "std")]
+use minmath::linear_algebra::dmatrix::DMatrix;
+use minmath::linear_algebra::{format::Style, matrix::Matrix};
+
+fn sample() -> Matrix<2, 3> {
+    Matrix::new([[1.0, -2.5, 3.0], [40.0, 0.0, 6.25]])
+}
+
+fn large() -> Matrix<6, 6> {
+    Matrix::from_fn(|r, c| (r * 6 + c) as f32)
+}
+
+#[test]
+fn test_display_is_aligned() {
+    assert_eq!(
+        sample().to_string(),
+        "Matrix (2x3):\n 1 -2.5    3\n40    0 6.25\n"
+    );
+    assert_eq!(
+        format!("{:6.2}", sample()),
+        "Matrix (2x3):\n  1.00  -2.50   3.00\n 40.00   0.00   6.25\n"
+    );
+    assert_eq!(
+        format!("{:.1}", Matrix::new([[1.0, 2.0]])),
+        "Matrix (1x2):\n1.0 2.0\n"
+    );
+}
+
+#[test]
+fn test_display_round_trips() {
+    let m = sample();
+    assert_eq!(m.to_string().parse::<Matrix<2, 3>>(), Ok(m));
+    assert_eq!(format!("{:10}", m).parse::<Matrix<2, 3>>(), Ok(m));
+}
+
+#[test]
+fn test_box_and_brackets() {
+    let m = Matrix::new([[1.0, 2.0], [3.0, 40.0]]);
+    assert_eq!(
+        m.format().style(Style::Box).to_string(),
+        "┌      ┐\n│ 1  2 │\n│ 3 40 │\n└      ┘\n"
+    );
+    let m3 = Matrix::<3, 1>::new([[1.0], [2.0], [3.0]]);
+    assert_eq!(
+        m3.format().style(Style::Brackets).to_string(),
+        "⎡ 1 ⎤\n⎢ 2 ⎥\n⎣ 3 ⎦\n"
+    );
+    assert_eq!(
+        Matrix::new([[1.0, 2.0]])
+            .format()
+            .style(Style::Brackets)
+            .to_string(),
+        "[ 1 2 ]\n"
+    );
+}
+
+#[test]
+fn test_elision() {
+    let formatted = large().format().max_rows(4).max_columns(3).to_string();
+    assert_eq!(
+        formatted,
+        "Matrix (6x6):\n 0  1 …  5\n 6  7 … 11\n …  … …  …\n24 25 … 29\n30 31 … 35\n"
+    );
+    let latex = large()
+        .format()
+        .style(Style::Latex)
+        .max_rows(2)
+        .max_columns(2)
+        .to_string();
+    assert_eq!(
+        latex,
+        "\\begin{bmatrix}\n     0 & \\cdots &      5 \\\\\n\\vdots & \\ddots & \\vdots \\\\\n    30 & \\cdots &     35\n\\end{bmatrix}\n"
+    );
+}
+
+#[test]
+fn test_latex() {
+    assert_eq!(
+        sample()
+            .format()
+            .style(Style::Latex)
+            .precision(1)
+            .to_string(),
+        "\\begin{bmatrix}\n 1.0 & -2.5 & 3.0 \\\\\n40.0 &  0.0 & 6.2\n\\end{bmatrix}\n"
+    );
+}
+
+#[test]
+fn test_markdown() {
+    assert_eq!(
+        sample().format().style(Style::Markdown).to_string(),
+        "|  0 |    1 |    2 |\n|---:|-----:|-----:|\n|  1 | -2.5 |    3 |\n| 40 |    0 | 6.25 |\n"
+    );
+}
+
+#[test]
+fn test_rust_literal() {
+    let m = Matrix::new([[1.0, f32::NAN], [-0.5, f32::NEG_INFINITY]]);
+    assert_eq!(
+        m.format().style(Style::Rust).max_rows(1).to_string(),
+        "Matrix::new([\n    [ 1.0,          f32::NAN],\n    [-0.5, f32::NEG_INFINITY],\n])\n"
+    );
+    assert_eq!(
+        Matrix::new([[1.0, 2.0]])
+            .format()
+            .style(Style::Rust)
+            .precision(0)
+            .to_string(),
+        "Matrix::new([\n    [1., 2.],\n])\n"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dmatrix_rust_literal() {
+    let d = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.5]);
+    assert_eq!(
+        d.format().style(Style::Rust).to_string(),
+        "DMatrix::new(2, 2, vec![\n    1.0, 2.0,\n    3.0, 4.5,\n])\n"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dmatrix_display() {
+    let d = DMatrix::new(2, 2, vec![1.0, 2.0, 30.0, 4.0]);
+    assert_eq!(d.to_string(), "DMatrix (2x2):\n 1 2\n30 4\n");
+}
@@ -0,0 +1,164 @@
+use minmath::{error::MathError, linear_algebra::matrix::Matrix};
+
+mod common;
+
+use common::assert_close;
+
+fn diagonal<const N: usize>(values: [f32; N]) -> Matrix<N, N> {
+    Matrix::from_fn(|r, c| if r == c { values[r] } else { 0.0 })
+}
+
+#[test]
+fn test_expm_of_diagonal_and_nilpotent_matrices() {
+    assert_eq!(Matrix::<3, 3>::ZERO.expm(), Matrix::IDENTITY);
+    let d = diagonal([1.0, -2.0, 0.5]);
+    assert_close(
+        d.expm(),
+        diagonal([1.0f32.exp(), (-2.0f32).exp(), 0.5f32.exp()]),
+        1e-6,
+    );
+    let nilpotent = Matrix::new([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+    let expected = Matrix::new([[1.0, 1.0, 0.5], [0.0, 1.0, 1.0], [0.0, 0.0, 1.0]]);
+    assert_close(nilpotent.expm(), expected, 1e-6);
+}
+
+#[test]
+fn test_expm_of_rotation_generator() {
+    let theta = 2.5;
+    let generator = Matrix::new([[0.0, -theta], [theta, 0.0]]);
+    assert_close(
+        generator.expm(),
+        Matrix::<2, 2>::rotation_matrix2x2(theta),
+        1e-6,
+    );
+}
+
+#[test]
+fn test_expm_of_large_argument() {
+    let a = Matrix::new([[-49.0, 24.0], [-64.0, 31.0]]);
+    // Eigenvalues -1 and -17; the exact result, from Moler and Van Loan.
+    let expected = Matrix::new([[-0.735_759, 0.551_819], [-1.471_518, 1.103_638]]);
+    assert_close(a.expm(), expected, 1e-5);
+}
+
+#[test]
+fn test_expm_inverse() {
+    let a = Matrix::new([[0.3, -1.2, 0.4], [0.9, 0.1, -0.5], [-0.2, 0.7, 0.6]]);
+    assert_close(a.expm() * (a * -1.0).expm(), Matrix::IDENTITY, 1e-5);
+}
+
+#[test]
+fn test_sqrtm() {
+    assert_close(
+        diagonal([4.0, 9.0, 0.25]).sqrtm().unwrap(),
+        diagonal([2.0, 3.0, 0.5]),
+        1e-6,
+    );
+    let spd = Matrix::new([[5.0, 2.0, 0.0], [2.0, 4.0, 1.0], [0.0, 1.0, 3.0]]);
+    let root = spd.sqrtm().unwrap();
+    assert_close(root * root, spd, 1e-5);
+    assert_close(root, root.transpose(), 1e-5);
+
+    let nonsymmetric = Matrix::new([[4.0, 1.0], [0.5, 2.0]]);
+    let root = nonsymmetric.sqrtm().unwrap();
+    assert_close(root * root, nonsymmetric, 1e-5);
+}
+
+#[test]
+fn test_sqrtm_errors() {
+    assert_eq!(
+        Matrix::new([[1.0, 2.0], [2.0, 4.0]]).sqrtm(),
+        Err(MathError::Singular)
+    );
+    assert!(diagonal([-1.0, 4.0]).sqrtm().is_err());
+    assert_eq!(diagonal([f32::NAN, 1.0]).sqrtm(), Err(MathError::NonFinite));
+}
+
+#[test]
+fn test_logm() {
+    assert_eq!(Matrix::<3, 3>::IDENTITY.logm(), Ok(Matrix::ZERO));
+    let e = 1.0f32.exp();
+    assert_close(
+        diagonal([e, e * e, 1.0 / e]).logm().unwrap(),
+        diagonal([1.0, 2.0, -1.0]),
+        1e-5,
+    );
+    let theta = 1.2;
+    let rotation = Matrix::<2, 2>::rotation_matrix2x2(theta);
+    assert_close(
+        rotation.logm().unwrap(),
+        Matrix::new([[0.0, -theta], [theta, 0.0]]),
+        1e-5,
+    );
+}
+
+#[test]
+fn test_logm_inverts_expm() {
+    let a = Matrix::new([[0.3, -1.2, 0.4], [0.9, 0.1, -0.5], [-0.2, 0.7, 0.6]]);
+    assert_close(a.expm().logm().unwrap(), a, 1e-5);
+    let b = Matrix::new([[6.0, 1.0], [2.0, 3.0]]);
+    assert_close(b.logm().unwrap().expm(), b, 1e-5);
+}
+
+#[test]
+fn test_logm_errors() {
+    assert_eq!(Matrix::<2, 2>::ZERO.logm(), Err(MathError::Singular));
+    assert!(diagonal([-2.0, 1.0]).logm().is_err());
+}
+
+#[test]
+fn test_pow() {
+    let a = Matrix::new([[1.0, 1.0], [1.0, 0.0]]);
+    assert_eq!(a.pow(0), Matrix::IDENTITY);
+    assert_eq!(a.pow(1), a);
+    // Fibonacci numbers.
+    assert_eq!(a.pow(10), Matrix::new([[89.0, 55.0], [55.0, 34.0]]));
+    let b = Matrix::new([[0.5, -1.0, 2.0], [1.0, 0.0, 0.25], [-0.5, 1.0, 1.0]]);
+    assert_close(b.pow(5), b * b * b * b * b, 1e-5);
+}
+
+#[test]
+fn test_checked_powi() {
+    let a = Matrix::new([[2.0, 1.0], [1.0, 1.0]]);
+    let inverse = a.checked_inverse().unwrap();
+    assert_close(
+        a.checked_powi(-3).unwrap(),
+        inverse * inverse * inverse,
+        1e-5,
+    );
+    assert_eq!(a.checked_powi(2), Ok(a * a));
+    let singular = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    assert_eq!(singular.checked_powi(-1), Err(MathError::Singular));
+    assert_eq!(singular.checked_powi(0), Ok(Matrix::IDENTITY));
+}
+
+#[test]
+fn test_powf() {
+    let d = diagonal([4.0, 9.0]);
+    assert_close(d.powf(1.5).unwrap(), diagonal([8.0, 27.0]), 1e-5);
+    assert_close(d.powf(-0.5).unwrap(), diagonal([0.5, 1.0 / 3.0]), 1e-5);
+
+    // A diagonalizable matrix with eigenvalues 2 and 5.
+    let a = Matrix::new([[4.0, 1.0], [2.0, 3.0]]);
+    assert_close(a.powf(0.5).unwrap(), a.sqrtm().unwrap(), 1e-5);
+    let cube_root = a.powf(1.0 / 3.0).unwrap();
+    assert_close(cube_root * cube_root * cube_root, a, 1e-5);
+
+    // Integer exponents work with negative eigenvalues.
+    let negative = diagonal([-2.0, 3.0]);
+    assert_eq!(negative.powf(2.0), Ok(diagonal([4.0, 9.0])));
+    assert!(negative.powf(0.5).is_err());
+    assert!(matches!(a.powf(f32::NAN), Err(MathError::Domain(_))));
+}
+
+#[test]
+fn test_powf_with_integer_exponents_beyond_i32() {
+    let swap = Matrix::new([[0.0, 1.0], [1.0, 0.0]]);
+    assert_eq!(swap.powf(2147483648.0), Ok(Matrix::IDENTITY));
+    assert_eq!(swap.powf(3e9), Ok(Matrix::IDENTITY));
+    assert_eq!(swap.powf(-3e9), Ok(Matrix::IDENTITY));
+    assert_eq!(swap.powf(f32::MAX), Ok(Matrix::IDENTITY));
+    let rotation = Matrix::new([[0.0, -1.0], [1.0, 0.0]]);
+    assert_eq!(rotation.powf(4e9 + 512.0), Ok(Matrix::IDENTITY));
+    assert_eq!(Matrix::<2, 2>::ZERO.powf(-3e9), Err(MathError::Singular));
+}
@@ -0,0 +1,200 @@
+#![cfg(feature = "std")]
+
+use minmath::{
+    dual,
+    least_squares::{LeastSquaresOptions, gauss_newton, levenberg_marquardt},
+    linear_algebra::matrix::Matrix,
+    optimize::Termination,
+    scalar::Real,
+};
+
+const T: [f32; 8] = [0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+
+// `y = a e^(-b t) + c`.
+fn decay<R: Real>(p: Matrix<3, 1, R>, t: f32) -> R {
+    p[0][0] * (-p[1][0] * R::from_f32(t)).exp() + p[2][0]
+}
+
+fn decay_residuals<R: Real>(p: Matrix<3, 1, R>, y: &[f32; 8]) -> Matrix<8, 1, R> {
+    Matrix::from_fn(|i, _| decay(p, T[i]) - R::from_f32(y[i]))
+}
+
+const TRUE: Matrix<3, 1> = Matrix::new([[3.0], [0.7], [0.5]]);
+const START: Matrix<3, 1> = Matrix::new([[1.0], [0.1], [0.0]]);
+
+#[test]
+fn test_levenberg_marquardt_exact_fit() {
+    let y = T.map(|t| decay(TRUE, t));
+    let options = LeastSquaresOptions::default();
+    let mut jacobian = |p: &Matrix<3, 1>| dual::jacobian(|p| decay_residuals(p, &y), *p);
+    let analytic = levenberg_marquardt(
+        |p| decay_residuals(*p, &y),
+        Some(&mut jacobian),
+        START,
+        options,
+    );
+    let numeric = levenberg_marquardt(|p| decay_residuals(*p, &y), None, START, options);
+    for fit in [analytic, numeric] {
+        assert!(fit.converged(), "{:?}", fit);
+        assert!((fit.x - TRUE).max_norm() < 1e-3, "{:?}", fit);
+        assert!(fit.cost < 1e-8);
+        assert!(fit.residuals.max_norm() < 1e-4);
+    }
+    // Every step was evaluated once, and central differences take two
+    // evaluations per parameter.
+    assert_eq!(analytic.evaluations, analytic.iterations + 1);
+    assert_eq!(
+        numeric.evaluations,
+        numeric.iterations + 1 + 6 * numeric.jacobian_evaluations
+    );
+}
+
+#[test]
+fn test_gauss_newton() {
+    let y = T.map(|t| decay(TRUE, t));
+    let mut jacobian = |p: &Matrix<3, 1>| dual::jacobian(|p| decay_residuals(p, &y), *p);
+    // Gauss–Newton needs a reasonable start.
+    let start = Matrix::new([[2.5], [0.5], [0.3]]);
+    let fit = gauss_newton(
+        |p| decay_residuals(*p, &y),
+        Some(&mut jacobian),
+        start,
+        LeastSquaresOptions::default(),
+    );
+    assert!(fit.converged(), "{:?}", fit);
+    assert!((fit.x - TRUE).max_norm() < 1e-3, "{:?}", fit);
+    assert!(fit.iterations < 20);
+
+    let fit = gauss_newton(
+        |p| decay_residuals(*p, &y),
+        None,
+        start,
+        LeastSquaresOptions::default(),
+    );
+    assert!((fit.x - TRUE).max_norm() < 1e-3, "{:?}", fit);
+}
+
+#[test]
+fn test_linear_regression_covariance() {
+    // For a linear model the fit is ordinary least squares, and the
+    // covariance is `s² (XᵀX)⁻¹` exactly.
+    let t = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = [1.1, 2.9, 5.2, 6.8, 9.1, 10.9];
+    let residuals =
+        |p: &Matrix<2, 1>| Matrix::<6, 1>::from_fn(|i, _| p[0][0] + p[1][0] * t[i] - y[i]);
+    let fit = levenberg_marquardt(
+        residuals,
+        None,
+        Matrix::ZERO,
+        LeastSquaresOptions::default(),
+    );
+    assert!(fit.converged(), "{:?}", fit);
+
+    let x = Matrix::<6, 2>::from_fn(|i, j| if j == 0 { 1.0 } else { t[i] });
+    let b = Matrix::new(y.map(|y| [y]));
+    let ols = Matrix::new(x.qr().solve(&y).map(|p| [p]));
+    assert!((fit.x - ols).max_norm() < 1e-4, "{} {}", fit.x, ols);
+    let r = x * ols - b;
+    let variance = r.iter().map(|r| r * r).sum::<f32>() / 4.0;
+    let expected = (x.transpose() * x).checked_inverse().unwrap() * variance;
+    let covariance = fit.covariance.unwrap();
+    assert!(
+        (covariance - expected).max_norm() < 1e-4,
+        "{}{}",
+        covariance,
+        expected
+    );
+    let errors = fit.standard_errors().unwrap();
+    assert!((errors[1][0] - expected[1][1].sqrt()).abs() < 1e-4);
+}
+
+#[test]
+fn test_camera_calibration() {
+    // A pinhole camera with focal length `f` and principal point (cx, cy)
+    // projects the point (X, Y, Z) to (f X / Z + cx, f Y / Z + cy).
+    let points = [
+        [0.1, 0.2, 2.0],
+        [-0.3, 0.1, 2.5],
+        [0.4, -0.2, 3.0],
+        [-0.2, -0.4, 1.5],
+        [0.0, 0.3, 4.0],
+    ];
+    let project = |camera: Matrix<3, 1>| -> Matrix<10, 1> {
+        Matrix::from_fn(|i, _| {
+            let [x, y, z] = points[i / 2];
+            let coordinate = if i % 2 == 0 { x } else { y };
+            camera[0][0] * coordinate / z + camera[1 + i % 2][0]
+        })
+    };
+    let truth = Matrix::new([[800.0], [320.0], [240.0]]);
+    let observed = project(truth);
+    let fit = levenberg_marquardt(
+        |camera| project(*camera) - observed,
+        None,
+        Matrix::new([[500.0], [300.0], [200.0]]),
+        LeastSquaresOptions::default(),
+    );
+    assert!(fit.converged(), "{:?}", fit);
+    assert!((fit.x - truth).max_norm() < 1e-2, "{}", fit.x);
+}
+
+#[test]
+fn test_rosenbrock() {
+    // ½‖r‖² is half the Rosenbrock function.
+    let residuals =
+        |p: &Matrix<2, 1>| Matrix::new([[10.0 * (p[1][0] - p[0][0] * p[0][0])], [1.0 - p[0][0]]]);
+    let mut jacobian = |p: &Matrix<2, 1>| Matrix::new([[-20.0 * p[0][0], 10.0], [-1.0, 0.0]]);
+    let fit = levenberg_marquardt(
+        residuals,
+        Some(&mut jacobian),
+        Matrix::new([[-1.2], [1.0]]),
+        LeastSquaresOptions::default(),
+    );
+    assert!(fit.converged(), "{:?}", fit);
+    assert!((fit.x - Matrix::new([[1.0], [1.0]])).max_norm() < 1e-4);
+    // Two residuals for two parameters leave no degrees of freedom.
+    assert_eq!(fit.covariance, None);
+}
+
+#[test]
+fn test_termination() {
+    let y = T.map(|t| decay(TRUE, t));
+    let options = LeastSquaresOptions {
+        max_iterations: 2,
+        ..LeastSquaresOptions::default()
+    };
+    let fit = levenberg_marquardt(|p| decay_residuals(*p, &y), None, START, options);
+    assert_eq!(fit.termination, Termination::MaxIterations);
+    assert_eq!(fit.iterations, 2);
+    assert!(!fit.converged());
+
+    // Only the sum of the parameters matters, so the columns of the
+    // Jacobian are equal.
+    let residuals =
+        |p: &Matrix<2, 1>| Matrix::<3, 1>::from_fn(|i, _| (p[0][0] + p[1][0]) * i as f32 - 1.0);
+    let fit = gauss_newton(
+        residuals,
+        None,
+        Matrix::ZERO,
+        LeastSquaresOptions::default(),
+    );
+    assert_eq!(fit.termination, Termination::Singular);
+    // The damping keeps Levenberg–Marquardt going.
+    let fit = levenberg_marquardt(
+        residuals,
+        None,
+        Matrix::ZERO,
+        LeastSquaresOptions::default(),
+    );
+    assert!(fit.converged(), "{:?}", fit);
+    assert!((fit.x[0][0] + fit.x[1][0] - 0.6).abs() < 1e-4);
+    assert_eq!(fit.covariance, None);
+
+    let fit = levenberg_marquardt(
+        |p: &Matrix<1, 1>| Matrix::<2, 1>::new([[p[0][0].ln()], [0.0]]),
+        None,
+        Matrix::new([[-1.0]]),
+        LeastSquaresOptions::default(),
+    );
+    assert_eq!(fit.termination, Termination::NonFinite);
+}
@@ -0,0 +1,119 @@
+use minmath::math::poly;
+
+fn ulp_distance(a: f32, b: f32) -> u32 {
+    if a.is_nan() && b.is_nan() {
+        return 0;
+    }
+    if a == b {
+        return 0;
+    }
+    let to_ordered = |v: f32| {
+        let bits = v.to_bits() as i32;
+        if bits < 0 { i32::MIN - bits } else { bits }
+    };
+    to_ordered(a).abs_diff(to_ordered(b))
+}
+
+fn sweep(start: f32, end: f32, steps: usize) -> impl Iterator<Item = f32> {
+    (0..=steps).map(move |i| start + (end - start) * i as f32 / steps as f32)
+}
+
+fn assert_within_one_ulp(
+    name: &str,
+    approx: impl Fn(f32) -> f32,
+    exact: impl Fn(f32) -> f32,
+    inputs: impl Iterator<Item = f32>,
+) {
+    for x in inputs {
+        let (a, e) = (approx(x), exact(x));
+        assert!(ulp_distance(a, e) <= 1, "{name}({x}) = {a}, expected {e}");
+    }
+}
+
+#[test]
+fn test_sin_cos_tan_within_one_ulp() {
+    assert_within_one_ulp("sin", poly::sin, f32::sin, sweep(-100.0, 100.0, 200_000));
+    assert_within_one_ulp("cos", poly::cos, f32::cos, sweep(-100.0, 100.0, 200_000));
+    assert_within_one_ulp("tan", poly::tan, f32::tan, sweep(-10.0, 10.0, 100_000));
+    assert_within_one_ulp("sin", poly::sin, f32::sin, sweep(-1.0e6, 1.0e6, 100_000));
+    assert_within_one_ulp("sin", poly::sin, f32::sin, sweep(-1.0e-3, 1.0e-3, 10_000));
+}
+
+#[test]
+fn test_sin_cos_tan_of_huge_arguments() {
+    // Every 4099th float from 2^20 up to f32::MAX, and their negations.
+    let huge = || {
+        (0x4980_0000..0x7f80_0000u32)
+            .step_by(4099)
+            .map(f32::from_bits)
+            .flat_map(|x| [x, -x])
+    };
+    for x in huge() {
+        assert!((-1.0..=1.0).contains(&poly::sin(x)), "sin({x})");
+        assert!((-1.0..=1.0).contains(&poly::cos(x)), "cos({x})");
+    }
+    assert_within_one_ulp("sin", poly::sin, f32::sin, huge());
+    assert_within_one_ulp("cos", poly::cos, f32::cos, huge());
+    assert_within_one_ulp("tan", poly::tan, f32::tan, huge());
+    assert_within_one_ulp(
+        "sin",
+        poly::sin,
+        f32::sin,
+        [1.0e19, 1.0e30, f32::MAX].into_iter(),
+    );
+}
+
+#[test]
+fn test_atan2_within_one_ulp() {
+    for y in sweep(-50.0, 50.0, 400) {
+        assert_within_one_ulp(
+            "atan2",
+            |x| poly::atan2(y, x),
+            |x| y.atan2(x),
+            sweep(-50.0, 50.0, 400),
+        );
+    }
+    assert_eq!(poly::atan2(0.0, -1.0), std::f32::consts::PI);
+    assert_eq!(poly::atan2(-0.0, 1.0).to_bits(), (-0.0f32).to_bits());
+    assert_eq!(poly::atan2(1.0, 0.0), std::f32::consts::FRAC_PI_2);
+    assert_eq!(
+        poly::atan2(f32::INFINITY, f32::NEG_INFINITY),
+        3.0 * std::f32::consts::FRAC_PI_4
+    );
+    assert!(poly::atan2(f32::NAN, 1.0).is_nan());
+}
+
+#[test]
+fn test_sqrt_within_one_ulp() {
+    assert_within_one_ulp("sqrt", poly::sqrt, f32::sqrt, sweep(0.0, 1000.0, 100_000));
+    assert_within_one_ulp(
+        "sqrt",
+        poly::sqrt,
+        f32::sqrt,
+        [f32::MIN_POSITIVE, 1.0e-40, f32::MAX, 0.0, f32::INFINITY].into_iter(),
+    );
+    assert!(poly::sqrt(-1.0).is_nan());
+}
+
+#[test]
+fn test_exp_ln_within_one_ulp() {
+    assert_within_one_ulp("exp", poly::exp, f32::exp, sweep(-103.0, 88.0, 200_000));
+    assert_within_one_ulp("ln", poly::ln, f32::ln, sweep(1.0e-6, 1000.0, 200_000));
+    assert_within_one_ulp(
+        "ln",
+        poly::ln,
+        f32::ln,
+        [1.0e-40, f32::MIN_POSITIVE, f32::MAX, 1.0, f32::INFINITY].into_iter(),
+    );
+    assert_eq!(poly::exp(100.0), f32::INFINITY);
+    assert_eq!(poly::exp(-200.0), 0.0);
+    assert_eq!(poly::ln(0.0), f32::NEG_INFINITY);
+    assert!(poly::ln(-1.0).is_nan());
+}
+
+#[test]
+fn test_non_finite_trigonometry() {
+    assert!(poly::sin(f32::INFINITY).is_nan());
+    assert!(poly::cos(f32::NAN).is_nan());
+    assert!(poly::tan(f32::NEG_INFINITY).is_nan());
+}
@@ -264,3 +264,167 @@ fn test_vector3_rotation_z_axis_90_degrees() {
     assert!((result.y - 1.0).abs() < 1e-5);
     assert!((result.z - 0.0).abs() < 1e-5);
 }
+
+#[test]
+fn test_from_fn() {
+    let m = Matrix::<2, 3>::from_fn(|r, c| (r * 3 + c) as f32);
+    assert_eq!(m, Matrix::new([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+}
+
+#[test]
+fn test_map_and_zip_map() {
+    let a = Matrix::new([[-1.0, 2.0], [-3.0, 4.0]]);
+    let b = Matrix::new([[5.0, 6.0], [7.0, 8.0]]);
+    assert_eq!(a.map(f32::abs), Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+    assert_eq!(
+        a.map(|v| v.clamp(0.0, 3.0)),
+        Matrix::new([[0.0, 2.0], [0.0, 3.0]])
+    );
+    assert_eq!(
+        a.zip_map(&b, f32::max),
+        Matrix::new([[5.0, 6.0], [7.0, 8.0]])
+    );
+    assert_eq!(
+        a.zip_map(&b, |x, y| x * y),
+        Matrix::new([[-5.0, 12.0], [-21.0, 32.0]])
+    );
+}
+
+#[test]
+fn test_fold_and_iter() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(m.fold(0.0, |acc, v| acc + v), 21.0);
+    assert_eq!(
+        m.iter().copied().collect::<Vec<_>>(),
+        vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+    );
+    assert_eq!(m.iter().cloned().fold(f32::MIN, f32::max), 6.0);
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut m = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    m.iter_mut().for_each(|v| *v *= 10.0);
+    assert_eq!(m, Matrix::new([[10.0, 20.0], [30.0, 40.0]]));
+}
+
+#[test]
+fn test_enumerate() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let positions: Vec<_> = m.enumerate().map(|(pos, &v)| (pos, v)).collect();
+    assert_eq!(positions[0], ((0, 0), 1.0));
+    assert_eq!(positions[2], ((0, 2), 3.0));
+    assert_eq!(positions[4], ((1, 1), 5.0));
+    assert_eq!(positions.len(), 6);
+}
+
+#[test]
+fn test_rows_and_columns() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let rows: Vec<_> = m.rows().collect();
+    assert_eq!(rows, vec![&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]);
+    let columns: Vec<_> = m.columns().collect();
+    assert_eq!(columns, vec![[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+}
+
+#[test]
+fn test_row_and_column() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(m.row::<1>(), Matrix::new([[4.0, 5.0, 6.0]]));
+    assert_eq!(m.column::<2>(), Matrix::new([[3.0], [6.0]]));
+}
+
+#[test]
+fn test_submatrix() {
+    let transform = Matrix::<4, 4>::from_fn(|r, c| (r * 4 + c) as f32);
+    let rotation: Matrix<3, 3> = transform.submatrix::<0, 0, 3, 3>();
+    assert_eq!(
+        rotation,
+        Matrix::new([[0.0, 1.0, 2.0], [4.0, 5.0, 6.0], [8.0, 9.0, 10.0]])
+    );
+    let translation: Matrix<3, 1> = transform.submatrix::<0, 3, 3, 1>();
+    assert_eq!(translation, Matrix::new([[3.0], [7.0], [11.0]]));
+}
+
+#[test]
+fn test_set_block() {
+    let mut m = Matrix::<3, 4>::ZERO;
+    m.set_block::<1, 2, 2, 2>(&Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+    assert_eq!(
+        m,
+        Matrix::new([
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 2.0],
+            [0.0, 0.0, 3.0, 4.0]
+        ])
+    );
+}
+
+#[test]
+fn test_hstack_and_vstack() {
+    let a = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Matrix::new([[5.0], [6.0]]);
+    let wide: Matrix<2, 3> = a.hstack(&b);
+    assert_eq!(wide, Matrix::new([[1.0, 2.0, 5.0], [3.0, 4.0, 6.0]]));
+    let tall: Matrix<3, 2> = a.vstack(&Matrix::new([[7.0, 8.0]]));
+    assert_eq!(tall, Matrix::new([[1.0, 2.0], [3.0, 4.0], [7.0, 8.0]]));
+}
+
+#[test]
+fn test_kronecker() {
+    let a = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Matrix::new([[0.0, 5.0], [6.0, 7.0]]);
+    let product: Matrix<4, 4> = a.kronecker(&b);
+    assert_eq!(
+        product,
+        Matrix::new([
+            [0.0, 5.0, 0.0, 10.0],
+            [6.0, 7.0, 12.0, 14.0],
+            [0.0, 15.0, 0.0, 20.0],
+            [18.0, 21.0, 24.0, 28.0],
+        ])
+    );
+    let column = Matrix::new([[1.0], [-1.0]]);
+    let wide: Matrix<2, 3> = column.kronecker(&Matrix::new([[1.0, 2.0, 3.0]]));
+    assert_eq!(wide, Matrix::new([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]));
+    let identity: Matrix<6, 6> = Matrix::<2, 2>::IDENTITY.kronecker(&Matrix::<3, 3>::IDENTITY);
+    assert_eq!(identity, Matrix::IDENTITY);
+}
+
+#[test]
+fn test_hadamard() {
+    let a = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Matrix::new([[2.0, 0.5], [-1.0, 4.0]]);
+    assert_eq!(a.hadamard(&b), Matrix::new([[2.0, 1.0], [-3.0, 16.0]]));
+    assert_eq!(a.hadamard_div(&b), Matrix::new([[0.5, 4.0], [-3.0, 1.0]]));
+    let quotient = a.hadamard_div(&Matrix::new([[0.0, 1.0], [1.0, 1.0]]));
+    assert_eq!(quotient[0][0], f32::INFINITY);
+}
+
+#[test]
+fn test_swap_rows_and_columns() {
+    let mut m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    m.swap_rows(0, 1);
+    assert_eq!(m, Matrix::new([[4.0, 5.0, 6.0], [1.0, 2.0, 3.0]]));
+    m.swap_columns(0, 2);
+    assert_eq!(m, Matrix::new([[6.0, 5.0, 4.0], [3.0, 2.0, 1.0]]));
+}
+
+#[test]
+fn test_remove_row_and_column() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let without_row: Matrix<2, 3> = m.remove_row(1);
+    assert_eq!(without_row, Matrix::new([[1.0, 2.0, 3.0], [7.0, 8.0, 9.0]]));
+    let without_column: Matrix<3, 2> = m.remove_column(0);
+    assert_eq!(
+        without_column,
+        Matrix::new([[2.0, 3.0], [5.0, 6.0], [8.0, 9.0]])
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_remove_row_out_of_bounds() {
+    let m = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let _: Matrix<1, 2> = m.remove_row(2);
+}
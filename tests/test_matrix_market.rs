@@ -0,0 +1,296 @@
+#![cfg(feature = "std")]
+
+use std::{fs::File, io::BufReader};
+
+use minmath::{
+    io::matrix_market::{self, Field, Format, Header, MatrixMarketError, Symmetry},
+    linear_algebra::{dmatrix::DMatrix, matrix::Matrix},
+};
+
+fn fixture(name: &str) -> BufReader<File> {
+    let path = format!(
+        "{}/tests/fixtures/matrix_market/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    BufReader::new(File::open(path).unwrap())
+}
+
+fn read_str(text: &str) -> Result<(Header, DMatrix), MatrixMarketError> {
+    matrix_market::read(text.as_bytes())
+}
+
+fn round_trip(matrix: &DMatrix, header: Header) -> DMatrix {
+    let mut buffer = Vec::new();
+    matrix_market::write(&mut buffer, matrix, header).unwrap();
+    let (read_header, read_matrix) = matrix_market::read(buffer.as_slice()).unwrap();
+    assert_eq!(read_header, header);
+    read_matrix
+}
+
+#[test]
+fn test_read_general_coordinate() {
+    let (header, m) = matrix_market::read(fixture("general_coordinate_real.mtx")).unwrap();
+    assert_eq!(
+        header,
+        Header::new(Format::Coordinate, Field::Real, Symmetry::General)
+    );
+    assert_eq!(m.size(), (4, 5));
+    assert_eq!(m[0][0], 1.0);
+    assert_eq!(m[2][2], 1.5e-2);
+    assert_eq!(m[3][4], 33.32);
+    assert_eq!(m[0][4], 12.0);
+    assert_eq!(m[1][0], 0.0);
+}
+
+#[test]
+fn test_read_symmetric_coordinate_integer() {
+    let m: Matrix<3, 3> =
+        matrix_market::read_matrix(fixture("symmetric_coordinate_integer.mtx")).unwrap();
+    assert_eq!(
+        m,
+        Matrix::new([[2.0, -1.0, 0.0], [-1.0, 0.0, -1.0], [0.0, -1.0, 2.0]])
+    );
+}
+
+#[test]
+fn test_read_pattern() {
+    let m: Matrix<3, 3> = matrix_market::read_matrix(fixture("pattern_coordinate.mtx")).unwrap();
+    assert_eq!(
+        m,
+        Matrix::new([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]])
+    );
+}
+
+#[test]
+fn test_read_array_formats() {
+    let m: Matrix<2, 3> = matrix_market::read_matrix(fixture("general_array_real.mtx")).unwrap();
+    assert_eq!(m, Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+    let m: Matrix<3, 3> = matrix_market::read_matrix(fixture("skew_array_real.mtx")).unwrap();
+    assert_eq!(
+        m,
+        Matrix::new([[0.0, -1.5, 2.0], [1.5, 0.0, -4.0], [-2.0, 4.0, 0.0]])
+    );
+
+    let m: Matrix<3, 3> =
+        matrix_market::read_matrix(fixture("symmetric_array_integer.mtx")).unwrap();
+    assert_eq!(
+        m,
+        Matrix::new([[4.0, 1.0, 0.0], [1.0, 5.0, 2.0], [0.0, 2.0, 6.0]])
+    );
+}
+
+#[test]
+fn test_read_matrix_shape_mismatch() {
+    let result = matrix_market::read_matrix::<3, 3, _>(fixture("general_array_real.mtx"));
+    assert!(matches!(
+        result,
+        Err(MatrixMarketError::ShapeMismatch {
+            expected: (3, 3),
+            found: (2, 3)
+        })
+    ));
+}
+
+#[test]
+fn test_round_trip_fixtures() {
+    for name in [
+        "general_coordinate_real.mtx",
+        "symmetric_coordinate_integer.mtx",
+        "pattern_coordinate.mtx",
+        "general_array_real.mtx",
+        "skew_array_real.mtx",
+        "symmetric_array_integer.mtx",
+    ] {
+        let (header, m) = matrix_market::read(fixture(name)).unwrap();
+        assert_eq!(round_trip(&m, header), m, "{}", name);
+    }
+}
+
+#[test]
+fn test_round_trip_all_headers() {
+    let symmetric = DMatrix::new(3, 3, vec![1.0, 2.0, 0.0, 2.0, -3.0, 4.0, 0.0, 4.0, 5.0]);
+    let skew = DMatrix::new(3, 3, vec![0.0, 2.0, -1.0, -2.0, 0.0, 4.0, 1.0, -4.0, 0.0]);
+    for format in [Format::Coordinate, Format::Array] {
+        for field in [Field::Real, Field::Integer] {
+            assert_eq!(
+                round_trip(&symmetric, Header::new(format, field, Symmetry::General)),
+                symmetric
+            );
+            assert_eq!(
+                round_trip(&symmetric, Header::new(format, field, Symmetry::Symmetric)),
+                symmetric
+            );
+            assert_eq!(
+                round_trip(&skew, Header::new(format, field, Symmetry::SkewSymmetric)),
+                skew
+            );
+        }
+    }
+    let real = DMatrix::new(2, 2, vec![0.1, -1.0e-30, 3.5e20, f32::MIN_POSITIVE]);
+    assert_eq!(round_trip(&real, Header::default()), real);
+}
+
+#[test]
+fn test_write_fixed_size_matrix() {
+    let mut buffer = Vec::new();
+    let m = Matrix::new([[1.0, 0.0], [0.0, 2.5]]);
+    matrix_market::write_matrix(
+        &mut buffer,
+        &m,
+        Header::new(Format::Coordinate, Field::Real, Symmetry::General),
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n2 2 2.5\n"
+    );
+}
+
+#[test]
+fn test_write_not_representable() {
+    let m = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    let mut buffer = Vec::new();
+    let header = Header::new(Format::Array, Field::Real, Symmetry::Symmetric);
+    assert!(matches!(
+        matrix_market::write(&mut buffer, &m, header),
+        Err(MatrixMarketError::NotRepresentable { row: 1, column: 2 })
+    ));
+    let header = Header::new(Format::Array, Field::Integer, Symmetry::General);
+    let fractional = DMatrix::new(1, 1, vec![0.5]);
+    assert!(matches!(
+        matrix_market::write(&mut buffer, &fractional, header),
+        Err(MatrixMarketError::NotRepresentable { row: 1, column: 1 })
+    ));
+}
+
+#[test]
+fn test_invalid_banner() {
+    assert!(matches!(
+        read_str("%MatrixMarket matrix array real general\n1 1\n1\n"),
+        Err(MatrixMarketError::InvalidBanner { line: 1 })
+    ));
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix array pattern general\n1 1\n"),
+        Err(MatrixMarketError::InvalidBanner { .. })
+    ));
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix coordinate complex general\n"),
+        Err(MatrixMarketError::Unsupported { line: 1, ref qualifier }) if qualifier == "complex"
+    ));
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix coordinate real hermitian\n"),
+        Err(MatrixMarketError::Unsupported { .. })
+    ));
+}
+
+#[test]
+fn test_too_large() {
+    // Addressable sizes whose dense storage cannot be allocated.
+    for size in ["1000000000 1000000000 0", "2000000000 1000000000 1"] {
+        let result = read_str(&format!(
+            "%%MatrixMarket matrix coordinate real general\n{size}\n"
+        ));
+        assert!(matches!(
+            result,
+            Err(MatrixMarketError::TooLarge { line: 2 })
+        ));
+    }
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix array real general\n1000000000 1000000000\n"),
+        Err(MatrixMarketError::TooLarge { line: 2 })
+    ));
+}
+
+#[test]
+fn test_invalid_size() {
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix coordinate real general\n% comment\n3 3\n"),
+        Err(MatrixMarketError::InvalidSize { line: 3 })
+    ));
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix array real symmetric\n2 3\n"),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    ));
+    for size in ["4294967296 4294967296 0", "18446744073709551615 2 0"] {
+        assert!(matches!(
+            read_str(&format!(
+                "%%MatrixMarket matrix coordinate real general\n{size}\n"
+            )),
+            Err(MatrixMarketError::InvalidSize { line: 2 })
+        ));
+    }
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix array real general\n4294967296 4294967296\n"),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    ));
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix array real symmetric\n4294967296 4294967296\n"),
+        Err(MatrixMarketError::InvalidSize { line: 2 })
+    ));
+}
+
+#[test]
+fn test_invalid_entries() {
+    let header = "%%MatrixMarket matrix coordinate real symmetric\n3 3 2\n";
+    assert!(matches!(
+        read_str(&format!("{header}1 1 1.0\n4 1 2.0\n")),
+        Err(MatrixMarketError::IndexOutOfBounds {
+            line: 4,
+            row: 4,
+            column: 1
+        })
+    ));
+    assert!(matches!(
+        read_str(&format!("{header}1 2 1.0\n")),
+        Err(MatrixMarketError::NotLowerTriangular {
+            line: 3,
+            row: 1,
+            column: 2
+        })
+    ));
+    assert!(matches!(
+        read_str(&format!("{header}2 1 1.0\n2 1 1.0\n")),
+        Err(MatrixMarketError::DuplicateEntry {
+            line: 4,
+            row: 2,
+            column: 1
+        })
+    ));
+    assert!(matches!(
+        read_str(&format!("{header}2 1 abc\n")),
+        Err(MatrixMarketError::InvalidEntry { line: 3 })
+    ));
+    assert!(matches!(
+        read_str(&format!("{header}2 1 1.0 5.0\n")),
+        Err(MatrixMarketError::InvalidEntry { line: 3 })
+    ));
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix coordinate integer general\n1 1 1\n1 1 1.5\n"),
+        Err(MatrixMarketError::InvalidEntry { line: 3 })
+    ));
+    assert!(matches!(
+        read_str("%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n1 1 1.0\n"),
+        Err(MatrixMarketError::NotLowerTriangular { .. })
+    ));
+}
+
+#[test]
+fn test_entry_count() {
+    let header = "%%MatrixMarket matrix coordinate real general\n2 2 2\n";
+    assert!(matches!(
+        read_str(&format!("{header}1 1 1.0\n")),
+        Err(MatrixMarketError::EntryCount {
+            expected: 2,
+            found: 1
+        })
+    ));
+    assert!(matches!(
+        read_str(&format!("{header}1 1 1.0\n2 2 1.0\n1 2 1.0\n")),
+        Err(MatrixMarketError::EntryCount {
+            expected: 2,
+            found: 3
+        })
+    ));
+}
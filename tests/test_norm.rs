@@ -0,0 +1,127 @@
+use minmath::{error::MathError, linear_algebra::matrix::Matrix};
+
+fn assert_close(a: f32, b: f32, tolerance: f32) {
+    assert!(
+        (a - b).abs() <= tolerance * b.abs().max(1.0),
+        "{} != {}",
+        a,
+        b
+    );
+}
+
+fn hilbert<const N: usize>() -> Matrix<N, N> {
+    Matrix::from_fn(|r, c| 1.0 / (r + c + 1) as f32)
+}
+
+#[test]
+fn test_elementwise_norms() {
+    let a = Matrix::new([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]]);
+    assert_close(a.frobenius_norm(), 91.0f32.sqrt(), 1e-6);
+    assert_eq!(a.one_norm(), 9.0);
+    assert_eq!(a.infinity_norm(), 15.0);
+    assert_eq!(a.max_norm(), 6.0);
+    assert_eq!(Matrix::<0, 0>::ZERO.frobenius_norm(), 0.0);
+    assert_eq!(Matrix::<2, 2>::ZERO.one_norm(), 0.0);
+}
+
+#[test]
+fn test_frobenius_norm_does_not_overflow() {
+    let a = Matrix::<2, 2>::filled(1e30);
+    assert_close(a.frobenius_norm(), 2e30, 1e-6);
+}
+
+#[test]
+fn test_singular_values() {
+    // AᵀA has eigenvalues 45 and 5.
+    let a = Matrix::new([[3.0, 0.0], [4.0, 5.0]]);
+    let values = a.singular_values();
+    assert_close(values[0], 45.0f32.sqrt(), 1e-6);
+    assert_close(values[1], 5.0f32.sqrt(), 1e-6);
+    assert_close(a.spectral_norm(), 45.0f32.sqrt(), 1e-6);
+
+    let d = Matrix::new([[0.0, 0.0, 0.0], [0.0, -7.0, 0.0], [0.0, 0.0, 2.0]]);
+    assert_eq!(d.singular_values(), [7.0, 2.0, 0.0]);
+}
+
+#[test]
+fn test_singular_values_of_rectangular_matrices() {
+    let tall = Matrix::new([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+    let values = tall.singular_values();
+    assert_close(values[0], 3.0f32.sqrt(), 1e-6);
+    assert_close(values[1], 1.0, 1e-6);
+
+    let wide = tall.transpose();
+    let values = wide.singular_values();
+    assert_close(values[0], 3.0f32.sqrt(), 1e-6);
+    assert_close(values[1], 1.0, 1e-6);
+    assert!(values[2].abs() < 1e-6);
+}
+
+#[test]
+fn test_rank() {
+    assert_eq!(Matrix::<3, 3>::IDENTITY.rank(), 3);
+    assert_eq!(Matrix::<3, 3>::ZERO.rank(), 0);
+    assert_eq!(Matrix::new([[1.0, 2.0], [2.0, 4.0]]).rank(), 1);
+    assert_eq!(Matrix::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]).rank(), 1);
+    let nearly_singular = Matrix::new([[1.0, 1.0], [1.0, 1.0001]]);
+    assert_eq!(nearly_singular.rank(), 2);
+    assert_eq!(nearly_singular.rank_with_tolerance(1e-3), 1);
+}
+
+#[test]
+fn test_trace() {
+    let a = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(a.trace(), 15.0);
+    assert_eq!(Matrix::<4, 4>::IDENTITY.trace(), 4.0);
+}
+
+#[test]
+fn test_condition_number() {
+    let a = Matrix::new([[3.0, 0.0], [4.0, 5.0]]);
+    assert_close(a.condition_number(), 3.0, 1e-5);
+    assert_eq!(Matrix::<3, 3>::IDENTITY.condition_number(), 1.0);
+    assert_eq!(
+        Matrix::new([[1.0, 2.0], [2.0, 4.0]]).condition_number(),
+        f32::INFINITY
+    );
+}
+
+#[test]
+fn test_condition_estimate() {
+    // ‖A‖₁ = 7 and ‖A⁻¹‖₁ = 0.6.
+    let a = Matrix::new([[3.0, 0.0], [4.0, 5.0]]);
+    assert_close(a.condition_estimate().unwrap(), 4.2, 1e-5);
+    assert_close(
+        Matrix::<4, 4>::IDENTITY.condition_estimate().unwrap(),
+        1.0,
+        1e-6,
+    );
+}
+
+#[test]
+fn test_condition_estimate_matches_exact_value() {
+    let a = hilbert::<4>();
+    let exact = a.one_norm() * a.checked_inverse().unwrap().one_norm();
+    let estimate = a.condition_estimate().unwrap();
+    assert!(estimate <= exact * 1.01, "{} > {}", estimate, exact);
+    assert!(estimate >= exact / 3.0, "{} < {}", estimate, exact);
+    assert!(estimate > 1e4);
+}
+
+#[test]
+fn test_condition_estimate_of_singular_matrix() {
+    let a = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    assert_eq!(a.condition_estimate(), Err(MathError::Singular));
+    assert_eq!(
+        Matrix::<3, 3>::ZERO.condition_estimate(),
+        Err(MathError::Singular)
+    );
+    assert_eq!(
+        Matrix::new([[f32::NAN, 1.0], [1.0, 2.0]]).condition_estimate(),
+        Err(MathError::NonFinite)
+    );
+    assert_eq!(
+        Matrix::new([[1.0, f32::INFINITY], [1.0, 2.0]]).condition_estimate(),
+        Err(MathError::NonFinite)
+    );
+}
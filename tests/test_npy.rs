@@ -0,0 +1,269 @@
+#![cfg(feature = "std")]
+
+use std::{
+    fs::File,
+    io::{BufReader, Cursor},
+};
+
+use minmath::{
+    io::npy::{self, DType, Endian, NpyError, NpyOptions, NpzReader, Order},
+    linear_algebra::{
+        dmatrix::DMatrix,
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+    },
+};
+
+fn fixture(name: &str) -> BufReader<File> {
+    let path = format!("{}/tests/fixtures/npy/{}", env!("CARGO_MANIFEST_DIR"), name);
+    BufReader::new(File::open(path).unwrap())
+}
+
+fn expected() -> Matrix<2, 3> {
+    Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])
+}
+
+#[test]
+fn test_read_fixtures() {
+    for name in [
+        "matrix_2x3_f4_le.npy",
+        "matrix_2x3_f8_be.npy",
+        "matrix_2x3_f4_fortran.npy",
+    ] {
+        assert_eq!(
+            npy::read_matrix(fixture(name)).unwrap(),
+            expected(),
+            "{}",
+            name
+        );
+    }
+    let v = npy::read_vector3(fixture("vector3_f8_le.npy")).unwrap();
+    assert_eq!(v, Vector3::new(0.5, -1.5, 2.25));
+    let array = npy::read(fixture("vector3_f8_le.npy")).unwrap();
+    assert_eq!(array.shape, vec![3]);
+}
+
+#[test]
+fn test_write_matches_numpy_layout() {
+    let mut buffer = Vec::new();
+    npy::write_matrix(&mut buffer, &expected(), NpyOptions::default()).unwrap();
+    let reference = std::fs::read(format!(
+        "{}/tests/fixtures/npy/matrix_2x3_f4_le.npy",
+        env!("CARGO_MANIFEST_DIR")
+    ))
+    .unwrap();
+    assert_eq!(buffer, reference);
+}
+
+#[test]
+fn test_round_trip_all_options() {
+    let m = Matrix::new([[1.5, -2.0, 3.25], [4.0, 1.0e-20, f32::MAX]]);
+    for dtype in [
+        DType::F32(Endian::Little),
+        DType::F32(Endian::Big),
+        DType::F64(Endian::Little),
+        DType::F64(Endian::Big),
+    ] {
+        for order in [Order::C, Order::Fortran] {
+            let mut buffer = Vec::new();
+            npy::write_matrix(&mut buffer, &m, NpyOptions { dtype, order }).unwrap();
+            let header = u16::from_le_bytes([buffer[8], buffer[9]]) as usize;
+            assert_eq!((10 + header) % 64, 0);
+            assert_eq!(npy::read_matrix(buffer.as_slice()).unwrap(), m);
+        }
+    }
+}
+
+#[test]
+fn test_round_trip_dmatrix_and_vectors() {
+    let m = DMatrix::from_fn(4, 7, |r, c| r as f32 * 0.5 - c as f32);
+    let mut buffer = Vec::new();
+    npy::write_dmatrix(&mut buffer, &m, NpyOptions::default()).unwrap();
+    assert_eq!(npy::read_dmatrix(buffer.as_slice()).unwrap(), m);
+
+    let mut buffer = Vec::new();
+    npy::write_vector2(&mut buffer, &Vector2::new(3.0, 4.0), NpyOptions::default()).unwrap();
+    assert_eq!(npy::read(buffer.as_slice()).unwrap().shape, vec![2]);
+    assert_eq!(
+        npy::read_vector2(buffer.as_slice()).unwrap(),
+        Vector2::new(3.0, 4.0)
+    );
+
+    let mut buffer = Vec::new();
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    npy::write_vector3(&mut buffer, &v, NpyOptions::default()).unwrap();
+    assert_eq!(npy::read_vector3(buffer.as_slice()).unwrap(), v);
+    assert_eq!(
+        npy::read_matrix::<3, 1, _>(buffer.as_slice()).unwrap(),
+        v.to_matrix()
+    );
+}
+
+#[test]
+fn test_three_dimensional_fortran_round_trip() {
+    let shape = [2, 3, 4];
+    let data: Vec<f32> = (0..24).map(|i| i as f32).collect();
+    let options = NpyOptions {
+        dtype: DType::F64(Endian::Big),
+        order: Order::Fortran,
+    };
+    let mut buffer = Vec::new();
+    npy::write(&mut buffer, &shape, &data, options).unwrap();
+    let array = npy::read(buffer.as_slice()).unwrap();
+    assert_eq!(array.shape, shape);
+    assert_eq!(array.data, data);
+}
+
+#[test]
+fn test_unsupported_dtype() {
+    assert!(matches!(
+        npy::read(fixture("int64.npy")),
+        Err(NpyError::UnsupportedDtype(ref descr)) if descr == "<i8"
+    ));
+}
+
+#[test]
+fn test_invalid_files() {
+    assert!(matches!(
+        npy::read(&b"NUMPY!"[..]),
+        Err(NpyError::InvalidMagic)
+    ));
+    assert!(matches!(
+        npy::read(&b"\x93NUMPY\x04\x00"[..]),
+        Err(NpyError::UnsupportedVersion(4, 0))
+    ));
+    let mut buffer = Vec::new();
+    npy::write_matrix(&mut buffer, &expected(), NpyOptions::default()).unwrap();
+    buffer.truncate(buffer.len() - 1);
+    assert!(matches!(
+        npy::read(buffer.as_slice()),
+        Err(NpyError::TruncatedData)
+    ));
+    assert!(matches!(
+        npy::read_matrix::<3, 2, _>(fixture("matrix_2x3_f4_le.npy")),
+        Err(NpyError::ShapeMismatch { .. })
+    ));
+    assert!(matches!(
+        npy::read_vector2(fixture("vector3_f8_le.npy")),
+        Err(NpyError::ShapeMismatch { .. })
+    ));
+}
+
+// A version 1.0 file declaring `shape`, followed by 16 bytes of data.
+fn with_shape(shape: &str) -> Vec<u8> {
+    let header = format!("{{'descr': '<f4', 'fortran_order': False, 'shape': {shape}, }}\n");
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(&[0; 16]);
+    bytes
+}
+
+#[test]
+fn test_oversized_shapes() {
+    for shape in [
+        "(4294967296, 4294967296)",
+        "(0, 4294967296, 4294967296)",
+        "(4611686018427387904,)",
+    ] {
+        assert!(
+            matches!(
+                npy::read(with_shape(shape).as_slice()),
+                Err(NpyError::InvalidHeader)
+            ),
+            "{shape}"
+        );
+    }
+    assert!(matches!(
+        npy::read(with_shape("(1099511627776,)").as_slice()),
+        Err(NpyError::TruncatedData)
+    ));
+    assert!(matches!(
+        npy::read_dmatrix(with_shape("(2, 2)").as_slice()),
+        Ok(m) if m == DMatrix::zeros(2, 2)
+    ));
+    assert!(matches!(
+        npy::read(&b"\x93NUMPY\x02\x00\xff\xff\xff\xff{}"[..]),
+        Err(NpyError::TruncatedData)
+    ));
+}
+
+#[test]
+fn test_read_npz() {
+    let mut archive = NpzReader::new(fixture("arrays.npz")).unwrap();
+    assert_eq!(
+        archive.names().collect::<Vec<_>>(),
+        vec!["rotation", "offset", "big"]
+    );
+    let rotation: Matrix<2, 2> = archive.array("rotation").unwrap().to_matrix().unwrap();
+    assert_eq!(rotation, Matrix::new([[0.0, -1.0], [1.0, 0.0]]));
+    let offset = archive.array("offset.npy").unwrap().to_vector3().unwrap();
+    assert_eq!(offset, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        archive.array("big").unwrap().to_matrix().unwrap(),
+        expected()
+    );
+    assert!(matches!(
+        archive.array("missing"),
+        Err(NpyError::MissingArray(_))
+    ));
+}
+
+#[test]
+fn test_npz_errors() {
+    let mut archive = NpzReader::new(fixture("compressed.npz")).unwrap();
+    assert!(matches!(
+        archive.array("rotation"),
+        Err(NpyError::UnsupportedCompression { method: 8, .. })
+    ));
+
+    let mut bytes = std::fs::read(format!(
+        "{}/tests/fixtures/npy/arrays.npz",
+        env!("CARGO_MANIFEST_DIR")
+    ))
+    .unwrap();
+    let corrupt = bytes
+        .windows(4)
+        .position(|w| w == [0, 0, 0x80, 0x3f])
+        .unwrap();
+    bytes[corrupt + 3] = 0x40;
+    let mut archive = NpzReader::new(Cursor::new(bytes)).unwrap();
+    assert!(matches!(
+        archive.array("rotation"),
+        Err(NpyError::ChecksumMismatch { .. })
+    ));
+
+    let bytes = std::fs::read(format!(
+        "{}/tests/fixtures/npy/arrays.npz",
+        env!("CARGO_MANIFEST_DIR")
+    ))
+    .unwrap();
+    // The size of the first entry in the central directory.
+    let mut oversized = bytes.clone();
+    let entry = oversized
+        .windows(4)
+        .position(|w| w == b"PK\x01\x02")
+        .unwrap();
+    oversized[entry + 24..entry + 28].copy_from_slice(&0xfff0_0000u32.to_le_bytes());
+    let mut archive = NpzReader::new(Cursor::new(oversized)).unwrap();
+    assert!(matches!(
+        archive.array("rotation"),
+        Err(NpyError::TruncatedData)
+    ));
+    // The size of the central directory.
+    let mut oversized = bytes;
+    let end = oversized
+        .windows(4)
+        .rposition(|w| w == b"PK\x05\x06")
+        .unwrap();
+    oversized[end + 12..end + 16].copy_from_slice(&0xfff0_0000u32.to_le_bytes());
+    assert!(matches!(
+        NpzReader::new(Cursor::new(oversized)),
+        Err(NpyError::TruncatedData)
+    ));
+
+    assert!(matches!(
+        NpzReader::new(Cursor::new(vec![0u8; 100])),
+        Err(NpyError::InvalidArchive)
+    ));
+}
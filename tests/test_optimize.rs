@@ -0,0 +1,189 @@
+#![cfg(feature = "std")]
+
+use std::cell::Cell;
+
+use minmath::{
+    dual,
+    linear_algebra::{dmatrix::DMatrix, matrix::Matrix},
+    optimize::{
+        LineSearch, OptimizeOptions, Report, Termination, bfgs, gradient_descent, lbfgs,
+        nelder_mead,
+    },
+    scalar::Real,
+};
+
+mod common;
+
+fn rosenbrock<T: Real>(x: Matrix<2, 1, T>) -> T {
+    common::rosenbrock(x[0][0], x[1][0])
+}
+
+fn rosenbrock_gradient(x: &Matrix<2, 1>) -> Matrix<2, 1> {
+    dual::gradient(rosenbrock, *x)
+}
+
+const START: Matrix<2, 1> = Matrix::new([[-1.2], [1.0]]);
+const MINIMUM: Matrix<2, 1> = Matrix::new([[1.0], [1.0]]);
+
+fn assert_minimum(report: &Report<Matrix<2, 1>>, tolerance: f32) {
+    assert!(report.converged(), "{:?}", report);
+    assert!((report.x - MINIMUM).max_norm() < tolerance, "{:?}", report);
+}
+
+#[test]
+fn test_bfgs_rosenbrock() {
+    let options = OptimizeOptions::default();
+    let report = bfgs(|x| rosenbrock(*x), rosenbrock_gradient, START, options);
+    assert_minimum(&report, 1e-3);
+    assert!(report.iterations < 100, "{:?}", report);
+    assert!(report.gradient_norm.unwrap() < 1e-2);
+
+    let armijo = OptimizeOptions {
+        line_search: LineSearch::Armijo,
+        ..options
+    };
+    let report = bfgs(|x| rosenbrock(*x), rosenbrock_gradient, START, armijo);
+    assert_minimum(&report, 1e-3);
+}
+
+#[test]
+fn test_lbfgs_rosenbrock() {
+    let options = OptimizeOptions::default();
+    let report = lbfgs(|x| rosenbrock(*x), rosenbrock_gradient, START, options);
+    assert_minimum(&report, 1e-3);
+
+    let armijo = OptimizeOptions {
+        line_search: LineSearch::Armijo,
+        memory: 3,
+        ..options
+    };
+    let report = lbfgs(|x| rosenbrock(*x), rosenbrock_gradient, START, armijo);
+    assert_minimum(&report, 1e-3);
+}
+
+#[test]
+fn test_gradient_descent() {
+    // An elongated quadratic bowl with its minimum at (1, -2).
+    let f = |x: &Matrix<2, 1>| {
+        let (a, b) = (x[0][0] - 1.0, x[1][0] + 2.0);
+        a * a + 10.0 * b * b
+    };
+    let gradient =
+        |x: &Matrix<2, 1>| Matrix::new([[2.0 * (x[0][0] - 1.0)], [20.0 * (x[1][0] + 2.0)]]);
+    for line_search in [LineSearch::Armijo, LineSearch::Wolfe] {
+        let options = OptimizeOptions {
+            line_search,
+            ..OptimizeOptions::default()
+        };
+        let report = gradient_descent(f, gradient, Matrix::ZERO, options);
+        assert!(report.converged(), "{:?}", report);
+        assert!((report.x - Matrix::new([[1.0], [-2.0]])).max_norm() < 1e-4);
+        assert!(report.value < 1e-8);
+    }
+}
+
+#[test]
+fn test_nelder_mead() {
+    let options = OptimizeOptions {
+        max_iterations: 2000,
+        ..OptimizeOptions::default()
+    };
+    let report = nelder_mead(|x| rosenbrock(*x), START, options);
+    assert_minimum(&report, 1e-2);
+    assert_eq!(report.gradient_norm, None);
+    assert_eq!(report.gradient_evaluations, 0);
+    assert!(report.evaluations > report.iterations);
+
+    // A single parameter. From -5 the first simplex straddles the minimum
+    // with equal values at both vertices, which must not end the search.
+    for start in [0.0, -5.0] {
+        let report = nelder_mead(
+            |x: &Matrix<1, 1>| (x[0][0] - 3.0) * (x[0][0] - 3.0),
+            Matrix::new([[start]]),
+            options,
+        );
+        assert!(report.converged(), "{:?}", report);
+        assert_eq!(report.termination, Termination::ValueTolerance);
+        assert!((report.x[0][0] - 3.0).abs() < 1e-5, "{:?}", report);
+    }
+}
+
+#[test]
+fn test_runtime_sized_parameters() {
+    // Σ i (x_i - i)², whose minimum is at x_i = i.
+    let n = 20;
+    let f = |x: &DMatrix| {
+        x.as_slice()
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (i + 1) as f32 * (x - i as f32) * (x - i as f32))
+            .sum::<f32>()
+    };
+    let gradient = |x: &DMatrix| {
+        DMatrix::from_fn(x.rows(), 1, |i, _| {
+            2.0 * (i + 1) as f32 * (x[i][0] - i as f32)
+        })
+    };
+    let expected = DMatrix::from_fn(n, 1, |i, _| i as f32);
+    let options = OptimizeOptions::default();
+    for report in [
+        lbfgs(f, gradient, DMatrix::zeros(n, 1), options),
+        bfgs(f, gradient, DMatrix::zeros(n, 1), options),
+    ] {
+        assert!(report.converged(), "{:?}", report);
+        let error = (&report.x - &expected)
+            .as_slice()
+            .iter()
+            .fold(0.0f32, |m, e| m.max(e.abs()));
+        assert!(error < 1e-4, "{:?}", report);
+    }
+}
+
+#[test]
+fn test_counts_evaluations() {
+    let evaluations = Cell::new(0);
+    let gradient_evaluations = Cell::new(0);
+    let report = bfgs(
+        |x| {
+            evaluations.set(evaluations.get() + 1);
+            rosenbrock(*x)
+        },
+        |x| {
+            gradient_evaluations.set(gradient_evaluations.get() + 1);
+            rosenbrock_gradient(x)
+        },
+        START,
+        OptimizeOptions::default(),
+    );
+    assert_eq!(report.evaluations, evaluations.get());
+    assert_eq!(report.gradient_evaluations, gradient_evaluations.get());
+}
+
+#[test]
+fn test_termination() {
+    let options = OptimizeOptions {
+        max_iterations: 3,
+        ..OptimizeOptions::default()
+    };
+    let report = bfgs(|x| rosenbrock(*x), rosenbrock_gradient, START, options);
+    assert_eq!(report.termination, Termination::MaxIterations);
+    assert_eq!(report.iterations, 3);
+    assert!(!report.converged());
+    assert!(report.value < rosenbrock(START));
+
+    // Already at the minimum.
+    let report = lbfgs(|x| rosenbrock(*x), rosenbrock_gradient, MINIMUM, options);
+    assert_eq!(report.termination, Termination::GradientTolerance);
+    assert_eq!(report.iterations, 0);
+    assert_eq!(report.x, MINIMUM);
+
+    let report = gradient_descent(
+        |x: &Matrix<1, 1>| x[0][0].ln(),
+        |x| Matrix::new([[1.0 / x[0][0]]]),
+        Matrix::new([[-1.0]]),
+        options,
+    );
+    assert_eq!(report.termination, Termination::NonFinite);
+    let report = nelder_mead(|_: &Matrix<1, 1>| f32::NAN, Matrix::ZERO, options);
+    assert_eq!(report.termination, Termination::NonFinite);
+}
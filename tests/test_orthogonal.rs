@@ -0,0 +1,111 @@
+use minmath::{
+    error::MathError,
+    linear_algebra::{matrix::Matrix, vector::Vector3},
+};
+
+fn assert_close(a: Vector3, b: Vector3) {
+    assert!((a - b).length() < 1e-6, "{:?} != {:?}", a, b);
+}
+
+// A rotation after many incremental updates, perturbed away from orthogonality.
+fn drifted_rotation() -> Matrix<3, 3> {
+    let step = Matrix::<3, 3>::rotation_matrix3x3_x(0.01)
+        * Matrix::<3, 3>::rotation_matrix3x3_y(0.02)
+        * Matrix::<3, 3>::rotation_matrix3x3_z(0.03);
+    let mut m = Matrix::<3, 3>::IDENTITY;
+    for _ in 0..100 {
+        m = m * step;
+    }
+    m + Matrix::new([[1e-3, -2e-3, 0.0], [0.0, 1e-3, 3e-3], [-1e-3, 0.0, 2e-3]])
+}
+
+#[test]
+fn test_predicates() {
+    let diagonal = Matrix::new([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+    assert!(diagonal.is_diagonal());
+    assert!(diagonal.is_upper_triangular());
+    let upper = Matrix::new([[1.0, 2.0], [0.0, 3.0]]);
+    assert!(!upper.is_diagonal());
+    assert!(upper.is_upper_triangular());
+    assert!(!upper.transpose().is_upper_triangular());
+    assert!(!upper.is_symmetric());
+    assert!(Matrix::new([[1.0, 2.0], [2.0, 3.0]]).is_symmetric());
+    assert!(Matrix::<3, 3>::IDENTITY.is_orthogonal(0.0));
+}
+
+#[test]
+fn test_is_orthogonal() {
+    let rotation = Matrix::<3, 3>::rotation_matrix3x3_y(0.7);
+    assert!(rotation.is_orthogonal(1e-6));
+    assert!(!drifted_rotation().is_orthogonal(1e-4));
+    assert!(!Matrix::new([[2.0, 0.0], [0.0, 1.0]]).is_orthogonal(1e-3));
+}
+
+#[test]
+fn test_gram_schmidt() {
+    let m = drifted_rotation();
+    let q = m.gram_schmidt().unwrap();
+    assert!(q.is_orthogonal(1e-6));
+    // The first column keeps its direction.
+    let first = m.column::<0>().to_vector3().normalize();
+    assert_close(q.column::<0>().to_vector3(), first);
+
+    let a = Matrix::new([[3.0, 1.0], [4.0, 2.0]]);
+    let q = a.gram_schmidt().unwrap();
+    assert!(q.is_orthogonal(1e-6));
+    assert_eq!(q.column::<0>(), Matrix::new([[0.6], [0.8]]));
+}
+
+#[test]
+fn test_gram_schmidt_dependent_columns() {
+    let m = Matrix::new([[1.0, 2.0, 0.0], [1.0, 2.0, 1.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(m.gram_schmidt(), Err(MathError::Singular));
+}
+
+#[test]
+fn test_orthonormalize() {
+    let m = drifted_rotation();
+    let q = m.orthonormalize().unwrap();
+    assert!(q.is_orthogonal(1e-6));
+    // The polar factor is the closest orthogonal matrix, so it is no further
+    // from the input than the Gram-Schmidt result.
+    let distance = |a: Matrix<3, 3>| (a - m).frobenius_norm();
+    assert!(distance(q) <= distance(m.gram_schmidt().unwrap()) + 1e-6);
+    // An orthogonal matrix is left unchanged.
+    let rotation = Matrix::<3, 3>::rotation_matrix3x3_z(1.3);
+    assert!((rotation.orthonormalize().unwrap() - rotation).max_norm() < 1e-6);
+}
+
+#[test]
+fn test_orthonormalize_errors() {
+    assert_eq!(
+        Matrix::new([[1.0, 2.0], [2.0, 4.0]]).orthonormalize(),
+        Err(MathError::Singular)
+    );
+    assert_eq!(
+        Matrix::new([[f32::NAN, 0.0], [0.0, 1.0]]).orthonormalize(),
+        Err(MathError::NonFinite)
+    );
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    let normals = [
+        Vector3::X,
+        Vector3::Y,
+        Vector3::Z,
+        Vector3::Z * -1.0,
+        Vector3::new(1.0, 2.0, 3.0).normalize(),
+        Vector3::new(-0.3, 0.4, -0.5).normalize(),
+        Vector3::new(1e-4, -1e-4, -1.0).normalize(),
+    ];
+    for n in normals {
+        let (b1, b2) = n.orthonormal_basis();
+        assert!((b1.length() - 1.0).abs() < 1e-6);
+        assert!((b2.length() - 1.0).abs() < 1e-6);
+        assert!(b1.dot(b2).abs() < 1e-6);
+        assert!(b1.dot(n).abs() < 1e-6);
+        assert!(b2.dot(n).abs() < 1e-6);
+        assert_close(b1.cross(b2), n);
+    }
+}
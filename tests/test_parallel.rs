@@ -0,0 +1,58 @@
+#![cfg(feature = "parallel")]
+
+use minmath::linear_algebra::{dmatrix::DMatrix, parallel::Threads};
+
+fn sample(rows: usize, columns: usize) -> DMatrix {
+    DMatrix::from_fn(rows, columns, |r, c| {
+        ((r * 31 + c * 17) % 23) as f32 / 7.0 - 1.5
+    })
+}
+
+#[test]
+fn test_par_mul_is_bitwise_identical() {
+    let a = sample(150, 90);
+    let b = sample(90, 70);
+    let serial = &a * &b;
+    for threads in [1, 2, 3, 8] {
+        assert_eq!(a.par_mul(&b, Threads::fixed(threads)), serial);
+    }
+    assert_eq!(a.par_mul(&b, Threads::Available), serial);
+}
+
+#[test]
+fn test_par_mul_empty() {
+    let a = sample(40, 5);
+    let empty = DMatrix::zeros(5, 0);
+    assert_eq!(a.par_mul(&empty, Threads::fixed(4)), DMatrix::zeros(40, 0));
+    let b = sample(5, 40);
+    let empty = DMatrix::zeros(0, 5);
+    assert_eq!(empty.par_mul(&b, Threads::fixed(4)), DMatrix::zeros(0, 40));
+}
+
+#[test]
+fn test_par_map_is_bitwise_identical() {
+    let m = sample(200, 33);
+    let f = |v: f32| (v * 1.3).sin() / (1.0 + v * v);
+    assert_eq!(m.par_map(Threads::fixed(4), f), m.map(f));
+}
+
+#[test]
+fn test_par_lu_is_bitwise_identical() {
+    let m = &sample(120, 120) + &(&DMatrix::identity(120) * 3.0);
+    let serial = m.lu().unwrap();
+    for threads in [2, 5] {
+        assert_eq!(m.par_lu(Threads::fixed(threads)).unwrap(), serial);
+    }
+}
+
+#[test]
+fn test_par_lu_singular() {
+    let m = DMatrix::zeros(40, 40);
+    assert!(m.par_lu(Threads::fixed(4)).is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_zero_threads() {
+    Threads::fixed(0);
+}
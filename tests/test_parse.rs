@@ -0,0 +1,161 @@
+use minmath::linear_algebra::{
+    matrix::Matrix,
+    parse::{ParseMatrixError, ParseMatrixErrorKind},
+    vector::{Vector2, Vector3},
+};
+
+fn expected() -> Matrix<2, 2> {
+    Matrix::new([[1.0, 2.0], [3.0, 4.0]])
+}
+
+#[test]
+fn test_parse_display_round_trip() {
+    let m = Matrix::new([[1.5, -2.0, 3.25], [4.0, 5.0, 6e-3]]);
+    assert_eq!(format!("{}", m).parse::<Matrix<2, 3>>(), Ok(m));
+    assert_eq!(format!("{:?}", m).parse::<Matrix<2, 3>>(), Ok(m));
+}
+
+#[test]
+fn test_parse_matlab_literal() {
+    assert_eq!("[1 2; 3 4]".parse(), Ok(expected()));
+    assert_eq!("[1, 2; 3, 4;]".parse(), Ok(expected()));
+    assert_eq!("[1 2\n 3 4]".parse(), Ok(expected()));
+}
+
+#[test]
+fn test_parse_numpy_literal() {
+    assert_eq!("[[1,2],[3,4]]".parse(), Ok(expected()));
+    assert_eq!("[[1.0, 2.0],\n [3.0, 4.0],]".parse(), Ok(expected()));
+    assert_eq!("[[1 2] [3 4]]".parse(), Ok(expected()));
+}
+
+#[test]
+fn test_parse_bare_rows() {
+    assert_eq!("1 2\n3 4\n".parse(), Ok(expected()));
+    assert_eq!("  1,2\r\n3,4".parse(), Ok(expected()));
+}
+
+#[test]
+fn test_parse_special_values() {
+    let m: Matrix<1, 3> = "[inf -inf NaN]".parse().unwrap();
+    assert_eq!(m[0][0], f32::INFINITY);
+    assert_eq!(m[0][1], f32::NEG_INFINITY);
+    assert!(m[0][2].is_nan());
+}
+
+#[test]
+fn test_parse_shape_mismatch() {
+    let error = "[1 2 3; 4 5 6]".parse::<Matrix<2, 2>>().unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseMatrixErrorKind::ShapeMismatch {
+            expected: (2, 2),
+            found: (2, 3)
+        }
+    );
+    assert_eq!((error.line, error.column), (1, 6));
+
+    let error = "[1 2]".parse::<Matrix<2, 2>>().unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseMatrixErrorKind::ShapeMismatch {
+            expected: (2, 2),
+            found: (1, 2)
+        }
+    );
+}
+
+#[test]
+fn test_parse_header_shape_mismatch() {
+    let error = "Matrix (3x2):\n1 2\n3 4\n"
+        .parse::<Matrix<2, 2>>()
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ParseMatrixError {
+            kind: ParseMatrixErrorKind::ShapeMismatch {
+                expected: (2, 2),
+                found: (3, 2)
+            },
+            line: 1,
+            column: 1,
+        }
+    );
+}
+
+#[test]
+fn test_parse_ragged_row() {
+    let error = "1 2\n3 4 5\n".parse::<Matrix<2, 2>>().unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseMatrixErrorKind::RaggedRow {
+            row: 1,
+            expected: 2,
+            found: 3
+        }
+    );
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+#[test]
+fn test_parse_invalid_number() {
+    let error = "[1 2; 3 x4]".parse::<Matrix<2, 2>>().unwrap_err();
+    assert!(matches!(error.kind, ParseMatrixErrorKind::InvalidNumber(_)));
+    assert_eq!((error.line, error.column), (1, 9));
+}
+
+#[test]
+fn test_parse_unexpected_input() {
+    let error = "[1 2; 3 4".parse::<Matrix<2, 2>>().unwrap_err();
+    assert_eq!(error.kind, ParseMatrixErrorKind::UnexpectedEnd);
+    assert_eq!((error.line, error.column), (1, 10));
+
+    let error = "[1 2; 3 4] x".parse::<Matrix<2, 2>>().unwrap_err();
+    assert_eq!(error.kind, ParseMatrixErrorKind::UnexpectedCharacter('x'));
+
+    let error = "[[1, 2], 3]".parse::<Matrix<2, 2>>().unwrap_err();
+    assert_eq!(error.kind, ParseMatrixErrorKind::UnexpectedCharacter('3'));
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 10: unexpected character '3'"
+    );
+
+    let error = "[1,,2]".parse::<Matrix<1, 2>>().unwrap_err();
+    assert_eq!(error.kind, ParseMatrixErrorKind::UnexpectedCharacter(','));
+    assert_eq!((error.line, error.column), (1, 4));
+    let error = "[[1, 2], [3, , 4]]".parse::<Matrix<2, 2>>().unwrap_err();
+    assert_eq!(error.kind, ParseMatrixErrorKind::UnexpectedCharacter(','));
+}
+
+#[test]
+fn test_parse_vectors() {
+    assert_eq!("[1, 2]".parse(), Ok(Vector2::new(1.0, 2.0)));
+    assert_eq!("(1.5, -2)".parse(), Ok(Vector2::new(1.5, -2.0)));
+    assert_eq!("[1; 2; 3]".parse(), Ok(Vector3::new(1.0, 2.0, 3.0)));
+    assert_eq!("1 2 3".parse(), Ok(Vector3::new(1.0, 2.0, 3.0)));
+    assert_eq!("[[1], [2], [3]]".parse(), Ok(Vector3::new(1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn test_parse_vector_debug_round_trip() {
+    let v = Vector3::new(1.0, -2.5, 3e10);
+    assert_eq!(format!("{:?}", v).parse(), Ok(v));
+    let v = Vector2::new(0.25, 8.0);
+    assert_eq!(format!("{:?}", v).parse(), Ok(v));
+}
+
+#[test]
+fn test_parse_vector_errors() {
+    let error = "[1 2 3 4]".parse::<Vector3>().unwrap_err();
+    assert_eq!(
+        error.kind,
+        ParseMatrixErrorKind::ShapeMismatch {
+            expected: (3, 1),
+            found: (1, 4)
+        }
+    );
+    assert_eq!((error.line, error.column), (1, 8));
+
+    let error = "Vector2 { x: 1.0, z: 2.0 }".parse::<Vector2>().unwrap_err();
+    assert_eq!(error.kind, ParseMatrixErrorKind::UnexpectedCharacter('z'));
+}
@@ -0,0 +1,179 @@
+use minmath::{
+    error::MathError,
+    linear_algebra::{format::Style, matrix::Matrix},
+    rational::Rational,
+};
+
+fn r(numerator: i64, denominator: i64) -> Rational {
+    Rational::new(numerator, denominator)
+}
+
+#[test]
+fn test_reduction() {
+    let half = r(-3, -6);
+    assert_eq!((half.numerator(), half.denominator()), (1, 2));
+    assert_eq!(r(4, -8), r(-1, 2));
+    assert_eq!(r(0, 5), Rational::ZERO);
+    assert_eq!(r(0, -5).denominator(), 1);
+    assert!(r(6, 3).is_integer());
+    assert_eq!(
+        Rational::checked_new(1, 0),
+        Err(MathError::Domain("zero denominator"))
+    );
+    assert_eq!(
+        Rational::checked_new(i64::MIN, -1),
+        Err(MathError::NonFinite)
+    );
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+    assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+    assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+    assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+    assert_eq!(-r(2, 3), r(-2, 3));
+    assert_eq!(r(-2, 3).abs(), r(2, 3));
+    assert_eq!(r(-2, 3).recip(), r(-3, 2));
+
+    let mut x = r(1, 2);
+    x += r(1, 2);
+    x *= Rational::from(3);
+    x -= r(1, 2);
+    x /= r(5, 4);
+    assert_eq!(x, Rational::integer(2));
+
+    assert_eq!(r(1, 4).to_f64(), 0.25);
+    assert_eq!(r(-3, 4).to_f32(), -0.75);
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let big = Rational::integer(i64::MAX);
+    assert_eq!(big.checked_add(Rational::ONE), None);
+    assert_eq!(big.checked_mul(Rational::integer(2)), None);
+    assert_eq!(r(1, 2).checked_div(Rational::ZERO), None);
+    // Reduction brings results that overflow `i64` in between back in range.
+    assert_eq!(
+        r(i64::MAX, 3).checked_mul(r(3, i64::MAX)),
+        Some(Rational::ONE)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Rational overflow")]
+fn test_overflow_panics() {
+    let _ = Rational::integer(i64::MAX) + Rational::ONE;
+}
+
+#[test]
+#[should_panic(expected = "Rational division by zero")]
+fn test_division_by_zero_panics() {
+    let _ = Rational::ONE / Rational::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "zero denominator")]
+fn test_zero_denominator_panics() {
+    let _ = r(1, 0);
+}
+
+#[test]
+fn test_ordering() {
+    assert!(r(1, 3) < r(1, 2));
+    assert!(r(-1, 2) < r(-1, 3));
+    assert!(r(i64::MAX, 2) > r(i64::MAX - 1, 2));
+    let mut values = [r(3, 4), r(-1, 2), r(2, 3), Rational::ZERO];
+    values.sort();
+    assert_eq!(values, [r(-1, 2), Rational::ZERO, r(2, 3), r(3, 4)]);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(r(3, 4).to_string(), "3/4");
+    assert_eq!(r(-3, 4).to_string(), "-3/4");
+    assert_eq!(Rational::integer(7).to_string(), "7");
+    assert_eq!(format!("{:>6}", r(1, 2)), "   1/2");
+    assert_eq!(format!("{:.2}", r(1, 3)), "1/3");
+    assert_eq!(format!("{:?}", r(1, 3)), "1/3");
+}
+
+#[test]
+fn test_matrix_determinant_is_exact() {
+    let hilbert: Matrix<4, 4, Rational> = Matrix::from_fn(|i, j| r(1, (i + j + 1) as i64));
+    assert_eq!(hilbert.determinant(), r(1, 6_048_000));
+    assert_eq!(
+        Matrix::new([[r(1, 2), r(1, 3)], [r(1, 4), r(1, 5)]]).determinant(),
+        r(1, 60)
+    );
+    let singular = Matrix::new([
+        [r(1, 1), r(2, 1), r(3, 1)],
+        [r(2, 1), r(4, 1), r(6, 1)],
+        [r(1, 3), r(0, 1), r(1, 1)],
+    ]);
+    assert_eq!(singular.determinant(), Rational::ZERO);
+}
+
+#[test]
+fn test_matrix_inverse_is_exact() {
+    let hilbert: Matrix<3, 3, Rational> = Matrix::from_fn(|i, j| r(1, (i + j + 1) as i64));
+    let inverse = hilbert.checked_inverse().unwrap();
+    let expected = Matrix::new([
+        [9, -36, 30].map(Rational::from),
+        [-36, 192, -180].map(Rational::from),
+        [30, -180, 180].map(Rational::from),
+    ]);
+    assert_eq!(inverse, expected);
+    assert_eq!(hilbert * inverse, Matrix::IDENTITY);
+
+    let singular = Matrix::new([[r(1, 2), r(1, 4)], [r(1, 1), r(1, 2)]]);
+    assert_eq!(singular.checked_inverse(), Err(MathError::Singular));
+}
+
+#[test]
+fn test_matrix_rref_is_exact() {
+    let m = Matrix::new([
+        [1, 2, 1, 4].map(Rational::from),
+        [2, 4, 0, 2].map(Rational::from),
+        [3, 6, 1, 6].map(Rational::from),
+    ]);
+    let rref = m.rref();
+    assert_eq!(rref.pivot_columns(), &[0, 2]);
+    let expected = Matrix::new([
+        [1, 2, 0, 1].map(Rational::from),
+        [0, 0, 1, 3].map(Rational::from),
+        [0, 0, 0, 0].map(Rational::from),
+    ]);
+    assert_eq!(*rref.matrix(), expected);
+    for vector in rref.null_space() {
+        assert_eq!(m * vector, Matrix::ZERO);
+    }
+
+    let steps: Vec<String> = m
+        .rref_steps()
+        .map(|step| step.operation.to_string())
+        .collect();
+    assert_eq!(steps[0], "R0 <-> R2");
+    assert_eq!(steps[1], "R0 = 1/3 * R0");
+    assert_eq!(steps[2], "R1 = R1 - 2 * R0");
+}
+
+#[test]
+fn test_matrix_operations() {
+    let m = Matrix::new([[r(1, 2), r(1, 3)], [r(1, 4), r(1, 5)]]);
+    assert_eq!(
+        m * Rational::from(60),
+        Matrix::new([[30, 20], [15, 12]].map(|row| row.map(Rational::from)))
+    );
+    assert_eq!(m + m - m, m);
+    assert_eq!(m.transpose().get(0, 1), r(1, 4));
+    assert_eq!(
+        m.checked_div(Rational::ZERO),
+        Err(MathError::Domain("division by zero"))
+    );
+    assert_eq!(m.to_string(), "Matrix (2x2):\n1/2 1/3\n1/4 1/5\n");
+    assert_eq!(
+        m.format().style(Style::Rust).to_string(),
+        "Matrix::new([\n    [Rational::new(1, 2), Rational::new(1, 3)],\n    [Rational::new(1, 4), Rational::new(1, 5)],\n])\n"
+    );
+}
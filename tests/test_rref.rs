@@ -0,0 +1,146 @@
+use minmath::linear_algebra::{
+    matrix::Matrix,
+    rref::{RowOperation, RrefSteps},
+};
+
+#[test]
+fn test_rref_of_invertible_matrix() {
+    let m = Matrix::new([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+    let rref = m.rref();
+    assert_eq!(*rref.matrix(), Matrix::IDENTITY);
+    assert_eq!(rref.pivot_columns(), &[0, 1, 2]);
+    assert_eq!(rref.rank(), 3);
+    assert_eq!(rref.null_space().count(), 0);
+}
+
+#[test]
+fn test_rref_of_rank_deficient_matrix() {
+    let m = Matrix::new([
+        [1.0, 2.0, 1.0, 4.0],
+        [2.0, 4.0, 0.0, 2.0],
+        [3.0, 6.0, 1.0, 6.0],
+    ]);
+    let rref = m.rref();
+    assert_eq!(rref.pivot_columns(), &[0, 2]);
+    assert_eq!(rref.rank(), 2);
+    let expected = Matrix::new([
+        [1.0, 2.0, 0.0, 1.0],
+        [0.0, 0.0, 1.0, 3.0],
+        [0.0, 0.0, 0.0, 0.0],
+    ]);
+    assert!(
+        (*rref.matrix() - expected).max_norm() < 1e-6,
+        "{}",
+        rref.matrix()
+    );
+    assert_eq!(rref.rank(), m.rank());
+}
+
+#[test]
+fn test_null_space() {
+    let m = Matrix::new([
+        [1.0, 2.0, 1.0, 4.0],
+        [2.0, 4.0, 0.0, 2.0],
+        [3.0, 6.0, 1.0, 6.0],
+    ]);
+    let rref = m.rref();
+    let basis: Vec<Matrix<4, 1>> = rref.null_space().collect();
+    assert_eq!(basis.len(), 2);
+    assert_eq!(basis[0], Matrix::new([[-2.0], [1.0], [0.0], [0.0]]));
+    for vector in basis {
+        assert!((m * vector).max_norm() < 1e-5);
+    }
+}
+
+#[test]
+fn test_column_space() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [2.0, 4.0, 7.0]]);
+    let rref = m.rref();
+    let basis: Vec<Matrix<2, 1>> = rref.column_space().collect();
+    assert_eq!(
+        basis,
+        vec![Matrix::new([[1.0], [2.0]]), Matrix::new([[3.0], [7.0]])]
+    );
+}
+
+#[test]
+fn test_rref_tolerance() {
+    let m = Matrix::new([[1.0, 1.0], [1.0, 1.0 + 1e-4]]);
+    assert_eq!(m.rref().rank(), 2);
+    assert_eq!(m.rref_with_tolerance(1e-3).rank(), 1);
+    assert_eq!(Matrix::<2, 3>::ZERO.rref().rank(), 0);
+    assert_eq!(Matrix::<2, 3>::ZERO.rref().null_space().count(), 3);
+}
+
+#[test]
+fn test_rref_steps() {
+    let m = Matrix::new([[0.0, 2.0], [1.0, 1.0]]);
+    let operations: Vec<RowOperation> = m.rref_steps().map(|step| step.operation).collect();
+    assert_eq!(
+        operations,
+        vec![
+            RowOperation::Swap(0, 1),
+            RowOperation::Scale {
+                row: 1,
+                factor: 0.5
+            },
+            RowOperation::AddMultiple {
+                target: 0,
+                source: 1,
+                factor: -1.0
+            },
+        ]
+    );
+    let last = m.rref_steps().last().unwrap();
+    assert_eq!(last.matrix, Matrix::IDENTITY);
+    assert_eq!(*m.rref().matrix(), Matrix::IDENTITY);
+}
+
+#[test]
+fn test_rref_steps_finish() {
+    let m = Matrix::new([[2.0, 4.0], [1.0, 3.0]]);
+    let mut steps = RrefSteps::new(&m, 0.0);
+    let first = steps.next().unwrap();
+    assert_eq!(
+        first.operation,
+        RowOperation::Scale {
+            row: 0,
+            factor: 0.5
+        }
+    );
+    assert_eq!(*steps.matrix(), Matrix::new([[1.0, 2.0], [1.0, 3.0]]));
+    assert_eq!(steps.finish(), m.rref_with_tolerance(0.0));
+}
+
+#[test]
+fn test_rref_steps_keep_chosen_pivot() {
+    let m = Matrix::new([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+    let operations: Vec<RowOperation> = m.rref_steps().map(|step| step.operation).collect();
+    assert_eq!(operations[0], RowOperation::Swap(0, 1));
+    assert!(matches!(operations[1], RowOperation::Scale { row: 0, .. }));
+    assert!(matches!(
+        operations[2],
+        RowOperation::AddMultiple {
+            target: 1,
+            source: 0,
+            ..
+        }
+    ));
+    let swaps = operations
+        .iter()
+        .filter(|operation| matches!(operation, RowOperation::Swap(..)))
+        .count();
+    assert_eq!(swaps, 2);
+}
+
+#[test]
+fn test_step_display() {
+    let m = Matrix::new([[2.0, 4.0], [1.0, 3.0]]);
+    let steps: Vec<String> = m.rref_steps().map(|step| format!("{:.1}", step)).collect();
+    assert_eq!(steps[0], "R0 = 0.5 * R0\nMatrix (2x2):\n1.0 2.0\n1.0 3.0\n");
+    assert_eq!(
+        steps[1],
+        "R1 = R1 - 1.0 * R0\nMatrix (2x2):\n1.0 2.0\n0.0 1.0\n"
+    );
+    assert_eq!(RowOperation::<f32>::Swap(0, 2).to_string(), "R0 <-> R2");
+}
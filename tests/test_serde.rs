@@ -0,0 +1,141 @@
+#![cfg(feature = "serde")]
+
+#[cfg(feature = "std")]
+use minmath::linear_algebra::dmatrix::DMatrix;
+use minmath::{
+    complex::Complex,
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Vector2, Vector3},
+    },
+    rational::Rational,
+};
+
+#[test]
+fn test_matrix_as_nested_rows() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.5]]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, "[[1.0,2.0,3.0],[4.0,5.0,6.5]]");
+    assert_eq!(serde_json::from_str::<Matrix<2, 3>>(&json).unwrap(), m);
+    assert_eq!(
+        serde_json::to_string(&m.transpose_view()).unwrap(),
+        "[[1.0,4.0],[2.0,5.0],[3.0,6.5]]"
+    );
+}
+
+#[test]
+fn test_matrix_shape_validation() {
+    for json in [
+        "[[1,2,3],[4,5,6]]",
+        "[[1,2],[3,4],[5,6]]",
+        "[[1,2],[3]]",
+        "[[1,2]]",
+        "[1,2,3,4]",
+    ] {
+        let error = serde_json::from_str::<Matrix<2, 2>>(json).unwrap_err();
+        assert!(error.is_data(), "{}: {}", json, error);
+    }
+    let error = serde_json::from_str::<Matrix<2, 2>>("[[1,2],[3]]").unwrap_err();
+    assert!(
+        error.to_string().contains("a row of 2 numbers"),
+        "{}",
+        error
+    );
+    let error = serde_json::from_str::<Matrix<2, 2>>("[[1,2]]").unwrap_err();
+    assert!(error.to_string().contains("2x2 matrix"), "{}", error);
+}
+
+#[test]
+fn test_vectors() {
+    let v = Vector3::new(1.0, -2.0, 0.5);
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "[1.0,-2.0,0.5]");
+    assert_eq!(serde_json::from_str::<Vector3>(&json).unwrap(), v);
+    assert_eq!(
+        serde_json::from_str::<Vector2>("[3, 4]").unwrap(),
+        Vector2::new(3.0, 4.0)
+    );
+    assert!(serde_json::from_str::<Vector2>("[1, 2, 3]").is_err());
+    assert!(serde_json::from_str::<Vector3>("[1, 2]").is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dmatrix() {
+    let m = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, "[[1.0,2.0],[3.0,4.0]]");
+    assert_eq!(serde_json::from_str::<DMatrix>(&json).unwrap(), m);
+    assert_eq!(
+        serde_json::from_str::<DMatrix>("[]").unwrap().size(),
+        (0, 0)
+    );
+    let error = serde_json::from_str::<DMatrix>("[[1,2],[3]]").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("row 1 has 1 elements, expected 2"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_nested_in_user_types() {
+    let transforms: Vec<Matrix<2, 2>> =
+        vec![Matrix::IDENTITY, Matrix::new([[0.0, -1.0], [1.0, 0.0]])];
+    let json = serde_json::to_value(&transforms).unwrap();
+    let back: Vec<Matrix<2, 2>> = serde_json::from_value(json).unwrap();
+    assert_eq!(back, transforms);
+}
+
+#[test]
+fn test_rational() {
+    let half = Rational::new(1, 2);
+    assert_eq!(serde_json::to_string(&half).unwrap(), "[1,2]");
+    assert_eq!(serde_json::from_str::<Rational>("[-3,-6]").unwrap(), half);
+    let error = serde_json::from_str::<Rational>("[1,0]").unwrap_err();
+    assert!(error.to_string().contains("zero denominator"), "{}", error);
+    assert!(serde_json::from_str::<Rational>("[1,2,3]").is_err());
+    assert!(serde_json::from_str::<Rational>("0.5").is_err());
+
+    let m = Matrix::new([
+        [half, Rational::from(2)],
+        [Rational::new(-1, 3), Rational::ZERO],
+    ]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, "[[[1,2],[2,1]],[[-1,3],[0,1]]]");
+    assert_eq!(
+        serde_json::from_str::<Matrix<2, 2, Rational>>(&json).unwrap(),
+        m
+    );
+    let v = Vector2::new(half, Rational::ONE);
+    assert_eq!(
+        serde_json::from_str::<Vector2<Rational>>("[[2,4],[1,1]]").unwrap(),
+        v
+    );
+}
+
+#[test]
+fn test_complex() {
+    let z = Complex::new(1.5, -2.0);
+    assert_eq!(serde_json::to_string(&z).unwrap(), "[1.5,-2.0]");
+    assert_eq!(serde_json::from_str::<Complex>("[1.5, -2]").unwrap(), z);
+    assert!(serde_json::from_str::<Complex>("[1.5]").is_err());
+
+    let m = Matrix::new([[Complex::I, Complex::ONE], [Complex::ZERO, z]]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, "[[[0.0,1.0],[1.0,0.0]],[[0.0,0.0],[1.5,-2.0]]]");
+    assert_eq!(
+        serde_json::from_str::<Matrix<2, 2, Complex>>(&json).unwrap(),
+        m
+    );
+
+    let exact = Complex::new(Rational::new(1, 2), Rational::ONE);
+    let json = serde_json::to_string(&exact).unwrap();
+    assert_eq!(json, "[[1,2],[1,1]]");
+    assert_eq!(
+        serde_json::from_str::<Complex<Rational>>(&json).unwrap(),
+        exact
+    );
+}
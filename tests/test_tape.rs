@@ -0,0 +1,192 @@
+#![cfg(feature = "std")]
+
+use minmath::{
+    dual,
+    linear_algebra::{
+        matrix::Matrix,
+        vector::{Cross, RealVector, Vector3},
+    },
+    scalar::Real,
+    tape::{Tape, Var, gradient},
+};
+
+mod common;
+
+use common::{arm, rosenbrock};
+
+#[test]
+fn test_arithmetic_derivatives() {
+    let tape = Tape::new();
+    let x = tape.var(2.0);
+    let y = x * x * x - x / (x + Var::constant(2.0));
+    assert_eq!(y.value(), 7.5);
+    // 3x² - 2 / (x + 2)²
+    assert_eq!(y.backward().wrt(x), 12.0 - 0.125);
+    assert_eq!((-x).backward().wrt(x), -1.0);
+    let mut z = x;
+    z *= x;
+    z -= Var::constant(1.0);
+    z /= x;
+    assert_eq!(z.value(), 1.5);
+    assert_eq!(z.backward().wrt(x), 1.25);
+}
+
+#[test]
+fn test_function_derivatives() {
+    let tape = Tape::new();
+    let x = tape.var(0.5);
+    let cases = [
+        (x.sin(), 0.5f32.cos()),
+        (x.cos(), -0.5f32.sin()),
+        (x.tan(), 1.0 / (0.5f32.cos() * 0.5f32.cos())),
+        (x.exp(), 0.5f32.exp()),
+        (x.ln(), 2.0),
+        (x.sqrt(), 0.5 / 0.5f32.sqrt()),
+        ((-x).abs(), 1.0),
+        (x.powi(3), 0.75),
+        (x.powi(-1), -4.0),
+    ];
+    for (y, expected) in cases {
+        let derivative = y.backward().wrt(x);
+        assert!((derivative - expected).abs() < 1e-5, "{} {}", y, expected);
+    }
+}
+
+#[test]
+fn test_gradients_of_every_leaf() {
+    let tape = Tape::new();
+    let a = tape.var(3.0);
+    let b = tape.var(-2.0);
+    let unused = tape.var(5.0);
+    // A value used on several paths accumulates their contributions.
+    let c = a * b;
+    let y = c * c + a;
+    let gradients = y.backward();
+    assert_eq!(gradients.wrt(a), 2.0 * 3.0 * 4.0 + 1.0);
+    assert_eq!(gradients.wrt(b), 2.0 * 9.0 * -2.0);
+    assert_eq!(gradients.wrt(c), -12.0);
+    assert_eq!(gradients.wrt(unused), 0.0);
+    assert_eq!(gradients.wrt(Var::constant(1.0)), 0.0);
+    assert_eq!(gradients.leaves().collect::<Vec<_>>(), [25.0, -36.0, 0.0]);
+    // Values recorded after the output do not depend on it.
+    let later = y + a;
+    assert_eq!(gradients.wrt(later), 0.0);
+}
+
+#[test]
+fn test_constants_stay_off_the_tape() {
+    let tape = Tape::new();
+    let x = tape.var(1.0);
+    assert_eq!(tape.len(), 1);
+    let c = (Var::constant(2.0) * Var::constant(3.0)).exp();
+    assert_eq!(tape.len(), 1);
+    assert!(c.backward().leaves().next().is_none());
+    let _ = x * c;
+    assert_eq!(tape.len(), 2);
+    assert_eq!(x, Var::constant(1.0));
+}
+
+#[test]
+fn test_clear() {
+    let mut tape = Tape::new();
+    let x = tape.var(1.0);
+    let _ = x + x;
+    assert_eq!(tape.len(), 2);
+    tape.clear();
+    assert!(tape.is_empty());
+    let y = tape.var(4.0);
+    assert_eq!(y.sqrt().backward().wrt(y), 0.25);
+}
+
+#[test]
+#[should_panic(expected = "Vars from different tapes")]
+fn test_mixed_tapes() {
+    let first = Tape::new();
+    let second = Tape::new();
+    let _ = first.var(1.0) + second.var(2.0);
+}
+
+#[test]
+fn test_gradient() {
+    let g = gradient(
+        |v| rosenbrock(v[0][0], v[1][0]),
+        Matrix::new([[0.5], [1.0]]),
+    );
+    assert_eq!(g, Matrix::new([[-151.0], [150.0]]));
+    let g = gradient(
+        |v| rosenbrock(v[0][0], v[1][0]),
+        Matrix::new([[1.0], [1.0]]),
+    );
+    assert_eq!(g, Matrix::ZERO);
+}
+
+#[test]
+fn test_matrix_product() {
+    let tape = Tape::new();
+    let a = tape.matrix(Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+    let b = tape.matrix(Matrix::new([[5.0, 6.0], [7.0, 8.0]]));
+    // The sum of all elements of `ab` has gradient `1 bᵀ` for `a` and `aᵀ 1`
+    // for `b`.
+    let y = (a * b).fold(Var::constant(0.0), |sum, e| sum + e);
+    assert_eq!(y.value(), 19.0 + 22.0 + 43.0 + 50.0);
+    let gradients = y.backward();
+    assert_eq!(
+        gradients.matrix(&a),
+        Matrix::new([[11.0, 15.0], [11.0, 15.0]])
+    );
+    assert_eq!(gradients.matrix(&b), Matrix::new([[4.0, 4.0], [6.0, 6.0]]));
+
+    let m = tape.matrix(Matrix::new([[3.0, 1.0], [2.0, 3.0]]));
+    // d det / dM is the cofactor matrix.
+    let det = m.determinant();
+    assert_eq!(det.value(), 7.0);
+    assert_eq!(
+        det.backward().matrix(&m),
+        Matrix::new([[3.0, -2.0], [-1.0, 3.0]])
+    );
+}
+
+#[test]
+fn test_vector_of_vars() {
+    let tape = Tape::new();
+    let a = tape.vector3(Vector3::new(1.0, 2.0, 0.0));
+    let b = tape.vector3(Vector3::new(0.0, 0.0, 1.0));
+    let gradients = a.dot(a).backward();
+    assert_eq!(gradients.vector3(&a), Vector3::new(2.0, 4.0, 0.0));
+    assert_eq!(gradients.vector3(&b), Vector3::ZERO);
+
+    // (a × b)·x = a·(b × x) = b·(x × a).
+    let gradients = a.cross(b).dot(Vector3::X).backward();
+    assert_eq!(gradients.vector3(&a), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(gradients.vector3(&b), Vector3::new(0.0, 0.0, 2.0));
+
+    let length = a.length();
+    assert_eq!(length.value(), 5.0f32.sqrt());
+    let gradient = length.backward().vector3(&a);
+    assert!((gradient.x - 1.0 / 5.0f32.sqrt()).abs() < 1e-6);
+    let scaled = (a * Var::constant(2.0) - b).y;
+    assert_eq!(scaled.backward().vector3(&a), Vector3::new(0.0, 2.0, 0.0));
+}
+
+#[test]
+fn test_agrees_with_forward_mode() {
+    let q = Matrix::new([[0.3], [-0.4], [0.8]]);
+    let forward = dual::jacobian(arm, q);
+    for row in 0..3 {
+        let reverse = gradient(|q| arm(q)[row][0], q);
+        for column in 0..3 {
+            let difference = reverse[column][0] - forward[row][column];
+            assert!(difference.abs() < 1e-6, "{}{}", reverse, forward);
+        }
+    }
+}
+
+#[test]
+fn test_display() {
+    let tape = Tape::new();
+    let x = tape.var(1.5);
+    assert_eq!(x.to_string(), "1.5");
+    assert_eq!(format!("{:6.2}", x), "  1.50");
+    assert_eq!(format!("{:?}", x * x), "Var(2.25 @ 1)");
+    assert_eq!(format!("{:?}", Var::constant(2.0)), "Var(2.0)");
+}
@@ -110,4 +110,11 @@ mod tests {
         let expected = Matrix::new([[1.0], [2.0]]);
         assert_eq!(m, expected);
     }
+
+    #[test]
+    fn test_vector2_outer_product() {
+        let a = Vector2::new(1.0, 2.0);
+        let b = Vector2::new(3.0, -4.0);
+        assert_eq!(a.outer(b), Matrix::new([[3.0, -4.0], [6.0, -8.0]]));
+    }
 }
@@ -111,6 +111,24 @@ mod tests {
         assert_eq!(c, Vector3::new(0.0, 0.0, 1.0));
     }
 
+    #[test]
+    fn test_vector3_outer_product() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(4.0, 5.0, 6.0);
+        let expected = Matrix::new([[4.0, 5.0, 6.0], [8.0, 10.0, 12.0], [12.0, 15.0, 18.0]]);
+        assert_eq!(a.outer(b), expected);
+        assert_eq!(a.outer(b), a.to_matrix() * b.to_matrix().transpose());
+    }
+
+    #[test]
+    fn test_vector3_cross_matrix() {
+        let a = Vector3::new(1.0, -2.0, 3.0);
+        let b = Vector3::new(4.0, 5.0, -6.0);
+        let m = a.cross_matrix();
+        assert_eq!((m * b.to_matrix()).to_vector3(), a.cross(b));
+        assert_eq!(m.transpose(), m * -1.0);
+    }
+
     #[test]
     fn test_vector3_to_matrix() {
         let v = Vector3::new(1.0, 2.0, 3.0);
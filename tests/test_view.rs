@@ -0,0 +1,52 @@
+use minmath::linear_algebra::matrix::Matrix;
+
+fn transform() -> Matrix<4, 4> {
+    Matrix::from_fn(|r, c| (r * 4 + c) as f32)
+}
+
+#[test]
+fn test_view_borrows_block() {
+    let m = transform();
+    let view = m.view::<1, 1, 2, 3>();
+    assert_eq!(view.size(), (2, 3));
+    assert_eq!(view[(0, 0)], 5.0);
+    assert_eq!(view.get(1, 2), 11.0);
+    assert_eq!(view, m.submatrix::<1, 1, 2, 3>());
+}
+
+#[test]
+fn test_row_and_column_views() {
+    let m = transform();
+    assert_eq!(m.row_view::<2>(), m.row::<2>());
+    assert_eq!(m.column_view::<3>(), m.column::<3>());
+    assert_eq!(
+        m.column_view::<1>().iter().collect::<Vec<_>>(),
+        vec![1.0, 5.0, 9.0, 13.0]
+    );
+}
+
+#[test]
+fn test_transpose_view() {
+    let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let t = m.transpose_view();
+    assert_eq!(t.size(), (3, 2));
+    assert_eq!(t, m.transpose());
+    assert_eq!(t.transpose(), m);
+}
+
+#[test]
+fn test_nested_views() {
+    let m = transform();
+    let inner = m.view::<1, 1, 3, 3>().view::<1, 0, 2, 2>();
+    assert_eq!(inner.to_matrix(), Matrix::new([[9.0, 10.0], [13.0, 14.0]]));
+    let column = m.transpose_view().view::<0, 2, 4, 1>();
+    assert_eq!(column, m.row::<2>().transpose());
+}
+
+#[test]
+#[should_panic]
+fn test_view_index_out_of_bounds() {
+    let m = transform();
+    let view = m.view::<0, 0, 2, 2>();
+    let _ = view[(0, 2)];
+}